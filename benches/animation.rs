@@ -0,0 +1,39 @@
+//! Cost of handing each animated object's current frame to the renderer,
+//! as layout does every frame. Frames are shared, so this should stay flat
+//! as frame size grows; a mirrored sprite only pays for a flip when its
+//! frame changes.
+
+use criterion::{criterion_group, criterion_main, BenchmarkId, Criterion};
+use image::RgbaImage;
+use quartz::AnimatedSprite;
+
+const SPRITES: usize = 200;
+
+fn sprites(frame_size: u32, mirrored: bool) -> Vec<AnimatedSprite> {
+    let frames: Vec<RgbaImage> = (0..8).map(|_| RgbaImage::new(frame_size, frame_size)).collect();
+    (0..SPRITES).map(|_| {
+        let mut sprite = AnimatedSprite::from_frames(frames.clone(), (64.0, 64.0), 12.0);
+        sprite.set_mirrored(mirrored);
+        sprite
+    }).collect()
+}
+
+fn current_images(c: &mut Criterion) {
+    let mut group = c.benchmark_group("get_current_image x200");
+    for frame_size in [32, 128, 512] {
+        for mirrored in [false, true] {
+            let mut sprites = sprites(frame_size, mirrored);
+            let id = BenchmarkId::new(if mirrored { "mirrored" } else { "plain" }, frame_size);
+            group.bench_function(id, |b| b.iter(|| {
+                for sprite in sprites.iter_mut() {
+                    sprite.update(1.0 / 60.0);
+                    std::hint::black_box(sprite.get_current_image());
+                }
+            }));
+        }
+    }
+    group.finish();
+}
+
+criterion_group!(benches, current_images);
+criterion_main!(benches);
@@ -0,0 +1,42 @@
+//! One fixed step of a canvas with 200 objects, each owning a tick, a key
+//! hold and a collision event, with the key held down. Event data is read
+//! in place, so this shouldn't grow with the size of the actions.
+
+use criterion::{criterion_group, criterion_main, Criterion};
+use prism::event::KeyboardState;
+use quartz::{Action, Canvas, CanvasMode, GameEvent, GameObject, Key, MathOp, NamedKey, Target};
+
+const OBJECTS: usize = 200;
+
+fn busy_canvas() -> Canvas {
+    let mut cv = Canvas::headless(CanvasMode::Landscape);
+    cv.set_var("ticks", 0);
+    cv.set_var("held", 0);
+    cv.set_var("hits", 0);
+    for i in 0..OBJECTS {
+        let name = format!("obj{i}");
+        let x = (i % 20) as f32 * 150.0;
+        let y = (i / 20) as f32 * 150.0;
+        let obj = GameObject::build("obj").position(x, y).size(40.0, 40.0).finish();
+        cv.add_game_object(name.clone(), obj);
+        let me = Target::name(name);
+        let nudge = Action::Multi(vec![
+            Action::mod_var("ticks", MathOp::Add, 1),
+            Action::set_velocity(Target::self_object(), 0.5, 0.0),
+        ]);
+        cv.add_event(GameEvent::tick(nudge, Target::self_object()), me.clone());
+        let push = Action::set_velocity(Target::self_object(), 2.0, 0.0);
+        cv.add_event(GameEvent::key_hold(Key::Named(NamedKey::ArrowRight), push, Target::self_object()), me.clone());
+        cv.add_event(GameEvent::Collision { action: Action::mod_var("hits", MathOp::Add, 1), target: Target::all() }, me);
+    }
+    cv.inject_key(KeyboardState::Pressed, Key::Named(NamedKey::ArrowRight));
+    cv
+}
+
+fn step(c: &mut Criterion) {
+    let mut cv = busy_canvas();
+    c.bench_function("step 200 objects x3 events", |b| b.iter(|| cv.step(1.0 / 60.0)));
+}
+
+criterion_group!(benches, step);
+criterion_main!(benches);
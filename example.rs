@@ -223,7 +223,7 @@ fn build_game_scene(ctx: &mut Context) -> Scene {
     // ── Enemies ────────────────────────────────────────────────────────────
     let enemy1 = make_enemy(ctx, "enemy1", 1800.0, GROUND_Y - ENEMY_SIZE);
     let enemy2 = make_enemy(ctx, "enemy2", 2800.0, GROUND_Y - ENEMY_SIZE);
-    let enemy3 = make_enemy(ctx, "enemy3", 3300.0, 1300.0 - ENEMY_SIZE);
+    let enemy3 = make_enemy(ctx, "enemy3", 3300.0, 1300.0 - ENEMY_SIZE).with_state_machine(guard_brain());
 
     // ── Coins ──────────────────────────────────────────────────────────────
     let coins: Vec<(String, GameObject)> = vec![
@@ -350,7 +350,8 @@ fn build_game_scene(ctx: &mut Context) -> Scene {
             GameEvent::Collision {
                 action: Action::Custom { name: "collect_coin".into() },
                 target: Target::tag("coin"),
-            },
+            }
+            .with_partner(Target::tag("coin")),
             Target::name("player"),
         )
         // Enemy collision → damage player
@@ -358,7 +359,8 @@ fn build_game_scene(ctx: &mut Context) -> Scene {
             GameEvent::Collision {
                 action: Action::Custom { name: "player_hit".into() },
                 target: Target::tag("enemy"),
-            },
+            }
+            .with_partner(Target::tag("enemy")),
             Target::name("player"),
         )
         // Pause with Escape
@@ -479,6 +481,11 @@ fn build_game_scene(ctx: &mut Context) -> Scene {
                     target: Target::name("player"),
                     value: (0.0, -18.0),
                 });
+                // and shove every enemy back, each along its own line from the player
+                c.run(Action::for_each(Target::tag("enemy"), Action::teleport(
+                    Target::current(),
+                    Location::away_from(Target::name("player"), 120.0),
+                )));
                 // Flash: hide then show
                 c.run(Action::Conditional {
                     condition: Condition::Always,
@@ -547,7 +554,8 @@ fn build_game_scene(ctx: &mut Context) -> Scene {
                     GameEvent::Collision {
                         action: Action::Remove { target: Target::name(&bullet_id) },
                         target: Target::tag("enemy"),
-                    },
+                    }
+                    .with_partner(Target::tag("enemy")),
                     Target::name(&bullet_id),
                 );
             }
@@ -670,6 +678,31 @@ fn make_enemy(ctx: &mut Context, id: &str, x: f32, y: f32) -> GameObject {
     .with_animation(AnimatedSprite::new(ENEMY_GIF, (ENEMY_SIZE, ENEMY_SIZE), 8.0).unwrap())
 }
 
+/// Three-state enemy: patrols until the player comes within 900px, chases
+/// them, and drops a spike every half second once close, going back to the
+/// chase after a while and to patrolling when the player gets away.
+fn guard_brain() -> StateMachine {
+    let spike = GameObject::build("spike").size(40.0, 40.0).tag("enemy").finish().with_lifetime(2.0);
+    let near = |distance| Condition::within_distance(Target::Current, Target::name("player"), distance);
+
+    StateMachine::new("patrol")
+        .on_enter("patrol", Action::multi(vec![
+            Action::stop_steering(Target::Current),
+            Action::set_velocity(Target::Current, ENEMY_SPEED, 0.0),
+        ]))
+        .transition("patrol", near(900.0), "chase")
+
+        .on_enter("chase", Action::move_toward(Target::Current,
+                  Location::at_target(Target::name("player")), ENEMY_SPEED * 1.5, 150.0))
+        .transition("chase", Condition::Not(Box::new(near(1200.0))), "patrol")
+        .transition("chase", near(250.0), "attack")
+
+        .on_enter("attack", Action::stop_steering(Target::Current))
+        .on_tick("attack", Action::when_if(Condition::cooldown("guard_spike", 0.5),
+                 Action::spawn(spike, Location::at_target(Target::Current))))
+        .transition("attack", Condition::time_in_state(Target::Current, 1.5), "chase")
+}
+
 fn make_platform(ctx: &mut Context, id: &str, x: f32, y: f32) -> GameObject {
     GameObject::new_rect(
         ctx,
@@ -1,5 +1,6 @@
 use std::collections::HashMap;
 use prism::canvas::Image;
+use crate::sprite::AnimatedSprite;
 
 /// A general-purpose image cache keyed by string identifiers.
 ///
@@ -82,3 +83,78 @@ impl ImageCache {
         self.entries.retain(|k, v| f(k.as_str(), v));
     }
 }
+
+/// Handle to an animation decoded once by `Canvas::preload_animation`.
+/// Apply it with `Action::SetAnimationHandle`.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub struct AnimationHandle(u64);
+
+/// Decoded GIF frames, so swapping animations doesn't re-decode them.
+///
+/// `Action::SetAnimation` entries are keyed by the byte slice's address and
+/// length plus the target size (the bytes are `'static`, so the address is
+/// stable). Preloaded animations are keyed by `AnimationHandle`.
+#[derive(Clone, Debug, Default)]
+pub struct AnimationCache {
+    by_bytes:    HashMap<(usize, usize, u32, u32), AnimatedSprite>,
+    by_handle:   HashMap<AnimationHandle, AnimatedSprite>,
+    next_handle: u64,
+}
+
+impl AnimationCache {
+    pub fn new() -> Self { Self::default() }
+
+    /// Returns a fresh sprite for `bytes` at `size`, decoding only on the
+    /// first request. The sprite starts at frame 0 with the given fps.
+    pub(crate) fn get_or_decode(&mut self, bytes: &'static [u8], size: (f32, f32), fps: f32) -> Result<AnimatedSprite, String> {
+        let key = (bytes.as_ptr() as usize, bytes.len(), size.0.to_bits(), size.1.to_bits());
+        let mut sprite = match self.by_bytes.get(&key) {
+            Some(sprite) => sprite.clone(),
+            None => {
+                let sprite = AnimatedSprite::new(bytes, size, fps)?;
+                self.by_bytes.insert(key, sprite.clone());
+                sprite
+            }
+        };
+        sprite.reset();
+        sprite.set_fps(fps);
+        Ok(sprite)
+    }
+
+    pub(crate) fn insert(&mut self, sprite: AnimatedSprite) -> AnimationHandle {
+        let handle = AnimationHandle(self.next_handle);
+        self.next_handle += 1;
+        self.by_handle.insert(handle, sprite);
+        handle
+    }
+
+    /// A fresh copy of a preloaded animation, starting at frame 0.
+    pub fn get(&self, handle: AnimationHandle) -> Option<AnimatedSprite> {
+        self.by_handle.get(&handle).map(|sprite| {
+            let mut sprite = sprite.clone();
+            sprite.reset();
+            sprite
+        })
+    }
+
+    /// Frees one preloaded animation. Objects already showing it keep
+    /// their own copy.
+    pub fn release(&mut self, handle: AnimationHandle) {
+        self.by_handle.remove(&handle);
+    }
+
+    /// Frees every cached and preloaded animation.
+    pub fn clear(&mut self) {
+        self.by_bytes.clear();
+        self.by_handle.clear();
+    }
+
+    /// Number of decoded animations held.
+    pub fn len(&self) -> usize {
+        self.by_bytes.len() + self.by_handle.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.len() == 0
+    }
+}
@@ -212,6 +212,9 @@ pub struct Camera {
     /// Some((wx, wy)) = zoom toward this world point (e.g. player position,
     /// mouse world position, or a fixed geometric anchor like the floor).
     pub zoom_anchor:      Option<(f32, f32)>,
+    /// Timed zoom started by `zoom_to`, as (start zoom, elapsed, duration);
+    /// it ends on `zoom_target`. Takes over from the lerp while running.
+    pub(crate) zoom_tween: Option<(f32, f32, f32)>,
 
     // ── Camera effects ────────────────────────────────────────────────────────
     pub effects: CameraEffects,
@@ -234,6 +237,7 @@ impl Camera {
             zoom_target:     1.0,
             zoom_lerp_speed: 0.12,
             zoom_anchor:     None,
+            zoom_tween:      None,
             effects:         CameraEffects::default(),
         }
     }
@@ -269,16 +273,40 @@ impl Camera {
 
     // ── Smooth zoom API ───────────────────────────────────────────────────────
 
+    /// Zoom limits for `zoom_to` and `smooth_zoom_at`.
+    pub const MIN_ZOOM: f32 = 0.05;
+    pub const MAX_ZOOM: f32 = 30.0;
+
     /// Set a desired zoom level; the camera lerps smoothly toward it.
     pub fn smooth_zoom(&mut self, target: f32) {
+        self.zoom_tween  = None;
         self.zoom_target = target.max(0.01);
     }
 
+    /// Eases the zoom to `target` (clamped to MIN_ZOOM..=MAX_ZOOM) over
+    /// exactly `duration` seconds of simulation time, around `zoom_anchor`
+    /// or the view centre, so whatever the camera follows stays put. 2.0
+    /// shows half the visible area at twice the size. A duration of 0
+    /// applies it at once. Replaces any zoom in progress.
+    pub fn zoom_to(&mut self, target: f32, duration: f32) {
+        let target = target.clamp(Self::MIN_ZOOM, Self::MAX_ZOOM);
+        self.zoom_target = target;
+        if duration > 0.0 {
+            self.zoom_tween = Some((self.zoom, 0.0, duration));
+        } else {
+            self.zoom_tween = None;
+            let old_zoom = self.zoom;
+            self.zoom = target;
+            self.adjust_position_for_zoom(old_zoom, target);
+        }
+    }
+
     /// Zoom toward a world-space point by a multiplicative delta.
     /// Positive delta = zoom in; negative = zoom out.
     pub fn smooth_zoom_at(&mut self, delta: f32, world_anchor: (f32, f32)) {
         let factor = 1.0 + delta;
-        let new_target = (self.zoom_target * factor).clamp(0.05, 30.0);
+        let new_target = (self.zoom_target * factor).clamp(Self::MIN_ZOOM, Self::MAX_ZOOM);
+        self.zoom_tween  = None;
         self.zoom_anchor = Some(world_anchor);
         self.zoom_target = new_target;
     }
@@ -287,6 +315,7 @@ impl Camera {
     /// Also snaps zoom_target so the lerp doesn't fight it.
     pub fn snap_zoom(&mut self, value: f32) {
         let v = value.max(0.01);
+        self.zoom_tween  = None;
         self.zoom        = v;
         self.zoom_target = v;
     }
@@ -382,10 +411,18 @@ impl Camera {
         (self.viewport_size.0 / self.zoom, self.viewport_size.1 / self.zoom)
     }
 
-    /// Advance the zoom lerp. Called once per frame from apply_camera_transform.
-    pub(crate) fn advance_zoom_lerp(&mut self) {
+    /// Advance the zoom tween or lerp by one step of `dt` seconds. Called
+    /// from apply_camera_transform.
+    pub(crate) fn advance_zoom_lerp(&mut self, dt: f32) {
         let old_zoom = self.zoom;
-        if (self.zoom - self.zoom_target).abs() > 0.0001 {
+        if let Some((from, elapsed, duration)) = self.zoom_tween {
+            let elapsed = elapsed + dt;
+            let t = (elapsed / duration).min(1.0);
+            let eased = t * t * (3.0 - 2.0 * t);
+            self.zoom = from + (self.zoom_target - from) * eased;
+            self.zoom_tween = (t < 1.0).then_some((from, elapsed, duration));
+            self.adjust_position_for_zoom(old_zoom, self.zoom);
+        } else if (self.zoom - self.zoom_target).abs() > 0.0001 {
             self.zoom += (self.zoom_target - self.zoom) * self.zoom_lerp_speed;
             if (self.zoom - self.zoom_target).abs() < 0.001 {
                 self.zoom = self.zoom_target;
@@ -14,31 +14,50 @@ use crate::expr::parse_action;
 use crate::types::{
     Action,
     Target,
+    Location,
     CollisionMode,
     GlowConfig,
+    Anchor,
 };
 use super::core::CanvasLayout;
+use super::physics::rotation_adjusted_offset;
+use super::steering::Steering;
+use super::query::Contact;
+use super::matrix::CollisionMatrix;
+use super::boundary::BoundaryBehavior;
+use super::broadphase::BroadPhase;
 use super::core::CanvasMode;
 use std::cell::Cell;
-use std::collections::HashMap;
+use std::collections::{HashMap, HashSet};
 use std::sync::Arc;
 use prism::canvas::{Text, Span, Align, Font, Color};
 
 impl Canvas {
     pub fn new(_ctx: &mut prism::Context, mode: CanvasMode) -> Self {
+        Self::headless(mode)
+    }
+
+    /// `new` without a window context, for driving a canvas with `step`
+    /// from tests and benchmarks.
+    pub fn headless(mode: CanvasMode) -> Self {
         let virtual_res = mode.virtual_resolution().unwrap_or((0.0, 0.0));
         Self {
             layout: CanvasLayout {
                 offsets:            Vec::new(),
                 particle_offsets:   Vec::new(),
+                letterbox_offsets:  Vec::new(),
+                debug_offsets:      Vec::new(),
                 sorted_offsets:     Vec::new(),
                 canvas_size:        Cell::new(virtual_res),
                 mode,
                 scale:              Cell::new(1.0),
+                base_scale:         Cell::new(1.0),
                 safe_area_offset:   Cell::new((0.0, 0.0)),
                 zoom:               Cell::new(1.0),
                 sorted_ignore_zoom: Vec::new(),
+                sorted_shakes:      Vec::new(),
                 actual_size:        Cell::new(virtual_res),
+                shake_offset:       Cell::new((0.0, 0.0)),
             },
             store:            ObjectStore::new(),
             input:            InputState::new(),
@@ -56,10 +75,49 @@ impl Canvas {
             last_particle_states:      Vec::new(),
             particle_images:           Vec::new(),
             image_cache:               crate::assets::ImageCache::new(),
+            animation_cache:           crate::assets::AnimationCache::new(),
             emitter_locations:         HashMap::new(),
             particle_render_layers:    Vec::new(),
             render_order:              Vec::new(),
             grapple_constraints:       HashMap::new(),
+            spawn_counter:             0,
+            fixed_timestep:            0.016,
+            step_delta:                0.016,
+            time_accumulator:          0.0,
+            time_scale:                1.0,
+            global_gravity:            0.0,
+            collision_matrix:          CollisionMatrix::default(),
+            platforms_collide:         false,
+            broadphase:                BroadPhase::BruteForce,
+            hitstop_remaining:         0.0,
+            hitstop_stacking:          true,
+            last_frame:                None,
+            background_color:          None,
+            letterbox_color:           None,
+            background_image:          None,
+            letterbox_images:          Vec::new(),
+            backdrop_key:              None,
+            debug_draw:                false,
+            y_sort:                    false,
+            event_trace:               false,
+            step_count:                0,
+            debug_images:              Vec::new(),
+            cooldowns:                 HashMap::new(),
+            hold_timers:               HashMap::new(),
+            contacts:                  HashSet::new(),
+            step_contacts:             HashMap::new(),
+            initial_state:             None,
+            attachments:               Vec::new(),
+            steering:                  HashMap::new(),
+            blinks:                    HashMap::new(),
+            template_events:           HashMap::new(),
+            boundaries:                [BoundaryBehavior::Event; 4],
+            oscillations:              HashMap::new(),
+            recording:                 None,
+            playback:                  None,
+            stats:                     Default::default(),
+            frame_stats:               Default::default(),
+            screen_shake:              None,
         }
     }
 
@@ -78,14 +136,11 @@ impl Canvas {
         self.input.held_keys.contains(&k)
     }
 
-    /// Returns the ratio of actual window width to virtual resolution width.
+    /// Returns the ratio of window pixels to virtual pixels (ignoring zoom).
+    /// Uses the same fit as layout, so it stays correct for windows that
+    /// aren't 16:9.
     pub fn virtual_scale(&self) -> f32 {
-        let actual = self.layout.actual_size.get();
-        match self.layout.mode {
-            CanvasMode::Landscape  => actual.0 / 3840.0,
-            CanvasMode::Portrait   => actual.0 / 2160.0,
-            CanvasMode::Fullscreen => 1.0,
-        }
+        CanvasLayout::fit(self.layout.mode, self.layout.actual_size.get()).0
     }
 
     /// Converts a font size authored in logical screen pixels to virtual canvas pixels.
@@ -106,10 +161,19 @@ impl Canvas {
         )
     }
 
-    pub fn add_game_object(&mut self, name: String, obj: GameObject) {
+    pub fn add_game_object(&mut self, name: String, mut obj: GameObject) {
+        if let Some(sprite) = obj.animated_sprite.as_mut() {
+            sprite.apply_random_start(&mut self.entropy);
+        }
+        if obj.global_gravity { obj.gravity = self.global_gravity; }
         let position = obj.position;
+        obj.previous_position = position;
+        let events = std::mem::take(&mut obj.events);
         self.layout.offsets.push(position);
         self.store.add(name, obj);
+        let idx = self.store.objects.len() - 1;
+        self.store.events[idx] = events;
+        self.attach_template_events(idx);
         self.rebuild_render_order();
     }
 
@@ -123,6 +187,7 @@ impl Canvas {
             self.mouse.hovered_indices = updated;
             self.layout.offsets.remove(idx);
             self.store.remove(name);
+            self.forget_objects(&[name.to_string()]);
             self.rebuild_render_order();
         }
     }
@@ -136,7 +201,62 @@ impl Canvas {
             .and_then(move |i| self.store.objects.get_mut(i))
     }
 
+    /// Registered name of the first object `target` resolves to.
+    pub fn name_of(&self, target: &Target) -> Option<&str> {
+        let idx = *self.store.get_indices(target).first()?;
+        self.store.names.get(idx).map(String::as_str)
+    }
+
+    /// Registered name of an object borrowed from this canvas, e.g. one
+    /// returned by `objects_in_radius`. Matches by identity, not by id, since
+    /// spawned clones share their template's id.
+    pub fn name_of_object(&self, object: &GameObject) -> Option<&str> {
+        let idx = self.store.objects.iter().position(|o| std::ptr::eq(o, object))?;
+        self.store.names.get(idx).map(String::as_str)
+    }
+
+    /// Every object with its registered name, in insertion order.
+    pub fn objects(&self) -> impl Iterator<Item = (&str, &GameObject)> {
+        self.store.names.iter().map(String::as_str).zip(self.store.objects.iter())
+    }
+
+    /// Names of the objects `predicate` accepts, in insertion order. For
+    /// queries tags and `Condition`s can't express.
+    pub fn find<F: Fn(&GameObject) -> bool>(&self, predicate: F) -> Vec<&str> {
+        self.objects().filter(|(_, obj)| predicate(obj)).map(|(name, _)| name).collect()
+    }
+
+    /// Spawns `object` at `location` and returns the generated name, so the
+    /// caller can look the new object up or remove it later.
+    /// Same behaviour as `Action::Spawn`.
+    pub fn spawn(&mut self, object: GameObject, location: Location) -> String {
+        let position = self.resolve_location(&location);
+        self.spawn_at(object, position)
+    }
+
+    /// Adds a clone of a template at `position` under a unique generated name
+    /// (`spawned_<id>_<n>`) and returns that name.
+    pub(crate) fn spawn_at(&mut self, mut obj: GameObject, position: (f32, f32)) -> String {
+        let name = loop {
+            self.spawn_counter += 1;
+            let candidate = format!("spawned_{}_{}", obj.id, self.spawn_counter);
+            if !self.store.name_to_index.contains_key(&candidate) { break candidate; }
+        };
+        obj.position = position;
+        self.add_game_object(name.clone(), obj);
+        name
+    }
+
+    /// Runs `action` with `Target::SelfObject` resolving to `owner`, as event
+    /// dispatch does for the object an event belongs to.
+    pub(crate) fn run_owned(&mut self, owner: String, action: Action) {
+        let previous = self.store.owner.replace(owner);
+        self.run(action);
+        self.store.owner = previous;
+    }
+
     pub fn run(&mut self, action: Action) {
+        if self.event_trace { self.trace_action(&action); }
         match action {
             Action::ApplyMomentum { target, value } => {
                 self.store.apply_to_targets(&target, |obj| {
@@ -144,22 +264,104 @@ impl Canvas {
                     obj.momentum.1 += value.1;
                 });
             }
+            Action::Knockback { target, from, force } => {
+                let source = self.resolve_location(&from);
+                self.store.apply_to_targets(&target, |obj| {
+                    let (cx, cy) = obj.center();
+                    let (dx, dy) = (cx - source.0, cy - source.1);
+                    let dist = (dx * dx + dy * dy).sqrt();
+                    let dir = if dist < f32::EPSILON { (0.0, -1.0) } else { (dx / dist, dy / dist) };
+                    obj.momentum.0 += dir.0 * force;
+                    obj.momentum.1 += dir.1 * force;
+                });
+            }
+            Action::MoveToward { target, destination, speed, arrive_radius } => {
+                let steer = Steering { location: destination, speed, radius: arrive_radius.max(0.0), flee: false };
+                for name in self.store.get_names(&target) { self.steering.insert(name, steer.clone()); }
+            }
+            Action::Flee { target, from, speed, radius } => {
+                let steer = Steering { location: from, speed, radius: radius.max(0.0), flee: true };
+                for name in self.store.get_names(&target) { self.steering.insert(name, steer.clone()); }
+            }
+            Action::StopSteering { target } => {
+                for name in self.store.get_names(&target) { self.steering.remove(&name); }
+            }
             Action::SetMomentum { target, value } => {
                 self.store.apply_to_targets(&target, |obj| obj.momentum = value);
             }
+            Action::Jump { target, strength } => {
+                self.store.apply_to_targets(&target, |obj| { obj.jump(strength); });
+            }
+            Action::SetState { target, state } => self.set_state(&target, &state),
+            Action::Attach { child, parent, offset, anchor } => self.attach(&child, &parent, offset, anchor),
+            Action::Detach { child } => self.detach(&child),
+            Action::ClampToBounds { target } => {
+                let indices = self.store.get_indices(&target);
+                self.clamp_to_bounds(&indices);
+            }
             Action::SetResistance { target, value } => {
                 self.store.apply_to_targets(&target, |obj| obj.resistance = value);
             }
+            Action::SetAcceleration { target, value } => {
+                self.store.apply_to_targets(&target, |obj| obj.acceleration = value);
+            }
             Action::Remove { target } => {
+                // Resolved to names up front: each removal shifts indices,
+                // so a wide target like `Target::All` can't skip objects.
                 let names = self.store.get_names(&target);
                 for name in names { self.remove_game_object(&name); }
             }
+            Action::Destroy { target, effect } => {
+                let names    = self.store.get_names(&target);
+                let previous = self.store.current.take();
+                for name in names {
+                    // An earlier effect may have removed this object.
+                    if !self.store.name_to_index.contains_key(&name) { continue; }
+                    if let Some(effect) = &effect {
+                        self.store.current = Some(name.clone());
+                        self.run((**effect).clone());
+                    }
+                    self.remove_game_object(&name);
+                }
+                self.store.current = previous;
+            }
             Action::Spawn { object, location } => {
-                let position = location.resolve_position(&self.store);
-                let mut new_obj = *object;
-                new_obj.position = position;
-                let name = format!("spawned_{}", new_obj.id);
-                self.add_game_object(name, new_obj);
+                let position = self.resolve_location(&location);
+                self.spawn_at(*object, position);
+            }
+            Action::SpawnWithMomentum { mut object, location, inherit_from, extra_momentum } => {
+                let position = self.resolve_location(&location);
+                let inherited = inherit_from
+                    .and_then(|t| self.store.get_indices(&t).first().map(|&i| self.store.objects[i].momentum))
+                    .unwrap_or((0.0, 0.0));
+                object.momentum.0 += inherited.0 + extra_momentum.0;
+                object.momentum.1 += inherited.1 + extra_momentum.1;
+                self.spawn_at(*object, position);
+            }
+            Action::SpawnRelativeToCamera { mut object, offset } => {
+                // Screen-space objects skip the camera offset, so their
+                // position is already relative to the view.
+                object.ignore_zoom = true;
+                self.spawn_at(*object, offset);
+            }
+            Action::SpawnMany { object, locations } => {
+                for location in locations {
+                    let position = self.resolve_location(&location);
+                    self.spawn_at((*object).clone(), position);
+                }
+            }
+            Action::EmitBurst { emitter, location, count } => {
+                let origin = self.resolve_location(&location);
+                self.emit_burst(&emitter, origin, count);
+            }
+            Action::SpawnGrid { object, rows, cols, spacing, origin } => {
+                let (ox, oy) = self.resolve_location(&origin);
+                for row in 0..rows {
+                    for col in 0..cols {
+                        let position = (ox + col as f32 * spacing.0, oy + row as f32 * spacing.1);
+                        self.spawn_at((*object).clone(), position);
+                    }
+                }
             }
             Action::TransferMomentum { from, to, scale } => {
                 let from_indices = self.store.get_indices(&from);
@@ -177,22 +379,65 @@ impl Canvas {
                 let indices = self.store.get_indices(&target);
                 for idx in indices {
                     if let Some(obj) = self.store.objects.get_mut(idx) {
-                        if let Ok(sprite) = AnimatedSprite::new(animation_bytes, obj.size, fps) {
+                        if let Ok(sprite) = self.animation_cache.get_or_decode(animation_bytes, obj.size, fps) {
                             obj.set_animation(sprite);
                         }
                     }
                 }
             }
+            Action::SetAnimationHandle { target, handle } => {
+                if let Some(sprite) = self.animation_cache.get(handle) {
+                    self.store.apply_to_targets(&target, |obj| obj.set_animation(sprite.clone()));
+                }
+            }
+            Action::PlayAnimation { target, name } => {
+                self.store.apply_to_targets(&target, |obj| obj.play_animation(&name));
+            }
+            Action::PauseAnimation { target } => {
+                self.store.apply_to_targets(&target, |obj| {
+                    if let Some(sprite) = obj.animated_sprite.as_mut() { sprite.pause(); }
+                });
+            }
+            Action::ResumeAnimation { target } => {
+                self.store.apply_to_targets(&target, |obj| {
+                    if let Some(sprite) = obj.animated_sprite.as_mut() { sprite.resume(); }
+                });
+            }
+            Action::SetAnimationFps { target, fps } => {
+                self.store.apply_to_targets(&target, |obj| {
+                    if let Some(sprite) = obj.animated_sprite.as_mut() { sprite.set_fps(fps); }
+                });
+            }
+            Action::SetAnimationFrame { target, frame } => {
+                self.store.apply_to_targets(&target, |obj| {
+                    if let Some(sprite) = obj.animated_sprite.as_mut() { sprite.set_frame(frame); }
+                });
+            }
+            Action::SetAnimationMode { target, mode } => {
+                self.store.apply_to_targets(&target, |obj| {
+                    if let Some(sprite) = obj.animated_sprite.as_mut() { sprite.set_mode(mode); }
+                });
+            }
+            Action::SetAnimationDirection { target, direction } => {
+                self.store.apply_to_targets(&target, |obj| {
+                    if let Some(sprite) = obj.animated_sprite.as_mut() { sprite.set_direction(direction); }
+                });
+            }
             Action::Teleport { target, location } => {
-                let position = location.resolve_position(&self.store);
+                let position = self.resolve_location(&location);
                 let indices = self.store.get_indices(&target);
                 for idx in indices {
                     if let Some(obj) = self.store.objects.get_mut(idx) {
                         obj.position = position;
+                        obj.previous_position = position;
                         self.layout.offsets[idx] = position;
                     }
                 }
             }
+            Action::LookAt { target, at } => self.look_at(&target, &at, None),
+            Action::LookAtSmooth { target, at, max_turn_rate } => {
+                self.look_at(&target, &at, Some(max_turn_rate.abs()));
+            }
             Action::Show   { target } => self.store.apply_to_targets(&target, |obj| obj.visible = true),
             Action::Hide   { target } => self.store.apply_to_targets(&target, |obj| obj.visible = false),
             Action::Toggle { target } => self.store.apply_to_targets(&target, |obj| obj.visible = !obj.visible),
@@ -209,6 +454,7 @@ impl Canvas {
                     self.callbacks.custom.insert(name, handler);
                 }
             }
+            Action::Callback(mut f) => f(self),
             Action::SetVar { name, value } => {
                 if let Some(resolved) = resolve_expr(&value, &self.game_vars) {
                     self.game_vars.insert(name, resolved);
@@ -226,45 +472,63 @@ impl Canvas {
             Action::Multi(actions) => {
                 for action in actions { self.run(action); }
             }
+            Action::Repeat { times, action } => {
+                for _ in 0..times.min(Action::MAX_REPEAT) { self.run((*action).clone()); }
+            }
+            Action::ForEach { target, action } => {
+                let names    = self.store.get_names(&target);
+                let previous = self.store.current.take();
+                for name in names {
+                    // Earlier iterations may have removed this object.
+                    if !self.store.name_to_index.contains_key(&name) { continue; }
+                    self.store.current = Some(name);
+                    self.run((*action).clone());
+                }
+                self.store.current = previous;
+            }
             Action::PlaySound { path, options } => {
                 self.play_sound_with(&path, options);
             }
             Action::SetGravity { target, value } => {
-                self.store.apply_to_targets(&target, |obj| obj.gravity = value);
+                self.store.apply_to_targets(&target, |obj| {
+                    obj.gravity        = value;
+                    obj.global_gravity = false;
+                });
             }
-            Action::SetSize { target, value } => {
-                let scale = self.layout.scale.get();
-                let indices = self.store.get_indices(&target);
-                for idx in indices {
-                    if let Some(obj) = self.store.objects.get_mut(idx) {
-                        obj.size = value;
-                        obj.scaled_size.set((value.0 * scale, value.1 * scale));
-                        obj.update_image_shape();
-                    }
-                }
+            Action::UseGlobalGravity { target } => {
+                let global = self.global_gravity;
+                self.store.apply_to_targets(&target, |obj| {
+                    obj.gravity        = global;
+                    obj.global_gravity = true;
+                });
             }
-            Action::AddTag { target, tag } => {
-                let indices = self.store.get_indices(&target);
-                for idx in indices {
-                    if let Some(obj) = self.store.objects.get_mut(idx) {
-                        if !obj.tags.contains(&tag) {
-                            obj.tags.push(tag.clone());
-                            self.store.tag_to_indices.entry(tag.clone()).or_default().push(idx);
-                        }
-                    }
-                }
+            Action::SetGlobalGravity { value } => self.set_global_gravity(value),
+            Action::SetGravityEnabled { target, enabled } => {
+                self.store.apply_to_targets(&target, |obj| obj.gravity_enabled = enabled);
             }
-            Action::RemoveTag { target, tag } => {
+            Action::SetKinematic { target, kinematic } => {
+                self.store.apply_to_targets(&target, |obj| obj.kinematic = kinematic);
+            }
+            Action::SetSize { target, value } => self.resize(&target, value, Anchor::BOTTOM_CENTER),
+            Action::SetSizeAnchored { target, value, anchor } => self.resize(&target, value, anchor),
+            Action::SetRenderScale { target, value } => {
+                let scale = self.layout.scale.get();
                 let indices = self.store.get_indices(&target);
                 for idx in indices {
                     if let Some(obj) = self.store.objects.get_mut(idx) {
-                        obj.tags.retain(|t| t != &tag);
-                    }
-                    if let Some(v) = self.store.tag_to_indices.get_mut(&tag) {
-                        v.retain(|&i| i != idx);
+                        obj.render_scale = value;
+                        let visual = obj.visual_scale();
+                        obj.scaled_size.set((
+                            obj.size.0 * visual.0 * scale,
+                            obj.size.1 * visual.1 * scale,
+                        ));
+                        obj.update_image_shape();
                     }
                 }
             }
+            Action::AddTag    { target, tag } => self.add_tag(&target, &tag),
+            Action::RemoveTag { target, tag } => self.remove_tag(&target, &tag),
+            Action::ChangeTag { target, from, to } => self.change_tag(&target, &from, &to),
             Action::SetText { target, text } => {
                 let indices = self.store.get_indices(&target);
                 for idx in indices {
@@ -273,6 +537,15 @@ impl Canvas {
                     }
                 }
             }
+            Action::SetLabel { target, text } => {
+                if let Some(content) = resolve_expr(&text, &self.game_vars).map(|v| v.to_display_string()) {
+                    self.store.apply_to_targets(&target, |obj| {
+                        if let Some(label) = obj.label.as_mut() { label.content = content.clone(); }
+                        obj.update_label();
+                    });
+                }
+            }
+            Action::Log(message) => println!("[step {}] {message}", self.step_count),
             Action::Expr(src) => {
                 match parse_action(&src) {
                     Ok(actions) => {
@@ -307,6 +580,9 @@ impl Canvas {
             Action::AddRotation { target, value } => {
                 self.store.apply_to_targets(&target, |obj| { obj.rotation += value; });
             }
+            Action::SetSpin { target, rate } => {
+                self.store.apply_to_targets(&target, |obj| obj.angular_velocity = rate);
+            }
             Action::ApplyRotation { target, value } => {
                 self.store.apply_to_targets(&target, |obj| { obj.rotation_momentum += value; });
             }
@@ -367,6 +643,9 @@ impl Canvas {
             Action::SetDensity { target, value } => {
                 self.store.apply_to_targets(&target, |obj| obj.material.density = value);
             }
+            Action::SetMass { target, value } => {
+                self.store.apply_to_targets(&target, |obj| obj.mass = value.max(0.001));
+            }
             Action::ApplyForce { target, fx, fy } => {
                 for name in self.store.get_names(&target) {
                     self.apply_physics_force(&name, fx, fy);
@@ -388,21 +667,7 @@ impl Canvas {
                 }
             }
             Action::SetCameraRelative { target, enabled } => {
-                let indices = self.store.get_indices(&target);
-                for idx in indices {
-                    if let Some(obj) = self.store.objects.get_mut(idx) {
-                        let tag = "ui".to_string();
-                        if enabled && !obj.tags.contains(&tag) {
-                            obj.tags.push(tag.clone());
-                            self.store.tag_to_indices.entry(tag).or_default().push(idx);
-                        } else if !enabled {
-                            obj.tags.retain(|t| t != "ui");
-                            if let Some(v) = self.store.tag_to_indices.get_mut("ui") {
-                                v.retain(|&i| i != idx);
-                            }
-                        }
-                    }
-                }
+                if enabled { self.add_tag(&target, "ui"); } else { self.remove_tag(&target, "ui"); }
             }
             Action::WakeBody { target } => {
                 for name in self.store.get_names(&target) { self.wake_body(&name); }
@@ -473,17 +738,16 @@ impl Canvas {
             }
             Action::SetZoom { value } => {
                 if let Some(cam) = &mut self.active_camera {
-                    cam.zoom        = value.max(0.01);
-                    cam.zoom_target = value.max(0.01);
+                    cam.snap_zoom(value);
                 }
             }
             Action::AddZoom { value } => {
                 if let Some(cam) = &mut self.active_camera {
-                    let new_val = (cam.zoom + value).max(0.01);
-                    cam.zoom        = new_val;
-                    cam.zoom_target = new_val;
+                    let new_val = cam.zoom + value;
+                    cam.snap_zoom(new_val);
                 }
             }
+            Action::ZoomTo { value, duration } => self.zoom_to(value, duration),
             Action::SmoothZoom { value } => {
                 self.smooth_zoom(value);
             }
@@ -494,6 +758,13 @@ impl Canvas {
                     self.smooth_zoom(self.get_zoom() * (1.0 + delta));
                 }
             }
+            Action::Shake { intensity, duration } => self.shake(intensity, duration),
+            Action::Hitstop { duration } => self.hitstop(duration),
+            Action::Blink { target, duration, interval } => self.blink(&target, duration, interval),
+            Action::Oscillate { target, axis, amplitude, frequency } => {
+                self.oscillate(&target, axis, amplitude, frequency)
+            }
+            Action::StopOscillation { target, axis } => self.stop_oscillation(&target, axis),
             Action::CameraShake { intensity, duration } => {
                 if let Some(cam) = &mut self.active_camera {
                     cam.shake(intensity, duration);
@@ -612,6 +883,39 @@ impl Canvas {
         }
     }
 
+    /// `SetSize`/`SetSizeAnchored`: resizes each target keeping `anchor` in
+    /// place, or its bottom edge if it's grounded.
+    fn resize(&mut self, target: &Target, value: (f32, f32), anchor: Anchor) {
+        let scale = self.layout.scale.get();
+        let indices = self.store.get_indices(target);
+        for idx in indices {
+            if let Some(obj) = self.store.objects.get_mut(idx) {
+                let anchor = if obj.grounded { Anchor::new(anchor.x, 1.0) } else { anchor };
+                let before = obj.position;
+                let pinned = obj.get_anchor_position(anchor);
+                obj.size = value;
+                obj.set_anchor_position(anchor, pinned);
+                // Move the interpolation origin along, so the resize
+                // doesn't render as a slide.
+                obj.previous_position.0 += obj.position.0 - before.0;
+                obj.previous_position.1 += obj.position.1 - before.1;
+                let visual = obj.visual_scale();
+                obj.scaled_size.set((
+                    value.0 * visual.0 * scale,
+                    value.1 * visual.1 * scale,
+                ));
+                obj.update_image_shape();
+                self.layout.offsets[idx] = rotation_adjusted_offset(
+                    obj.position,
+                    obj.size,
+                    obj.rotation,
+                    obj.slope.is_some(),
+                    obj.pivot,
+                );
+            }
+        }
+    }
+
     pub fn add_event(&mut self, event: crate::types::GameEvent, target: Target) {
         let indices = self.store.get_indices(&target);
         for idx in indices {
@@ -621,6 +925,35 @@ impl Canvas {
         }
     }
 
+    /// `add_event` for each pair, in order.
+    pub fn add_events(&mut self, events: Vec<(crate::types::GameEvent, Target)>) {
+        for (event, target) in events { self.add_event(event, target); }
+    }
+
+    /// Gives `event` to every object whose `id` is `id`: those already on
+    /// the canvas and every one added later, spawned clones and pool
+    /// objects included (they keep their template's id). Spawn a template
+    /// as often as you like and each copy behaves.
+    pub fn add_template_event(&mut self, id: &str, event: crate::types::GameEvent) {
+        let indices: Vec<usize> = (0..self.store.objects.len())
+            .filter(|&i| self.store.objects[i].id == id)
+            .collect();
+        for idx in indices { self.store.events[idx].push(event.clone()); }
+        self.template_events.entry(id.to_string()).or_default().push(event);
+    }
+
+    /// Stops giving `id`'s template events to new objects. Objects that
+    /// already have them keep them.
+    pub fn clear_template_events(&mut self, id: &str) {
+        self.template_events.remove(id);
+    }
+
+    /// Appends the template events for the object at `idx`'s id.
+    pub(crate) fn attach_template_events(&mut self, idx: usize) {
+        let Some(events) = self.template_events.get(&self.store.objects[idx].id) else { return; };
+        self.store.events[idx].extend(events.iter().cloned());
+    }
+
     pub fn on_update<F>(&mut self, callback: F)
     where
         F: FnMut(&mut Canvas) + Clone + 'static,
@@ -635,6 +968,26 @@ impl Canvas {
         self.callbacks.custom.insert(name, Box::new(handler));
     }
 
+    /// Replaces the default platform response. Whenever the built-in physics
+    /// pushes an object out of a platform (or stops it against one while
+    /// sweeping), `handler` gets the object, the platform and the contact,
+    /// with the normal pointing away from the platform, and decides what
+    /// happens to the object's momentum. The push-out itself, landing,
+    /// conveyor `surface_velocity` and collision events are unchanged. The
+    /// default cancels the inward momentum. Not called under crystalline
+    /// physics.
+    pub fn set_collision_response<F>(&mut self, handler: F)
+    where
+        F: FnMut(&mut GameObject, &GameObject, Contact) + Clone + 'static,
+    {
+        self.callbacks.collision_response = Some(Box::new(handler));
+    }
+
+    /// Restores the default platform response.
+    pub fn clear_collision_response(&mut self) {
+        self.callbacks.collision_response = None;
+    }
+
     pub fn set_camera(&mut self, camera: Camera)        { self.active_camera = Some(camera); }
     pub fn clear_camera(&mut self)                      { self.active_camera = None; }
     pub fn camera(&self)     -> Option<&Camera>         { self.active_camera.as_ref() }
@@ -656,6 +1009,21 @@ impl Canvas {
         }
     }
 
+    /// Sets the zoom at once, keeping the view centre (or `zoom_anchor`)
+    /// in place. Same as `zoom_to(value, 0.0)`; no-op without a camera.
+    pub fn set_zoom(&mut self, value: f32) {
+        self.zoom_to(value, 0.0);
+    }
+
+    /// Eases the camera zoom to `value` over `duration` seconds: in for a
+    /// cutscene, out for a boss arena. See `Camera::zoom_to`. No-op if no
+    /// camera is set.
+    pub fn zoom_to(&mut self, value: f32, duration: f32) {
+        if let Some(cam) = &mut self.active_camera {
+            cam.zoom_to(value, duration);
+        }
+    }
+
     /// Get current (interpolated) zoom level. Returns 1.0 if no camera is set.
     pub fn get_zoom(&self) -> f32 {
         self.active_camera.as_ref().map(|c| c.zoom).unwrap_or(1.0)
@@ -720,4 +1088,100 @@ impl Canvas {
     pub fn pause(&mut self)         { self.paused = true; }
     pub fn resume(&mut self)        { self.paused = false; }
     pub fn is_paused(&self) -> bool { self.paused }
-}
\ No newline at end of file
+
+    /// Sets the world gravity (momentum per step, default 0) for every
+    /// object with `global_gravity`, now and when added later. With
+    /// `gravity_enabled` off an object gets no gravity at all; otherwise one
+    /// following the world gets this value and any other its own `gravity`.
+    /// `Action::SetGravity` is the per-object override: it sets `gravity`
+    /// and stops the object following the world.
+    pub fn set_global_gravity(&mut self, gravity: f32) {
+        self.global_gravity = gravity;
+        for obj in self.store.objects.iter_mut().filter(|o| o.global_gravity) {
+            obj.gravity = gravity;
+        }
+    }
+
+    pub fn global_gravity(&self) -> f32 { self.global_gravity }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::object::GameObject;
+    use crate::testing::{canvas, object, run};
+    use crate::types::{Action, Anchor, GameEvent, Location, Target};
+
+    #[test]
+    fn destroy_effect_spawns_where_each_object_was() {
+        let mut cv = canvas();
+        cv.add_game_object("bat".into(), object("bat", (300.0, 200.0), (20.0, 20.0)).with_tag("enemy").with_velocity((5.0, 0.0)));
+        cv.add_game_object("rat".into(), object("rat", (40.0, 60.0), (20.0, 20.0)).with_tag("enemy"));
+        run(&mut cv, 2);
+
+        let puff = Action::spawn(object("puff", (0.0, 0.0), (4.0, 4.0)), Location::at_target(Target::current()));
+        cv.run(Action::destroy(Target::tag("enemy"), Some(puff)));
+
+        assert!(cv.get_game_object("bat").is_none());
+        assert!(cv.get_game_object("rat").is_none());
+        let mut puffs: Vec<(f32, f32)> = cv.store.objects.iter().filter(|o| o.id == "puff").map(|o| o.position).collect();
+        puffs.sort_by(|a, b| a.0.total_cmp(&b.0));
+        assert_eq!(puffs, [(40.0, 60.0), (310.0, 200.0)]);
+    }
+
+    #[test]
+    fn spawned_copies_run_their_template_events() {
+        let mut cv = canvas();
+        let walk_left = GameEvent::tick(Action::set_velocity(Target::self_object(), -3.0, 0.0), Target::self_object());
+        let bat = GameObject::build("bat").size(20.0, 20.0).event(walk_left).finish();
+        let fall = GameEvent::tick(Action::set_velocity(Target::self_object(), 0.0, 2.0), Target::self_object());
+        cv.add_template_event("rat", fall);
+
+        cv.run(Action::spawn(bat, Location::at(500.0, 100.0)));
+        cv.run(Action::spawn(object("rat", (0.0, 0.0), (20.0, 20.0)), Location::at(200.0, 100.0)));
+        run(&mut cv, 4);
+
+        let position = |id: &str| cv.store.objects.iter().find(|o| o.id == id).unwrap().position;
+        assert_eq!(position("bat"), (488.0, 100.0));
+        assert_eq!(position("rat"), (200.0, 108.0));
+    }
+
+    #[test]
+    fn every_spawn_gets_its_own_resolvable_name() {
+        let mut cv = canvas();
+        let coin = object("coin", (0.0, 0.0), (8.0, 8.0));
+        cv.add_game_object("spawned_coin_3".into(), coin.clone());
+
+        let mut names: Vec<String> = (0..5).map(|i| cv.spawn(coin.clone(), Location::at(i as f32 * 10.0, 0.0))).collect();
+        for _ in 0..5 { cv.run(Action::spawn(coin.clone(), Location::at(0.0, 50.0))); }
+        names.extend(cv.store.names.iter().skip(6).cloned());
+
+        assert_eq!(names.len(), 10);
+        assert!(!names.contains(&"spawned_coin_3".to_string()));
+        let mut unique = names.clone();
+        unique.sort();
+        unique.dedup();
+        assert_eq!(unique.len(), 10);
+        for name in &names {
+            assert!(cv.get_game_object(name).is_some(), "{name} can't be looked up");
+        }
+        assert_eq!(cv.get_game_object(&names[2]).unwrap().position, (20.0, 0.0));
+
+        cv.remove_game_object(&names[0]);
+        assert!(cv.get_game_object(&names[0]).is_none());
+        assert!(names[1..].iter().all(|n| cv.get_game_object(n).is_some()));
+        assert_eq!(cv.store.objects.len(), 10);
+    }
+
+    #[test]
+    fn set_size_grows_from_the_feet_unless_anchored() {
+        let mut cv = canvas();
+        cv.add_game_object("hero".into(), object("hero", (100.0, 100.0), (20.0, 20.0)));
+        cv.run(Action::set_size(Target::name("hero"), 40.0, 40.0));
+        let hero = cv.get_game_object("hero").unwrap();
+        assert_eq!((hero.position, hero.size), ((90.0, 80.0), (40.0, 40.0)));
+
+        cv.run(Action::set_size_anchored(Target::name("hero"), 20.0, 20.0, Anchor::TOP_LEFT));
+        let hero = cv.get_game_object("hero").unwrap();
+        assert_eq!((hero.position, hero.size), ((90.0, 80.0), (20.0, 20.0)));
+    }
+}
@@ -0,0 +1,81 @@
+use super::core::Canvas;
+use super::physics::rotation_adjusted_offset;
+use crate::types::{Anchor, Target};
+
+/// A persistent child → parent binding. See `Canvas::attach`.
+#[derive(Clone, Debug)]
+pub(crate) struct Attachment {
+    pub(crate) child:  String,
+    pub(crate) parent: String,
+    pub(crate) anchor: Anchor,
+    pub(crate) offset: (f32, f32),
+}
+
+impl Canvas {
+    /// Binds each `child` to the first object of `parent`: every step, after
+    /// physics, the child's top-left is placed at the parent's `anchor` point
+    /// plus `offset`. While the parent is flipped the binding is mirrored
+    /// (anchor x, offset x and the child's side) and the child is flipped
+    /// with it. Re-attaching a child replaces its old binding. Removing the
+    /// parent orphans the child where it is.
+    pub fn attach(&mut self, child: &Target, parent: &Target, offset: (f32, f32), anchor: Anchor) {
+        let Some(parent) = self.store.get_names(parent).into_iter().next() else { return; };
+        for child in self.store.get_names(child) {
+            if child == parent { continue; }
+            self.attachments.retain(|a| a.child != child);
+            self.attachments.push(Attachment { child, parent: parent.clone(), anchor, offset });
+        }
+        self.apply_attachments();
+    }
+
+    /// Unbinds each target from its parent, leaving it in place.
+    pub fn detach(&mut self, child: &Target) {
+        let names = self.store.get_names(child);
+        self.attachments.retain(|a| !names.contains(&a.child));
+    }
+
+    /// Name of the object `name` is attached to, if any.
+    pub fn parent_of(&self, name: &str) -> Option<&str> {
+        self.attachments.iter().find(|a| a.child == name).map(|a| a.parent.as_str())
+    }
+
+    /// Moves attached children onto their parents, in attach order so a
+    /// child attached to another child follows it the same step.
+    pub(crate) fn apply_attachments(&mut self) {
+        for i in 0..self.attachments.len() {
+            let Attachment { child, parent, anchor, offset } = &self.attachments[i];
+            let (Some(&c), Some(&p)) = (self.store.name_to_index.get(child), self.store.name_to_index.get(parent))
+            else { continue; };
+
+            let parent_obj = &self.store.objects[p];
+            let flipped = parent_obj.flip_x();
+            let point = if flipped {
+                let ap = parent_obj.get_anchor_position(Anchor::new(1.0 - anchor.x, anchor.y));
+                (ap.0 - offset.0, ap.1 + offset.1)
+            } else {
+                let ap = parent_obj.get_anchor_position(*anchor);
+                (ap.0 + offset.0, ap.1 + offset.1)
+            };
+            let momentum = parent_obj.momentum;
+            let moved = (
+                parent_obj.position.0 - parent_obj.previous_position.0,
+                parent_obj.position.1 - parent_obj.previous_position.1,
+            );
+
+            let obj = &mut self.store.objects[c];
+            let x = if flipped { point.0 - obj.size.0 } else { point.0 };
+            obj.position = (x, point.1);
+            // Interpolate along the parent's path so the pair renders together.
+            obj.previous_position = (x - moved.0, point.1 - moved.1);
+            obj.momentum = momentum;
+            obj.set_flip_x(flipped);
+            self.layout.offsets[c] = rotation_adjusted_offset(
+                obj.position,
+                obj.size,
+                obj.rotation,
+                obj.slope.is_some(),
+                obj.pivot,
+            );
+        }
+    }
+}
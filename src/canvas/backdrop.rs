@@ -0,0 +1,128 @@
+use prism::canvas::{Image, ShapeType, Color};
+use image::{RgbaImage, Rgba};
+use std::sync::Arc;
+
+use super::core::Canvas;
+
+impl Canvas {
+    /// Fills the virtual region behind every object with `color`. The fill
+    /// is part of the draw tree, so it scales with the canvas and stays inside
+    /// the letterbox padding.
+    pub fn set_background_color(&mut self, color: Color) {
+        self.background_color = Some(color);
+        self.rebuild_backdrop();
+        self.rebuild_render_order();
+    }
+
+    pub fn clear_background_color(&mut self) {
+        self.background_color = None;
+        self.rebuild_backdrop();
+        self.rebuild_render_order();
+    }
+
+    /// Fills the letterbox bars outside the virtual region with `color`.
+    /// The bars are drawn above everything, hiding objects that stray off
+    /// the canvas. Has no effect in `CanvasMode::Fullscreen`.
+    pub fn set_letterbox_color(&mut self, color: Color) {
+        self.letterbox_color = Some(color);
+        self.rebuild_backdrop();
+        self.rebuild_render_order();
+    }
+
+    pub fn clear_letterbox_color(&mut self) {
+        self.letterbox_color = None;
+        self.rebuild_backdrop();
+        self.rebuild_render_order();
+    }
+
+    pub fn background_color(&self) -> Option<Color> { self.background_color }
+    pub fn letterbox_color(&self)  -> Option<Color> { self.letterbox_color }
+
+    /// Resizes the background and letterbox drawables to the current scale.
+    /// Called every frame, but only rebuilds when the scale, virtual size,
+    /// mode or either color changed since the last build.
+    pub(crate) fn rebuild_backdrop(&mut self) {
+        let base_scale = self.layout.base_scale.get();
+        let (vw, vh)   = self.layout.canvas_size.get();
+        let rgba = |c: Color| [c.0, c.1, c.2, c.3];
+        let key = (base_scale, (vw, vh), self.layout.mode, self.background_color.map(rgba), self.letterbox_color.map(rgba));
+        if self.backdrop_key == Some(key) { return; }
+        self.backdrop_key = Some(key);
+
+        let pixel: Arc<RgbaImage> = Arc::new(RgbaImage::from_pixel(1, 1, Rgba([255, 255, 255, 255])));
+        let fill = |size: (f32, f32), color: Color| Image {
+            shape: ShapeType::Rectangle(0.0, (size.0 * base_scale, size.1 * base_scale), 0.0),
+            image: Arc::clone(&pixel),
+            color: Some(color),
+        };
+
+        self.background_image = self.background_color.map(|c| fill((vw, vh), c));
+
+        self.letterbox_images.clear();
+        self.layout.letterbox_offsets.clear();
+        let Some(color) = self.letterbox_color else { return; };
+        if self.layout.mode.virtual_resolution().is_none() { return; }
+
+        // Bars extend far past the window edge; the window clips them.
+        let b = vw.max(vh) * 4.0;
+        let bars = [
+            ((-b, -b), (b, vh + 2.0 * b)),
+            ((vw, -b), (b, vh + 2.0 * b)),
+            ((0.0, -b), (vw, b)),
+            ((0.0, vh), (vw, b)),
+        ];
+        for (offset, size) in bars {
+            self.letterbox_images.push(fill(size, color));
+            self.layout.letterbox_offsets.push(offset);
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::sync::Arc;
+
+    use prism::canvas::{Color, ShapeType};
+
+    use crate::canvas::Canvas;
+    use crate::testing::{canvas, run};
+
+    fn background_pixels(cv: &Canvas) -> Arc<image::RgbaImage> {
+        Arc::clone(&cv.background_image.as_ref().unwrap().image)
+    }
+
+    fn background_size(cv: &Canvas) -> (f32, f32) {
+        match cv.background_image.as_ref().unwrap().shape {
+            ShapeType::Rectangle(_, size, _) => size,
+            _ => panic!("background isn't a rectangle"),
+        }
+    }
+
+    #[test]
+    fn backdrop_rebuilds_only_when_something_changes() {
+        let mut cv = canvas();
+        cv.set_window_size((1920.0, 1200.0));
+        cv.set_background_color(Color(10, 20, 30, 255));
+        cv.set_letterbox_color(Color(0, 0, 0, 255));
+        let first = background_pixels(&cv);
+        let bar = Arc::clone(&cv.letterbox_images[0].image);
+
+        run(&mut cv, 3);
+        assert!(Arc::ptr_eq(&background_pixels(&cv), &first));
+        assert!(Arc::ptr_eq(&cv.letterbox_images[0].image, &bar));
+
+        cv.set_window_size((960.0, 600.0));
+        run(&mut cv, 1);
+        assert!(!Arc::ptr_eq(&background_pixels(&cv), &first));
+        assert_eq!(background_size(&cv), (960.0, 540.0));
+
+        let resized = background_pixels(&cv);
+        cv.set_background_color(Color(200, 0, 0, 255));
+        assert!(!Arc::ptr_eq(&background_pixels(&cv), &resized));
+        let color = cv.background_image.as_ref().unwrap().color.map(|c| [c.0, c.1, c.2, c.3]);
+        assert_eq!(color, Some([200, 0, 0, 255]));
+
+        cv.clear_letterbox_color();
+        assert!(cv.letterbox_images.is_empty());
+    }
+}
@@ -0,0 +1,59 @@
+use super::core::Canvas;
+use crate::types::Target;
+
+/// An object flashing on and off. Registered by `Action::Blink`.
+#[derive(Clone, Debug)]
+pub(crate) struct Blink {
+    pub(crate) elapsed:  f32,
+    pub(crate) duration: f32,
+    /// Seconds per on or off phase.
+    pub(crate) interval: f32,
+}
+
+impl Canvas {
+    /// Flashes each target on and off every `interval` seconds for
+    /// `duration` seconds, starting with it hidden, then leaves it drawn.
+    /// Only drawing is affected: a blinking object keeps moving, colliding
+    /// and firing events, unlike one with `visible` off. Blinking again
+    /// restarts it; a zero duration or interval stops it at once.
+    pub fn blink(&mut self, target: &Target, duration: f32, interval: f32) {
+        for name in self.store.get_names(target) {
+            if duration <= 0.0 || interval <= 0.0 {
+                self.stop_blink(&name);
+                continue;
+            }
+            self.blinks.insert(name, Blink { elapsed: 0.0, duration, interval });
+        }
+        self.advance_blinks(0.0);
+    }
+
+    pub fn is_blinking(&self, name: &str) -> bool {
+        self.blinks.contains_key(name)
+    }
+
+    /// Advances every blink by `dt`, finishing the ones that have run out
+    /// with their object drawn whatever phase they were in.
+    pub(crate) fn advance_blinks(&mut self, dt: f32) {
+        if self.blinks.is_empty() { return; }
+        let mut finished = Vec::new();
+        for (name, blink) in self.blinks.iter_mut() {
+            blink.elapsed += dt;
+            let Some(&idx) = self.store.name_to_index.get(name) else { continue; };
+            let obj = &mut self.store.objects[idx];
+            if blink.elapsed >= blink.duration {
+                obj.blink_hidden = false;
+                finished.push(name.clone());
+            } else {
+                obj.blink_hidden = (blink.elapsed / blink.interval) as u32 % 2 == 0;
+            }
+        }
+        for name in finished { self.blinks.remove(&name); }
+    }
+
+    fn stop_blink(&mut self, name: &str) {
+        self.blinks.remove(name);
+        if let Some(&idx) = self.store.name_to_index.get(name) {
+            self.store.objects[idx].blink_hidden = false;
+        }
+    }
+}
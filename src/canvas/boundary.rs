@@ -0,0 +1,129 @@
+use super::core::Canvas;
+use super::physics::rotation_adjusted_offset;
+
+/// One side of the canvas bounds. See `Canvas::set_boundary`.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Edge {
+    Left,
+    Right,
+    Top,
+    Bottom,
+}
+
+impl Edge {
+    pub const ALL: [Edge; 4] = [Edge::Left, Edge::Right, Edge::Top, Edge::Bottom];
+}
+
+/// What happens to an object that reaches an edge. See `Canvas::set_boundary`.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum BoundaryBehavior {
+    /// Fires the object's `BoundaryCollision` events while it touches the
+    /// edge, and nothing else.
+    #[default]
+    Event,
+    /// Stops it flush against the edge, cancelling momentum into it.
+    Block,
+    /// Stops it against the edge and reverses momentum into it.
+    Bounce,
+    /// Once it's entirely past the edge, moves it in from the opposite one.
+    Wrap,
+    /// Removes it once it's entirely past the edge.
+    Destroy,
+}
+
+impl Canvas {
+    /// Sets how objects behave at one edge of the bounds. Every edge starts
+    /// as `Event`, which only fires `BoundaryCollision` events; the others
+    /// act on their own, without any handler, and don't fire events. A
+    /// breakout field: walls and ceiling `Bounce`, floor `Destroy`.
+    ///
+    /// The acting behaviours use the bounds `clamp_to_canvas` and `wrap` use
+    /// (the camera's world when one is active, else the canvas) and apply to
+    /// visible non-platform, non-kinematic objects after movement, before
+    /// the per-object clamp and wrap.
+    pub fn set_boundary(&mut self, edge: Edge, behavior: BoundaryBehavior) {
+        self.boundaries[edge as usize] = behavior;
+    }
+
+    /// `set_boundary` for all four edges.
+    pub fn set_boundaries(&mut self, behavior: BoundaryBehavior) {
+        self.boundaries = [behavior; 4];
+    }
+
+    pub fn boundary(&self, edge: Edge) -> BoundaryBehavior {
+        self.boundaries[edge as usize]
+    }
+
+    /// Applies every edge's behaviour other than `Event`.
+    pub(crate) fn apply_boundary_behaviors(&mut self) {
+        if self.boundaries.iter().all(|&b| b == BoundaryBehavior::Event) { return; }
+        let bounds = self.clamp_bounds();
+        let [left, right, top, bottom] = self.boundaries;
+        let mut destroyed = Vec::new();
+
+        for (idx, obj) in self.store.objects.iter_mut().enumerate() {
+            if !obj.visible || obj.is_platform || obj.kinematic { continue; }
+            let (min_x, min_y, max_x, max_y) = obj.extent();
+            let (w, h) = (max_x - min_x, max_y - min_y);
+
+            // (behaviour, distance past the edge, whether it's entirely
+            // past, axis, outward sign, wrap shift)
+            let edges = [
+                (left,   -min_x,            max_x < 0.0,      0, -1.0,  bounds.0 + w),
+                (right,  max_x - bounds.0,  min_x > bounds.0, 0,  1.0, -(bounds.0 + w)),
+                (top,    -min_y,            max_y < 0.0,      1, -1.0,  bounds.1 + h),
+                (bottom, max_y - bounds.1,  min_y > bounds.1, 1,  1.0, -(bounds.1 + h)),
+            ];
+            let mut moved = false;
+            for (behavior, past, beyond, axis, outward, wrap_shift) in edges {
+                if past <= 0.0 { continue; }
+                let (pos, mom, prev) = if axis == 0 {
+                    (&mut obj.position.0, &mut obj.momentum.0, &mut obj.previous_position.0)
+                } else {
+                    (&mut obj.position.1, &mut obj.momentum.1, &mut obj.previous_position.1)
+                };
+                match behavior {
+                    BoundaryBehavior::Event => {}
+                    BoundaryBehavior::Block | BoundaryBehavior::Bounce => {
+                        *pos -= past * outward;
+                        if *mom * outward > 0.0 {
+                            *mom = if behavior == BoundaryBehavior::Bounce { -*mom } else { 0.0 };
+                        }
+                        moved = true;
+                    }
+                    BoundaryBehavior::Wrap if beyond => {
+                        *pos  += wrap_shift;
+                        *prev += wrap_shift;
+                        moved = true;
+                    }
+                    BoundaryBehavior::Destroy if beyond => destroyed.push(idx),
+                    BoundaryBehavior::Wrap | BoundaryBehavior::Destroy => {}
+                }
+            }
+            if moved {
+                self.layout.offsets[idx] = rotation_adjusted_offset(
+                    obj.position,
+                    obj.size,
+                    obj.rotation,
+                    obj.slope.is_some(),
+                    obj.pivot,
+                );
+            }
+        }
+
+        destroyed.dedup();
+        let names: Vec<String> = destroyed.into_iter().map(|idx| self.store.names[idx].clone()).collect();
+        for name in names { self.remove_game_object(&name); }
+    }
+
+    /// Whether the object at `idx` touches an `Event` edge of the canvas,
+    /// so its `BoundaryCollision` events should run.
+    pub(crate) fn touches_event_edge(&self, idx: usize) -> bool {
+        let obj = &self.store.objects[idx];
+        let (w, h) = self.layout.canvas_size.get();
+        let (min_x, min_y, max_x, max_y) = obj.extent();
+        let touching = [min_x <= 0.0, max_x >= w, min_y <= 0.0, max_y >= h];
+        Edge::ALL.iter().zip(touching)
+            .any(|(&edge, touch)| touch && self.boundary(edge) == BoundaryBehavior::Event)
+    }
+}
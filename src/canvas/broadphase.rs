@@ -0,0 +1,176 @@
+use std::collections::HashMap;
+
+use super::core::Canvas;
+use super::physics::collision_aabb;
+use crate::object::GameObject;
+
+/// How the built-in physics picks the object pairs it tests for overlap.
+/// See `Canvas::set_broadphase`.
+#[derive(Debug, Clone, Copy, PartialEq, Default)]
+pub enum BroadPhase {
+    /// Every visible pair. Cheapest for a few dozen objects.
+    #[default]
+    BruteForce,
+    /// Only pairs sharing a cell of a `cell_size` pixel grid, so the work
+    /// grows with the number of objects rather than its square.
+    Grid { cell_size: f32 },
+}
+
+/// Objects spanning more grid cells than this (long floors, walls) skip
+/// the grid and are paired with everything, as with `BruteForce`.
+const MAX_CELLS_PER_OBJECT: i64 = 64;
+
+impl Canvas {
+    /// Chooses how collision pairs are found. The default `BruteForce`
+    /// compares every visible pair; for hundreds of small objects
+    /// `Grid { cell_size }` only compares objects sharing a cell. A cell
+    /// size around the size of the common objects is a good start: much
+    /// smaller and each object fills many cells, much larger and each cell
+    /// holds many objects. Both the platform sweep and the overlap pass go
+    /// through it; `stats().collision_checks` counts the pairs they
+    /// actually compare, to tune it by. Results are the same either way,
+    /// and are resolved in the same order. Crystalline physics has its own
+    /// broadphase and ignores this.
+    pub fn set_broadphase(&mut self, broadphase: BroadPhase) {
+        self.broadphase = match broadphase {
+            BroadPhase::Grid { cell_size } => BroadPhase::Grid { cell_size: cell_size.max(1.0) },
+            other => other,
+        };
+    }
+
+    pub fn broadphase(&self) -> BroadPhase { self.broadphase }
+
+    /// Visible (i, j) pairs with i < j that might overlap, ascending.
+    pub(crate) fn candidate_pairs(&self) -> Box<dyn Iterator<Item = (usize, usize)> + '_> {
+        self.pairs_by(collision_aabb)
+    }
+
+    /// Like `candidate_pairs`, for pairs that might meet while each moves by
+    /// `fraction` of its momentum: objects are binned by the box they sweep.
+    pub(crate) fn swept_candidate_pairs(&self, fraction: f32) -> Box<dyn Iterator<Item = (usize, usize)> + '_> {
+        self.pairs_by(move |obj| {
+            let (x, y, w, h) = collision_aabb(obj);
+            let (dx, dy) = if obj.kinematic { (0.0, 0.0) } else { (obj.momentum.0 * fraction, obj.momentum.1 * fraction) };
+            (x.min(x + dx), y.min(y + dy), w + dx.abs(), h + dy.abs())
+        })
+    }
+
+    /// Candidate pairs, binning each object by the (x, y, w, h) box `aabb`
+    /// gives it.
+    fn pairs_by<F>(&self, aabb: F) -> Box<dyn Iterator<Item = (usize, usize)> + '_>
+    where
+        F: Fn(&GameObject) -> (f32, f32, f32, f32),
+    {
+        let objects = &self.store.objects;
+        let n = objects.len();
+        match self.broadphase {
+            BroadPhase::BruteForce => Box::new(
+                (0..n).filter(move |&i| objects[i].visible).flat_map(move |i| {
+                    ((i + 1)..n).filter(move |&j| objects[j].visible).map(move |j| (i, j))
+                }),
+            ),
+            BroadPhase::Grid { cell_size } => Box::new(self.grid_pairs(cell_size, aabb).into_iter()),
+        }
+    }
+
+    fn grid_pairs<F>(&self, cell_size: f32, aabb: F) -> Vec<(usize, usize)>
+    where
+        F: Fn(&GameObject) -> (f32, f32, f32, f32),
+    {
+        let mut cells: HashMap<(i64, i64), Vec<usize>> = HashMap::new();
+        let mut oversized = Vec::new();
+        let cell = |v: f32| (v / cell_size).floor() as i64;
+
+        for (idx, obj) in self.store.objects.iter().enumerate() {
+            if !obj.visible { continue; }
+            let (x, y, w, h) = aabb(obj);
+            let (x0, y0, x1, y1) = (cell(x), cell(y), cell(x + w), cell(y + h));
+            if (x1 - x0 + 1) * (y1 - y0 + 1) > MAX_CELLS_PER_OBJECT {
+                oversized.push(idx);
+                continue;
+            }
+            for cx in x0..=x1 {
+                for cy in y0..=y1 { cells.entry((cx, cy)).or_default().push(idx); }
+            }
+        }
+
+        let mut pairs = Vec::new();
+        for members in cells.values() {
+            for (k, &i) in members.iter().enumerate() {
+                pairs.extend(members[k + 1..].iter().map(|&j| (i, j)));
+            }
+        }
+        for &big in &oversized {
+            let others = self.store.objects.iter().enumerate()
+                .filter(|&(j, o)| o.visible && j != big)
+                .map(|(j, _)| (big.min(j), big.max(j)));
+            pairs.extend(others);
+        }
+        // Indices are pushed in ascending order per cell, so every pair is
+        // already (low, high); sorting restores the brute-force order.
+        pairs.sort_unstable();
+        pairs.dedup();
+        pairs
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::collections::HashSet;
+
+    use super::BroadPhase;
+    use crate::canvas::physics::collision_aabb;
+    use crate::canvas::Canvas;
+    use crate::testing::{canvas, object, platform, run};
+
+    /// 80 small drifting boxes over a floor long enough to skip the grid.
+    fn crowd(broadphase: BroadPhase) -> Canvas {
+        let mut cv = canvas();
+        cv.set_broadphase(broadphase);
+        cv.add_game_object("floor".into(), platform("floor", (0.0, 900.0), (3800.0, 20.0)));
+        for i in 0..80 {
+            let name = format!("box{i}");
+            let position = ((i % 16) as f32 * 23.0, (i / 16) as f32 * 31.0);
+            let momentum = ((i % 5) as f32 - 2.0, 1.0 + (i % 3) as f32);
+            cv.add_game_object(name.clone(), object(&name, position, (20.0, 20.0)).with_velocity(momentum));
+        }
+        cv
+    }
+
+    fn overlapping(cv: &Canvas, pairs: impl Iterator<Item = (usize, usize)>) -> HashSet<(usize, usize)> {
+        let objects = &cv.store.objects;
+        pairs.filter(|&(i, j)| {
+            let (ax, ay, aw, ah) = collision_aabb(&objects[i]);
+            let (bx, by, bw, bh) = collision_aabb(&objects[j]);
+            ax < bx + bw && bx < ax + aw && ay < by + bh && by < ay + ah
+        }).collect()
+    }
+
+    #[test]
+    fn grid_misses_no_overlapping_pair() {
+        let brute = crowd(BroadPhase::BruteForce);
+        let grid = crowd(BroadPhase::Grid { cell_size: 24.0 });
+        let expected = overlapping(&brute, brute.candidate_pairs());
+        assert!(!expected.is_empty());
+        assert_eq!(overlapping(&grid, grid.candidate_pairs()), expected);
+        assert!(grid.candidate_pairs().any(|(i, _)| i == 0), "the floor is paired despite spanning many cells");
+    }
+
+    #[test]
+    fn grid_plays_out_the_same_with_fewer_checks() {
+        let mut brute = crowd(BroadPhase::BruteForce);
+        let mut grid = crowd(BroadPhase::Grid { cell_size: 24.0 });
+        run(&mut brute, 120);
+        run(&mut grid, 120);
+        let positions = |cv: &Canvas| cv.store.objects.iter().map(|o| o.position).collect::<Vec<_>>();
+        assert_eq!(positions(&grid), positions(&brute));
+        assert!(grid.frame_stats.collision_checks < brute.frame_stats.collision_checks);
+    }
+
+    #[test]
+    fn cell_size_is_clamped_to_a_pixel() {
+        let mut cv = canvas();
+        cv.set_broadphase(BroadPhase::Grid { cell_size: 0.0 });
+        assert_eq!(cv.broadphase(), BroadPhase::Grid { cell_size: 1.0 });
+    }
+}
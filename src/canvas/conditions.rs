@@ -4,11 +4,41 @@ use crate::expr::parse_condition;
 use crate::types::Condition;
 
 impl Canvas {
-    pub(crate) fn evaluate_condition(&self, condition: &Condition) -> bool {
+    /// Checks `condition`, then restarts the cooldowns that let it pass.
+    /// A ready cooldown whose `And` partner fails, or that sits under a
+    /// `Not`, is left ready.
+    pub(crate) fn evaluate_condition(&mut self, condition: &Condition) -> bool {
+        let mut ready = Vec::new();
+        let passed = self.check_condition(condition, &mut ready);
+        if passed {
+            for key in ready { self.cooldowns.insert(key, 0.0); }
+        }
+        passed
+    }
+
+    /// How many of the objects `target` resolves to are visible.
+    fn visible_count(&self, target: &crate::types::Target) -> usize {
+        self.store.get_indices(target).iter().filter(|&&i| self.store.objects[i].visible).count()
+    }
+
+    /// `check_condition` that only adds to `ready` if `condition` passes.
+    fn check_scoped(&mut self, condition: &Condition, ready: &mut Vec<String>) -> bool {
+        let mut own = Vec::new();
+        let passed = self.check_condition(condition, &mut own);
+        if passed { ready.extend(own); }
+        passed
+    }
+
+    /// Evaluates `condition` without side effects on cooldowns, collecting
+    /// the keys of ready cooldowns that counted towards the result.
+    fn check_condition(&mut self, condition: &Condition, ready: &mut Vec<String>) -> bool {
         match condition {
             Condition::Always => true,
             Condition::KeyHeld(k)    =>  self.input.held_keys.contains(k),
             Condition::KeyNotHeld(k) => !self.input.held_keys.contains(k),
+            Condition::KeyHeldFor { key, seconds } => {
+                self.input.held_durations.get(key).is_some_and(|held| *held >= *seconds)
+            }
             Condition::Collision(t) => {
                 self.store.get_indices(t).iter().any(|&i| {
                     (0..self.store.objects.len()).any(|j| {
@@ -20,10 +50,22 @@ impl Canvas {
                     })
                 })
             }
-            Condition::NoCollision(t) => !self.evaluate_condition(&Condition::Collision(t.clone())),
-            Condition::And(c1, c2) => self.evaluate_condition(c1) && self.evaluate_condition(c2),
-            Condition::Or(c1, c2)  => self.evaluate_condition(c1) || self.evaluate_condition(c2),
-            Condition::Not(c)      => !self.evaluate_condition(c),
+            Condition::NoCollision(t) => !self.check_condition(&Condition::Collision(t.clone()), ready),
+            Condition::Partner(t) => {
+                let Some(partner) = self.store.partner.as_ref() else { return false; };
+                match self.store.name_to_index.get(partner) {
+                    Some(idx) => self.store.get_indices(t).contains(idx),
+                    None => matches!(t, crate::types::Target::ByName(n) if n == partner),
+                }
+            }
+            Condition::And(c1, c2) => {
+                let mut own = Vec::new();
+                let passed = self.check_condition(c1, &mut own) && self.check_condition(c2, &mut own);
+                if passed { ready.extend(own); }
+                passed
+            }
+            Condition::Or(c1, c2)  => self.check_scoped(c1, ready) || self.check_scoped(c2, ready),
+            Condition::Not(c)      => !self.check_condition(c, &mut Vec::new()),
             Condition::IsVisible(t) => self.store.get_indices(t).iter()
                 .any(|&i| self.store.objects.get(i).map_or(false, |o| o.visible)),
             Condition::IsHidden(t)  => self.store.get_indices(t).iter()
@@ -45,7 +87,7 @@ impl Canvas {
             }
             Condition::Expr(src) => {
                 match parse_condition(src) {
-                    Ok(condition) => self.evaluate_condition(&condition),
+                    Ok(condition) => self.check_condition(&condition, ready),
                     Err(e) => {
                         debug_assert!(false,
                             "[Condition::Expr] parse error in \"{src}\": {e}\n\
@@ -54,6 +96,58 @@ impl Canvas {
                     }
                 }
             }
+            Condition::Chance(p) => self.entropy.chance(p.clamp(0.0, 1.0)),
+            Condition::RatePerSecond(p) => {
+                let per_step = 1.0 - (1.0 - p.clamp(0.0, 1.0)).powf(self.step_delta);
+                self.entropy.chance(per_step)
+            }
+            Condition::Cooldown { key, seconds } => {
+                let is_ready = self.cooldowns.get(key).map_or(true, |elapsed| *elapsed >= *seconds);
+                if is_ready { ready.push(key.clone()); }
+                is_ready
+            }
+            Condition::CountEquals { target, value }  => self.visible_count(target) == *value,
+            Condition::CountGreater { target, value } => self.visible_count(target) > *value,
+            Condition::WithinDistance { a, b, distance } => {
+                let max_sq = distance * distance;
+                let others = self.store.get_indices(b);
+                self.store.get_indices(a).iter().any(|&i| {
+                    let (ax, ay) = self.store.objects[i].center();
+                    others.iter().any(|&j| {
+                        if i == j { return false; }
+                        let (bx, by) = self.store.objects[j].center();
+                        (ax - bx) * (ax - bx) + (ay - by) * (ay - by) <= max_sq
+                    })
+                })
+            }
+            Condition::InState(target, state) => {
+                self.store.get_indices(target).iter().any(|&idx| {
+                    self.store.objects.get(idx)
+                        .and_then(|obj| obj.state_machine.as_ref())
+                        .map_or(false, |m| m.state() == state)
+                })
+            }
+            Condition::TimeInState(target, seconds) => {
+                self.store.get_indices(target).iter().any(|&idx| {
+                    self.store.objects.get(idx)
+                        .and_then(|obj| obj.state_machine.as_ref())
+                        .map_or(false, |m| m.time_in_state() >= *seconds)
+                })
+            }
+            Condition::AnimationFinished(target) => {
+                self.store.get_indices(target).iter().any(|&idx| {
+                    self.store.objects.get(idx)
+                        .and_then(|obj| obj.animated_sprite.as_ref())
+                        .map_or(false, |s| s.is_finished())
+                })
+            }
+            Condition::AnimationFrame(target, frame) => {
+                self.store.get_indices(target).iter().any(|&idx| {
+                    self.store.objects.get(idx)
+                        .and_then(|obj| obj.animation_frame())
+                        .map_or(false, |f| f == *frame)
+                })
+            }
             Condition::HasTag(target, tag) => {
                 self.store.get_indices(target).iter().any(|&idx| {
                     self.store.objects.get(idx).map_or(false, |obj| obj.tags.contains(tag))
@@ -384,3 +478,66 @@ fn value_type_name(value: &Value) -> &'static str {
         Value::Str(_)   => "Str",
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use crate::testing::{canvas, count, object, run};
+    use crate::types::{Action, Condition, ConditionOps, GameEvent, Target};
+
+    #[test]
+    fn cooldown_restarts_only_when_the_whole_condition_passes() {
+        let mut cv = canvas();
+        let never = || Condition::VarExists("nope".into());
+        let cooldown = |key: &str| Condition::cooldown(key, 1.0);
+
+        assert!(!cv.evaluate_condition(&cooldown("a").and(never())));
+        assert!(!cv.evaluate_condition(&never().and(cooldown("a"))));
+        assert!(cv.evaluate_condition(&cooldown("b").and(never()).or(Condition::Always)));
+        assert!(!cv.evaluate_condition(&cooldown("c").not()));
+        assert!(cv.cooldowns.is_empty());
+
+        assert!(cv.evaluate_condition(&Condition::Always.and(cooldown("a"))));
+        assert!(!cv.evaluate_condition(&cooldown("a")));
+        assert!(cv.evaluate_condition(&cooldown("b")));
+    }
+
+    #[test]
+    fn counts_skip_hidden_objects() {
+        let mut cv = canvas();
+        for i in 0..3 {
+            let name = format!("minion{i}");
+            cv.add_game_object(name.clone(), object(&name, (i as f32 * 30.0, 0.0), (10.0, 10.0)).with_tag("enemy"));
+        }
+        let enemies = || Target::tag("enemy");
+        assert!(cv.evaluate_condition(&Condition::count_equals(enemies(), 3)));
+
+        cv.run(Action::hide(Target::name("minion1")));
+        assert!(cv.evaluate_condition(&Condition::count_equals(enemies(), 2)));
+        assert!(!cv.evaluate_condition(&Condition::count_greater(enemies(), 2)));
+        assert!(cv.evaluate_condition(&Condition::count_greater(enemies(), 1)));
+
+        cv.run(Action::hide(enemies()));
+        assert!(cv.evaluate_condition(&Condition::count_equals(enemies(), 0)));
+        assert!(cv.evaluate_condition(&Condition::count_equals(Target::tag("boss"), 0)));
+        assert!(!cv.evaluate_condition(&Condition::count_greater(Target::tag("boss"), 0)));
+    }
+
+    #[test]
+    fn gated_shot_fires_as_soon_as_it_is_armed() {
+        let mut cv = canvas();
+        cv.add_game_object("gun".into(), object("gun", (0.0, 0.0), (10.0, 10.0)));
+        cv.set_var("shots", 0);
+        let gate = Condition::VarExists("armed".into()).and(Condition::cooldown("shot", 0.5));
+        cv.add_event(GameEvent::tick(Action::when_if(gate, count("shots")), Target::self_object()), Target::name("gun"));
+
+        run(&mut cv, 40);
+        assert_eq!(cv.get_i32("shots"), 0);
+        cv.set_var("armed", true);
+        run(&mut cv, 1);
+        assert_eq!(cv.get_i32("shots"), 1);
+        run(&mut cv, 20);
+        assert_eq!(cv.get_i32("shots"), 1);
+        run(&mut cv, 20);
+        assert_eq!(cv.get_i32("shots"), 2);
+    }
+}
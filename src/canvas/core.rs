@@ -1,9 +1,9 @@
 use prism::drawable::{Component, Drawable};
 use prism::layout::{Area, SizeRequest, Layout};
 use std::cell::Cell;
-use std::collections::HashMap;
+use std::collections::{HashMap, HashSet};
 
-use prism::canvas::Image;
+use prism::canvas::{Image, Color};
 use crate::store::ObjectStore;
 use crate::input::{InputState, MouseState, CallbackStore};
 use crate::scene::SceneManager;
@@ -15,10 +15,20 @@ use crate::crystalline::{CrystallinePhysics, ParticleSystem, ParticleState};
 use crate::constraints::GrappleConstraint;
 
 
+/// Scale, virtual size, mode and background/letterbox RGBA the backdrop
+/// drawables were built for.
+pub(crate) type BackdropKey = (f32, (f32, f32), CanvasMode, Option<[u8; 4]>, Option<[u8; 4]>);
+
 #[derive(Clone, Copy, Debug)]
 pub(crate) enum RenderSlot {
     Object(usize),
     Particle(usize),
+    /// Background fill, always drawn first.
+    Background,
+    /// Debug-draw outline, drawn above objects and particles.
+    Debug(usize),
+    /// Letterbox bar, always drawn last.
+    Letterbox(usize),
 }
 
 
@@ -51,15 +61,24 @@ impl CanvasMode {
 pub struct CanvasLayout {
     pub offsets:                  Vec<(f32, f32)>,
     pub(crate) particle_offsets:  Vec<(f32, f32)>,
+    pub(crate) letterbox_offsets: Vec<(f32, f32)>,
+    pub(crate) debug_offsets:     Vec<(f32, f32)>,
     pub(crate) sorted_offsets:    Vec<(f32, f32)>,
     pub canvas_size:              Cell<(f32, f32)>,
     pub mode:                     CanvasMode,
     pub scale:                    Cell<f32>,
+    /// `scale` without camera zoom. Set by `update_transform`.
+    pub(crate) base_scale:        Cell<f32>,
     pub safe_area_offset:         Cell<(f32, f32)>,
     pub(crate) zoom:              Cell<f32>,
     pub(crate) sorted_ignore_zoom: Vec<bool>,
+    /// Whether each sorted child moves with `shake_offset`: objects,
+    /// particles and debug outlines do, the background and letterbox don't.
+    pub(crate) sorted_shakes:     Vec<bool>,
     /// Actual window size in physical pixels, updated each frame by build().
     pub(crate) actual_size:       Cell<(f32, f32)>,
+    /// Render-only offset from `Canvas::shake`, in virtual pixels.
+    pub(crate) shake_offset:      Cell<(f32, f32)>,
 }
 
 impl Layout for CanvasLayout {
@@ -74,41 +93,77 @@ impl Layout for CanvasLayout {
             "CanvasLayout: sorted_offsets count must match child count"
         );
 
-        // Store the actual window size so virtual_scale() can use it.
-        self.actual_size.set(size);
-
-        let (base_scale, padding_x, padding_y, virtual_res) = match self.mode.virtual_resolution() {
-            None => (1.0_f32, 0.0_f32, 0.0_f32, size),
-            Some(vres) => {
-                let s  = (size.0 / vres.0).min(size.1 / vres.1);
-                let pw = (size.0 - vres.0 * s) / 2.0;
-                let ph = (size.1 - vres.1 * s) / 2.0;
-                (s, pw, ph, vres)
-            }
-        };
-
-        let zoom = self.zoom.get().max(0.01);
-        let scale = base_scale * zoom;
-
-        self.scale.set(scale);
-        self.safe_area_offset.set((padding_x, padding_y));
-        self.canvas_size.set(virtual_res);
+        let (base_scale, _, _) = self.update_transform(size);
+        let scale = self.scale.get();
+        let shake = self.shake_offset.get();
 
         self.sorted_offsets.iter()
             .copied()
             .zip(self.sorted_ignore_zoom.iter().copied())
+            .zip(self.sorted_shakes.iter().copied())
             .zip(children)
-            .map(|((offset, no_zoom), child)| {
+            .map(|(((offset, no_zoom), shakes), child)| {
                 let s = if no_zoom { base_scale } else { scale };
                 let child_size = child.get((f32::MAX, f32::MAX));
+                let (shake_x, shake_y) = if shakes { shake } else { (0.0, 0.0) };
                 Area {
-                    offset: (offset.0 * s + padding_x, offset.1 * s + padding_y),
+                    offset: self.virtual_to_screen((offset.0 + shake_x, offset.1 + shake_y), no_zoom),
                     size:   (child_size.0 * s, child_size.1 * s),
                 }
             }).collect()
     }
 }
 
+impl CanvasLayout {
+    /// The single virtual↔screen transform. For a window of `size` returns
+    /// `(base_scale, padding, virtual_res)` such that
+    ///
+    /// ```text
+    /// screen = virtual * base_scale * zoom + padding
+    /// ```
+    ///
+    /// Fixed-resolution modes fit the virtual canvas inside the window and
+    /// centre it, leaving letterbox padding on the long axis; any aspect
+    /// ratio works. `Fullscreen` maps 1:1 with no padding.
+    pub(crate) fn fit(mode: CanvasMode, size: (f32, f32)) -> (f32, (f32, f32), (f32, f32)) {
+        match mode.virtual_resolution() {
+            None => (1.0, (0.0, 0.0), size),
+            Some(vres) => {
+                let s = (size.0 / vres.0).min(size.1 / vres.1).max(0.0);
+                (s, ((size.0 - vres.0 * s) / 2.0, (size.1 - vres.1 * s) / 2.0), vres)
+            }
+        }
+    }
+
+    /// Recomputes the transform for a window of `size` and stores it, so
+    /// layout, input and screen-space placement all read the same values.
+    pub(crate) fn update_transform(&self, size: (f32, f32)) -> (f32, (f32, f32), (f32, f32)) {
+        let (base_scale, padding, virtual_res) = Self::fit(self.mode, size);
+        self.actual_size.set(size);
+        self.base_scale.set(base_scale);
+        self.scale.set(base_scale * self.zoom.get().max(0.01));
+        self.safe_area_offset.set(padding);
+        self.canvas_size.set(virtual_res);
+        (base_scale, padding, virtual_res)
+    }
+
+    /// Virtual point → window pixels. `ignore_zoom` skips camera zoom, as for
+    /// `ignore_zoom` objects and screen pins.
+    pub(crate) fn virtual_to_screen(&self, point: (f32, f32), ignore_zoom: bool) -> (f32, f32) {
+        let s = self.base_scale.get() * if ignore_zoom { 1.0 } else { self.zoom.get().max(0.01) };
+        let (pad_x, pad_y) = self.safe_area_offset.get();
+        (point.0 * s + pad_x, point.1 * s + pad_y)
+    }
+
+    /// Window pixels → virtual point; the exact inverse of `virtual_to_screen`.
+    pub(crate) fn screen_to_virtual(&self, point: (f32, f32), ignore_zoom: bool) -> (f32, f32) {
+        let s = self.base_scale.get() * if ignore_zoom { 1.0 } else { self.zoom.get().max(0.01) };
+        if s <= 0.0 { return point; }
+        let (pad_x, pad_y) = self.safe_area_offset.get();
+        ((point.0 - pad_x) / s, (point.1 - pad_y) / s)
+    }
+}
+
 // ── Canvas ───────────────────────────────────────────────────────────────────
 
 #[derive(Clone)]
@@ -130,11 +185,91 @@ pub struct Canvas {
     pub(crate) last_particle_states:      Vec<ParticleState>,
     pub(crate) particle_images:           Vec<Image>,
     pub(crate) image_cache:               crate::assets::ImageCache,
+    pub(crate) animation_cache:           crate::assets::AnimationCache,
     pub(crate) emitter_locations:         HashMap<String, crate::types::Location>,
     pub(crate) particle_render_layers:    Vec<i32>,
     pub(crate) render_order:              Vec<RenderSlot>,
     /// Per-object grapple constraints. Key = game object name.
     pub(crate) grapple_constraints:       HashMap<String, GrappleConstraint>,
+    /// Counter used to give every spawned clone a unique name. `reset`
+    /// rewinds it, along with the clones.
+    pub(crate) spawn_counter:             u64,
+    /// Fixed simulation step in seconds. See `set_fixed_timestep`.
+    pub(crate) fixed_timestep:            f32,
+    /// Length of the step being (or last) simulated, in seconds.
+    pub(crate) step_delta:                f32,
+    /// Real time not yet consumed by fixed steps.
+    pub(crate) time_accumulator:          f32,
+    /// Game seconds per real second. See `set_time_scale`.
+    pub(crate) time_scale:                f32,
+    /// Tag pairs the built-in physics skips. See `set_tags_collide`.
+    pub(crate) collision_matrix:          super::matrix::CollisionMatrix,
+    /// See `set_platforms_collide`.
+    pub(crate) platforms_collide:         bool,
+    /// See `set_broadphase`.
+    pub(crate) broadphase:                super::broadphase::BroadPhase,
+    /// World gravity for objects with `global_gravity`. See `set_global_gravity`.
+    pub(crate) global_gravity:            f32,
+    /// Real seconds of hitstop left. See `hitstop`.
+    pub(crate) hitstop_remaining:         f32,
+    /// Whether a new hitstop adds to one in progress. See `set_hitstop_stacking`.
+    pub(crate) hitstop_stacking:          bool,
+    pub(crate) last_frame:                Option<std::time::Instant>,
+    /// Fill behind every object. See `set_background_color`.
+    pub(crate) background_color:          Option<Color>,
+    /// Fill for the bars outside the virtual region. See `set_letterbox_color`.
+    pub(crate) letterbox_color:           Option<Color>,
+    pub(crate) background_image:          Option<Image>,
+    pub(crate) letterbox_images:          Vec<Image>,
+    /// What the backdrop drawables were last built for; `rebuild_backdrop`
+    /// skips the rebuild while it matches.
+    pub(crate) backdrop_key:              Option<BackdropKey>,
+    /// See `set_debug_draw`.
+    pub(crate) debug_draw:                bool,
+    /// See `set_y_sort`.
+    pub(crate) y_sort:                    bool,
+    /// See `set_event_trace`.
+    pub(crate) event_trace:               bool,
+    /// Fixed steps run so far. See `step_count`.
+    pub(crate) step_count:                u64,
+    pub(crate) debug_images:              Vec<Image>,
+    /// Seconds since each `Condition::Cooldown` key last fired.
+    pub(crate) cooldowns:                 HashMap<String, f32>,
+    /// Seconds since each `ThrottledKeyHold` last fired, per (owner, state
+    /// the event belongs to or None for the object's own, position in that
+    /// event list), so every event keeps its own rate.
+    pub(crate) hold_timers:               HashMap<(String, Option<String>, usize), f32>,
+    /// Overlapping (a, b) name pairs as of the last step, a < b. Diffed
+    /// each step for `CollisionEnter`/`CollisionExit`.
+    pub(crate) contacts:                  HashSet<(String, String)>,
+    /// Contacts the last step's collision pass found, per object name.
+    /// See `contacts_of`.
+    pub(crate) step_contacts:             HashMap<String, Vec<super::query::Contact>>,
+    /// Scene `reset` restores. See `mark_initial_state`.
+    pub(crate) initial_state:             Option<Box<super::helpers::InitialState>>,
+    /// Child → parent bindings from `attach`, in attach order.
+    pub(crate) attachments:               Vec<super::attach::Attachment>,
+    /// Active MoveToward/Flee behaviours. Key = game object name.
+    pub(crate) steering:                  HashMap<String, super::steering::Steering>,
+    /// Objects flashing on and off, by name. See `blink`.
+    pub(crate) blinks:                    HashMap<String, super::blink::Blink>,
+    /// Events every object with a given `id` gets. See `add_template_event`.
+    pub(crate) template_events:           HashMap<String, Vec<crate::types::GameEvent>>,
+    /// Per-edge behaviour, indexed by `Edge`. See `set_boundary`.
+    pub(crate) boundaries:                [super::boundary::BoundaryBehavior; 4],
+    /// Running oscillations, at most one per axis, by object name. See
+    /// `oscillate`.
+    pub(crate) oscillations:              HashMap<String, Vec<super::oscillate::Oscillation>>,
+    /// Recording in progress and the step it started at. See `start_recording`.
+    pub(crate) recording:                 Option<(super::replay::Recording, u64)>,
+    /// See `play_recording`.
+    pub(crate) playback:                  Option<super::replay::Playback>,
+    /// Last completed frame. See `stats`.
+    pub(crate) stats:                     super::stats::TickStats,
+    /// Frame currently being measured.
+    pub(crate) frame_stats:               super::stats::TickStats,
+    /// See `shake`.
+    pub(crate) screen_shake:              Option<crate::camera::ShakeEffect>,
 }
 
 impl std::fmt::Debug for Canvas {
@@ -150,8 +285,11 @@ impl std::fmt::Debug for Canvas {
 impl Component for Canvas {
     fn children(&self) -> Vec<&dyn Drawable> {
         self.render_order.iter().map(|slot| match slot {
-            RenderSlot::Object(i)   => &self.store.objects[*i] as &dyn Drawable,
-            RenderSlot::Particle(i) => &self.particle_images[*i] as &dyn Drawable,
+            RenderSlot::Object(i)    => &self.store.objects[*i] as &dyn Drawable,
+            RenderSlot::Particle(i)  => &self.particle_images[*i] as &dyn Drawable,
+            RenderSlot::Background   => self.background_image.as_ref().unwrap() as &dyn Drawable,
+            RenderSlot::Letterbox(i) => &self.letterbox_images[*i] as &dyn Drawable,
+            RenderSlot::Debug(i)     => &self.debug_images[*i] as &dyn Drawable,
         }).collect()
     }
 
@@ -161,9 +299,17 @@ impl Component for Canvas {
             .map(|o| Some(o as &mut dyn Drawable)).collect();
         let mut part_slots: Vec<Option<&mut dyn Drawable>> = self.particle_images.iter_mut()
             .map(|i| Some(i as &mut dyn Drawable)).collect();
+        let mut bar_slots: Vec<Option<&mut dyn Drawable>> = self.letterbox_images.iter_mut()
+            .map(|i| Some(i as &mut dyn Drawable)).collect();
+        let mut debug_slots: Vec<Option<&mut dyn Drawable>> = self.debug_images.iter_mut()
+            .map(|i| Some(i as &mut dyn Drawable)).collect();
+        let mut background = self.background_image.as_mut().map(|i| i as &mut dyn Drawable);
         order.iter().map(|slot| match slot {
-            RenderSlot::Object(i)   => obj_slots[*i].take().unwrap(),
-            RenderSlot::Particle(i) => part_slots[*i].take().unwrap(),
+            RenderSlot::Object(i)    => obj_slots[*i].take().unwrap(),
+            RenderSlot::Particle(i)  => part_slots[*i].take().unwrap(),
+            RenderSlot::Background   => background.take().unwrap(),
+            RenderSlot::Letterbox(i) => bar_slots[*i].take().unwrap(),
+            RenderSlot::Debug(i)     => debug_slots[*i].take().unwrap(),
         }).collect()
     }
 
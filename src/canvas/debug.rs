@@ -0,0 +1,117 @@
+use prism::canvas::{Image, ShapeType, Color};
+use image::{RgbaImage, Rgba};
+use std::sync::Arc;
+
+use super::core::Canvas;
+use super::physics::rotation_adjusted_offset;
+use crate::types::{Action, CollisionMode, CollisionShape};
+
+const BOX_COLOR:      Color = Color(0, 255, 0, 220);
+const PLATFORM_COLOR: Color = Color(0, 160, 255, 220);
+const VELOCITY_COLOR: Color = Color(255, 220, 0, 220);
+const ANCHOR_COLOR:   Color = Color(255, 0, 255, 255);
+/// Outline width in window pixels.
+const STROKE: f32 = 2.0;
+/// Side of the anchor markers in window pixels.
+const MARKER: f32 = 6.0;
+/// Velocity arrows show where the object will be this many steps from now.
+const VELOCITY_STEPS: f32 = 10.0;
+
+impl Canvas {
+    /// Overlays each visible object's collision box (or circle), velocity
+    /// and pivot/centre on top of normal rendering. Boxes are built from the
+    /// same `position`/`size` the collision code tests, in world space.
+    pub fn set_debug_draw(&mut self, enabled: bool) {
+        self.debug_draw = enabled;
+        self.rebuild_debug_overlay();
+        self.rebuild_render_order();
+    }
+
+    pub fn debug_draw(&self) -> bool { self.debug_draw }
+
+    /// Prints every action as `run` executes it, with the step number, the
+    /// owning object of the event (if any), its target and the indices that
+    /// target resolves to. Nested actions (`Multi`, `Conditional`, ...) are
+    /// printed as they run. Off by default.
+    pub fn set_event_trace(&mut self, enabled: bool) { self.event_trace = enabled; }
+
+    pub fn event_trace(&self) -> bool { self.event_trace }
+
+    /// Fixed simulation steps run since the canvas was created.
+    pub fn step_count(&self) -> u64 { self.step_count }
+
+    pub(crate) fn trace_action(&self, action: &Action) {
+        let debug = format!("{action:?}");
+        let variant = debug.split(|c: char| !c.is_alphanumeric()).next().unwrap_or("");
+        let owner = self.store.owner.as_deref()
+            .map(|o| format!(" (owner {o})"))
+            .unwrap_or_default();
+        match action.target() {
+            Some(target) => println!(
+                "[trace step {}]{owner} {variant} {target:?} -> {:?}",
+                self.step_count, self.store.get_indices(target),
+            ),
+            None => println!("[trace step {}]{owner} {variant}", self.step_count),
+        }
+    }
+
+    /// Rebuilds the overlay drawables. Called every frame; does nothing
+    /// (and allocates nothing) while debug draw is off.
+    pub(crate) fn rebuild_debug_overlay(&mut self) {
+        self.debug_images.clear();
+        self.layout.debug_offsets.clear();
+        if !self.debug_draw { return; }
+
+        let scale = self.layout.scale.get();
+        let (cam_x, cam_y) = self.active_camera.as_ref()
+            .map(|c| {
+                let shake = c.effects.shake_offset();
+                (c.position.0 + shake.0, c.position.1 + shake.1)
+            })
+            .unwrap_or((0.0, 0.0));
+        let pixel: Arc<RgbaImage> = Arc::new(RgbaImage::from_pixel(1, 1, Rgba([255, 255, 255, 255])));
+        let marker = MARKER / scale.max(0.001);
+
+        for obj in self.store.objects.iter().filter(|o| o.visible) {
+            let mut push = |shape: ShapeType, color: Color, world: (f32, f32)| {
+                self.debug_images.push(Image { shape, image: Arc::clone(&pixel), color: Some(color) });
+                self.layout.debug_offsets.push((world.0 - cam_x, world.1 - cam_y));
+            };
+
+            // Collision bounds.
+            let color = if obj.is_platform { PLATFORM_COLOR } else { BOX_COLOR };
+            match &obj.collision_mode {
+                CollisionMode::Solid(CollisionShape::Circle { radius }) => {
+                    let r = if *radius <= 0.0 { obj.size.0.min(obj.size.1) * 0.5 } else { *radius };
+                    let (cx, cy) = obj.rotated_center();
+                    push(ShapeType::Ellipse(STROKE, (r * 2.0 * scale, r * 2.0 * scale), 0.0), color, (cx - r, cy - r));
+                }
+                _ => {
+                    let (x, y, w, h) = obj.slope_aabb();
+                    push(ShapeType::Rectangle(STROKE, (w * scale, h * scale), 0.0), color, (x, y));
+                }
+            }
+
+            // Velocity arrow: a thin bar from the centre along the momentum.
+            let (cx, cy) = obj.center();
+            let (mx, my) = obj.momentum;
+            let len = (mx * mx + my * my).sqrt() * VELOCITY_STEPS;
+            if len > 0.5 {
+                let thickness = STROKE / scale.max(0.001);
+                let size  = (len, thickness);
+                let angle = my.atan2(mx).to_degrees();
+                let at    = rotation_adjusted_offset((cx, cy - thickness * 0.5), size, angle, false, (0.0, 0.5));
+                push(ShapeType::Rectangle(0.0, (size.0 * scale, size.1 * scale), angle), VELOCITY_COLOR, at);
+            }
+
+            // Rotation pivot and geometric centre.
+            for (px, py) in [obj.pivot_world(), obj.rotated_center()] {
+                push(
+                    ShapeType::Rectangle(0.0, (MARKER, MARKER), 0.0),
+                    ANCHOR_COLOR,
+                    (px - marker * 0.5, py - marker * 0.5),
+                );
+            }
+        }
+    }
+}
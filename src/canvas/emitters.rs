@@ -0,0 +1,51 @@
+use super::core::Canvas;
+use crate::object::ObjectEmitter;
+
+impl Canvas {
+    /// Spawns `count` particles from `emitter` around `origin` and returns
+    /// their generated names.
+    pub fn emit_burst(&mut self, emitter: &ObjectEmitter, origin: (f32, f32), count: usize) -> Vec<String> {
+        (0..count).map(|_| {
+            let rolls    = (self.entropy.next(), self.entropy.next(), self.entropy.next());
+            let particle = emitter.make_particle(origin, rolls);
+            let position = particle.position;
+            self.spawn_at(particle, position)
+        }).collect()
+    }
+
+    /// Ages objects that have a lifetime, applies fade-out, tint and opacity,
+    /// removes expired objects and runs attached emitters.
+    pub(crate) fn update_object_lifetimes(&mut self, delta_time: f32) {
+        let mut expired = Vec::new();
+        let mut pending = Vec::new();
+
+        for (idx, obj) in self.store.objects.iter_mut().enumerate() {
+            if let Some(left) = obj.lifetime.as_mut() {
+                *left -= delta_time;
+                if *left <= 0.0 {
+                    expired.push(self.store.names[idx].clone());
+                    continue;
+                }
+                if obj.fade_out && obj.lifetime_total > 0.0 {
+                    obj.opacity = (*left / obj.lifetime_total).clamp(0.0, 1.0);
+                }
+            }
+            obj.apply_color();
+
+            if !obj.visible { continue; }
+            let center = obj.center();
+            if let Some(em) = obj.emitter.as_mut() {
+                if em.rate <= 0.0 { continue; }
+                em.accumulator += em.rate * delta_time;
+                let count = em.accumulator.floor() as usize;
+                if count > 0 {
+                    em.accumulator -= count as f32;
+                    pending.push(((**em).clone(), center, count));
+                }
+            }
+        }
+
+        for name in expired { self.remove_game_object(&name); }
+        for (emitter, origin, count) in pending { self.emit_burst(&emitter, origin, count); }
+    }
+}
@@ -2,7 +2,10 @@ use prism::event::{OnEvent, Event, TickEvent};
 use prism::drawable::SizedTree;
 use prism::Context;
 
-use super::core::Canvas;
+use std::time::Instant;
+
+use super::core::{Canvas, CanvasMode};
+use super::stats::elapsed_ms;
 use crate::types::GameEvent;
 
 impl OnEvent for Canvas {
@@ -21,75 +24,245 @@ impl OnEvent for Canvas {
         }
 
         if let Some(_tick) = event.downcast_ref::<TickEvent>() {
+            let now = Instant::now();
+            let frame_time = self.last_frame
+                .map(|t| now.duration_since(t).as_secs_f32())
+                .unwrap_or(self.fixed_timestep);
+            self.last_frame = Some(now);
+            self.begin_frame_stats();
+
             if self.paused {
                 self.apply_camera_transform();
                 self.sync_sorted_offsets();
+                self.end_frame_stats(now, frame_time);
                 return vec![event];
             }
-            const DELTA_TIME: f32 = 0.016;
 
-            let mut tick_cbs = std::mem::take(&mut self.callbacks.tick);
-            tick_cbs.iter_mut().for_each(|cb| cb(self));
-            self.callbacks.tick = tick_cbs;
-
-            self.process_held_key_events();
-            self.process_all_tick_events();
+            // Hitstop eats real time first; only what's left of the frame
+            // after it ends reaches the simulation.
+            let mut sim_time = frame_time.min(MAX_FRAME_TIME);
+            if self.hitstop_remaining > 0.0 {
+                let frozen = sim_time.min(self.hitstop_remaining);
+                self.hitstop_remaining -= frozen;
+                sim_time -= frozen;
+                self.advance_screen_shake(frozen);
+                self.apply_camera_transform();
+            }
 
-            if let Some(pos) = self.mouse.position {
-                let vpos = self.screen_to_virtual(pos);
-                self.process_mouse_over_events(vpos);
+            // Step the simulation at a fixed dt as many times as the elapsed
+            // (time-scaled) real time allows, so game speed doesn't follow
+            // the refresh rate.
+            let dt = self.fixed_timestep;
+            self.time_accumulator += sim_time * self.time_scale;
+            let mut steps = 0;
+            while self.time_accumulator >= dt && steps < MAX_STEPS_PER_FRAME {
+                self.fixed_step(dt);
+                self.time_accumulator -= dt;
+                steps += 1;
             }
+            // Too far behind (breakpoint, window drag): drop the backlog
+            // instead of spiralling.
+            if steps == MAX_STEPS_PER_FRAME { self.time_accumulator = 0.0; }
+            self.frame_stats.steps = steps;
+
+            self.refresh_visuals();
+            self.end_frame_stats(now, frame_time);
+        }
+
+        vec![event]
+    }
+}
+
+/// Longest real frame time fed into the accumulator, in seconds.
+const MAX_FRAME_TIME: f32 = 0.25;
+/// Upper bound on fixed steps per rendered frame.
+const MAX_STEPS_PER_FRAME: u32 = 8;
+
+impl Canvas {
+    /// Runs one simulation step of `delta` seconds synchronously, as a
+    /// `TickEvent` does for each fixed step, then refreshes the visuals a
+    /// frame would. Needs no window or wall clock, so with a seeded
+    /// `entropy` and `inject_key` a scene replays identically; e.g. a test
+    /// can step 60 times and assert on `get_game_object`. Does nothing
+    /// while paused, like a tick.
+    pub fn step(&mut self, delta: f32) {
+        if self.paused { return; }
+        self.fixed_step(delta.max(0.001));
+        self.refresh_visuals();
+    }
+
+    /// Per-frame rebuilds after the simulation steps.
+    fn refresh_visuals(&mut self) {
+        self.rebuild_backdrop();
+        self.rebuild_debug_overlay();
+        self.rebuild_particle_visuals();
+        self.sync_sorted_offsets();
+    }
+
+    /// Advances the simulation by exactly one fixed step of `dt` seconds.
+    /// Phases, in order: `on_update` callbacks, held keys, state machines,
+    /// `Tick` events (by priority), mouse-over, custom events, hot reload,
+    /// the object update (gravity, acceleration, animation), lifetimes, blinks,
+    /// oscillations, movement and collision events, planet landings, squash
+    /// and stretch, auto-align, edge behaviours, clamp/wrap/attachments,
+    /// camera, boundary events. Events therefore read the positions the previous step's
+    /// physics left. A playing recording injects its keys for the step
+    /// before any of it.
+    pub(crate) fn fixed_step(&mut self, dt: f32) {
+        self.replay_due_keys();
+        self.step_count += 1;
+        self.step_delta = dt;
+        for obj in self.store.objects.iter_mut() {
+            obj.previous_position = obj.position;
+        }
+        self.cooldowns.values_mut().for_each(|elapsed| *elapsed += dt);
+        self.hold_timers.values_mut().for_each(|elapsed| *elapsed += dt);
+        self.input.held_durations.values_mut().for_each(|held| *held += dt);
+
+        let events_start = Instant::now();
+        let mut tick_cbs = std::mem::take(&mut self.callbacks.tick);
+        tick_cbs.iter_mut().for_each(|cb| cb(self));
+        self.callbacks.tick = tick_cbs;
 
-            let custom_names: Vec<String> = self.store.events.iter()
-                .flatten()
-                .filter_map(|e| {
-                    if GameEvent::is_custom(e) {
-                        e.custom_name().map(str::to_string)
-                    } else {
-                        None
-                    }
-                })
-                .collect();
-
-            for name in custom_names {
-                if let Some(mut handler) = self.callbacks.custom.remove(&name) {
-                    handler(self);
-                    self.callbacks.custom.insert(name, handler);
+        self.process_held_key_events();
+        self.apply_state_machines(dt);
+        self.process_all_tick_events();
+
+        // mouse.position is already virtual (converted in handle_mouse_event).
+        if let Some(vpos) = self.mouse.position {
+            self.process_mouse_over_events(vpos);
+        }
+
+        let custom_names: Vec<String> = self.store.all_events()
+            .filter_map(|e| {
+                if GameEvent::is_custom(e) {
+                    e.custom_name().map(str::to_string)
+                } else {
+                    None
                 }
+            })
+            .collect();
+
+        for name in custom_names {
+            if let Some(mut handler) = self.callbacks.custom.remove(&name) {
+                handler(self);
+                self.callbacks.custom.insert(name, handler);
             }
+        }
+        self.frame_stats.events_ms += elapsed_ms(events_start);
 
-            self.process_hot_reloads(DELTA_TIME);
-            self.update_objects(DELTA_TIME);
+        self.process_hot_reloads(dt);
+        let physics_start = Instant::now();
+        self.apply_steering();
+        self.update_objects(dt);
+        self.update_object_lifetimes(dt);
+        self.advance_blinks(dt);
+        self.advance_oscillations(dt);
 
-            if self.crystalline.is_some() {
-                self.run_crystalline_step(DELTA_TIME);
-            } else {
-                self.handle_collisions();
-            }
+        if self.crystalline.is_some() {
+            self.run_crystalline_step(dt);
+        } else {
+            self.move_and_collide();
+        }
+        self.frame_stats.physics_ms += elapsed_ms(physics_start);
 
-            self.handle_planet_landings();
-            self.apply_auto_align();
-
-            self.apply_camera_transform();
-            self.rebuild_particle_visuals();
-            self.sync_sorted_offsets();
-
-            let canvas_size = self.layout.canvas_size.get();
-            let boundary_indices: Vec<usize> = self.store.objects.iter()
-                .enumerate()
-                .filter(|(_, obj)| obj.visible && obj.check_boundary_collision(canvas_size))
-                .map(|(i, _)| i)
-                .collect();
-            for idx in boundary_indices {
-                self.trigger_boundary_collision_events(idx);
-            }
+        self.handle_planet_landings();
+        self.store.objects.iter_mut().for_each(|obj| obj.update_squash_stretch());
+        self.apply_auto_align();
+
+        self.apply_boundary_behaviors();
+        let clamped: Vec<usize> = self.store.objects.iter().enumerate()
+            .filter(|(_, obj)| obj.visible && obj.clamp_to_canvas)
+            .map(|(i, _)| i)
+            .collect();
+        if !clamped.is_empty() { self.clamp_to_bounds(&clamped); }
+        self.apply_wrap();
+        self.apply_attachments();
+
+        self.apply_camera_transform();
+        self.advance_screen_shake(dt);
+
+        let boundary_indices: Vec<usize> = (0..self.store.objects.len())
+            .filter(|&i| self.store.objects[i].visible && !self.store.objects[i].wrap)
+            .filter(|&i| self.touches_event_edge(i))
+            .collect();
+        for idx in boundary_indices {
+            self.trigger_boundary_collision_events(idx);
         }
+    }
 
-        vec![event]
+    /// Sets the fixed simulation step in seconds (default 0.016).
+    pub fn set_fixed_timestep(&mut self, dt: f32) {
+        self.fixed_timestep = dt.max(0.001);
+        self.time_accumulator = self.time_accumulator.min(self.fixed_timestep);
+    }
+
+    pub fn fixed_timestep(&self) -> f32 {
+        self.fixed_timestep
+    }
+
+    /// Simulates at `hz` steps per second whatever the display refresh:
+    /// every `TickEvent` still renders (interpolated), but physics, events,
+    /// `on_update` callbacks, cooldowns and camera effects only advance on
+    /// simulation steps. Same as `set_fixed_timestep(1.0 / hz)`.
+    pub fn set_tick_rate(&mut self, hz: f32) {
+        self.set_fixed_timestep(1.0 / hz.max(f32::EPSILON));
+    }
+
+    pub fn tick_rate(&self) -> f32 {
+        1.0 / self.fixed_timestep
+    }
+
+    /// Multiplies game speed: 0.5 is slow motion, 2.0 fast-forward and 0
+    /// freezes the simulation while frames keep rendering. Each step stays
+    /// `fixed_timestep` long (callbacks read it as their delta); only how
+    /// many run per real second changes, so per-step physics, animations,
+    /// timers and tweens all scale together. Clamped to 0..=8. Doesn't
+    /// affect `step`.
+    pub fn set_time_scale(&mut self, scale: f32) {
+        self.time_scale = scale.clamp(0.0, MAX_STEPS_PER_FRAME as f32);
+    }
+
+    pub fn time_scale(&self) -> f32 {
+        self.time_scale
+    }
+
+    /// Longest total hitstop in seconds, however many stack up.
+    pub const MAX_HITSTOP: f32 = 1.0;
+
+    /// Freezes the simulation for `duration` real seconds (ignoring
+    /// `time_scale`) while frames keep rendering, then resumes on its own:
+    /// a few frames of it sell a heavy hit. Nothing steps meanwhile —
+    /// physics, animations, events, timers — except screen shake, so a
+    /// hit can freeze and shake at once. Input callbacks still run. A
+    /// hitstop during another one adds to it, up to `MAX_HITSTOP` in
+    /// total, unless `set_hitstop_stacking(false)`, which keeps whichever
+    /// is longer. Doesn't affect `step`.
+    pub fn hitstop(&mut self, duration: f32) {
+        let duration = duration.max(0.0);
+        self.hitstop_remaining = if self.hitstop_stacking {
+            (self.hitstop_remaining + duration).min(Self::MAX_HITSTOP)
+        } else {
+            self.hitstop_remaining.max(duration.min(Self::MAX_HITSTOP))
+        };
+    }
+
+    /// Whether `hitstop` extends one in progress (default) or only
+    /// replaces it when longer.
+    pub fn set_hitstop_stacking(&mut self, stacking: bool) {
+        self.hitstop_stacking = stacking;
+    }
+
+    /// Real seconds of hitstop left; 0 when running normally.
+    pub fn hitstop_remaining(&self) -> f32 {
+        self.hitstop_remaining
+    }
+
+    /// How far the renderer is between the last two physics states (0..1).
+    pub fn interpolation_alpha(&self) -> f32 {
+        (self.time_accumulator / self.fixed_timestep).clamp(0.0, 1.0)
     }
-}
 
-impl Canvas {
     pub fn canvas_size(&self) -> (f32, f32) {
         self.layout.canvas_size.get()
     }
@@ -98,19 +271,209 @@ impl Canvas {
         self.layout.canvas_size.get()
     }
 
-    pub(crate) fn screen_to_virtual(&self, screen_pos: (f32, f32)) -> (f32, f32) {
-        let scale = self.layout.scale.get();
-        let (pad_x, pad_y) = self.layout.safe_area_offset.get();
-        if scale == 0.0 { return screen_pos; }
-        ((screen_pos.0 - pad_x) / scale, (screen_pos.1 - pad_y) / scale)
+    /// Letterbox padding in window pixels: the gap between the window edge and
+    /// the virtual canvas on the left/right and top/bottom.
+    pub fn safe_area_offset(&self) -> (f32, f32) {
+        self.layout.safe_area_offset.get()
+    }
+
+    /// The visible window as `(x, y, w, h)` in virtual coordinates. Wider or
+    /// taller than the virtual canvas when letterboxed, so `x`/`y` can be
+    /// negative. Use it to place things in the true window corners.
+    pub fn window_rect(&self) -> (f32, f32, f32, f32) {
+        let (aw, ah) = self.layout.actual_size.get();
+        let (x0, y0) = self.layout.screen_to_virtual((0.0, 0.0), true);
+        let (x1, y1) = self.layout.screen_to_virtual((aw, ah), true);
+        (x0, y0, x1 - x0, y1 - y0)
+    }
+
+    /// Primes the virtual↔screen transform with the window size. Layout does
+    /// this every frame; call it at startup so input that arrives before the
+    /// first frame maps to the right virtual coordinates.
+    pub fn set_window_size(&mut self, size: (f32, f32)) {
+        self.layout.update_transform(size);
+    }
+
+    pub fn mode(&self) -> CanvasMode { self.layout.mode }
+
+    /// Switches between landscape, portrait and fullscreen at runtime, e.g.
+    /// when a device is rotated. The virtual canvas size, scale and
+    /// letterboxing are recomputed for the current window straight away,
+    /// `clamp_to_canvas` objects are pulled back inside, and a camera whose
+    /// viewport was the whole old canvas gets the new one. Then the
+    /// `on_mode_change` callbacks and `ModeChanged` events run so the game
+    /// can re-place its UI. Nothing happens if `mode` is the current mode.
+    pub fn set_mode(&mut self, mode: CanvasMode) {
+        if mode == self.layout.mode { return; }
+        let old_size = self.layout.canvas_size.get();
+        self.layout.mode = mode;
+        let (_, _, new_size) = self.layout.update_transform(self.layout.actual_size.get());
+        if let Some(cam) = self.active_camera.as_mut().filter(|cam| cam.viewport_size == old_size) {
+            cam.viewport_size = new_size;
+        }
+        self.rebuild_backdrop();
+        self.rebuild_render_order();
+
+        let clamped: Vec<usize> = self.store.objects.iter().enumerate()
+            .filter(|(_, obj)| obj.visible && obj.clamp_to_canvas)
+            .map(|(i, _)| i)
+            .collect();
+        if !clamped.is_empty() { self.clamp_to_bounds(&clamped); }
+        self.apply_camera_transform();
+
+        let mut callbacks = std::mem::take(&mut self.callbacks.mode_change);
+        callbacks.iter_mut().for_each(|cb| cb(self));
+        self.callbacks.mode_change = callbacks;
+
+        let actions: Vec<_> = (0..self.store.objects.len())
+            .flat_map(|idx| {
+                self.store.events_of(idx)
+                    .filter_map(|e| {
+                        if let GameEvent::ModeChanged { action, .. } = e { Some(action.clone()) } else { None }
+                    })
+                    .map(|a| (self.store.names[idx].clone(), a))
+                    .collect::<Vec<_>>()
+            })
+            .collect();
+        actions.into_iter().for_each(|(owner, a)| self.run_owned(owner, a));
+    }
+
+    /// Runs `callback` after every `set_mode` that changes the mode; read
+    /// the new one with `mode()` and the new size with `canvas_size()`.
+    pub fn on_mode_change<F>(&mut self, callback: F)
+    where
+        F: FnMut(&mut Canvas) + Clone + 'static,
+    {
+        self.callbacks.mode_change.push(Box::new(callback));
+    }
+
+    /// Window pixels → virtual coordinates (camera zoom included), using the
+    /// same transform layout uses to place objects.
+    pub fn screen_to_virtual(&self, screen_pos: (f32, f32)) -> (f32, f32) {
+        self.layout.screen_to_virtual(screen_pos, false)
+    }
+
+    /// Virtual coordinates → window pixels; the inverse of `screen_to_virtual`.
+    pub fn virtual_to_screen(&self, virtual_pos: (f32, f32)) -> (f32, f32) {
+        self.layout.virtual_to_screen(virtual_pos, false)
     }
 
+    /// Runs every `Tick` action once, by ascending priority, then object
+    /// order. Actions are snapshotted before any runs, so one tick removing
+    /// an object doesn't skip that object's own tick this step. Only `Tick`
+    /// actions are cloned, never whole event lists.
+    /// `Target::SelfObject` resolves to each event's owner; state-machine
+    /// ticks also set `Target::Current` to it.
     pub(crate) fn process_all_tick_events(&mut self) {
-        let actions: Vec<_> = self.store.events.iter()
-            .flatten()
-            .filter(|e| GameEvent::is_tick(e))
-            .map(|e| e.action().clone())
+        let mut actions: Vec<_> = (0..self.store.objects.len())
+            .flat_map(|idx| {
+                let own = self.store.events[idx].iter().map(|e| (false, e));
+                let state = self.store.objects[idx].state_machine.as_ref()
+                    .map_or(&[][..], |m| m.active_events())
+                    .iter()
+                    .map(|e| (true, e));
+                own.chain(state).map(move |(from_state, e)| (idx, from_state, e))
+            })
+            .filter(|(_, _, e)| GameEvent::is_tick(e))
+            .map(|(idx, from_state, e)| (e.priority(), self.store.names[idx].clone(), from_state, e.action().clone()))
             .collect();
-        actions.into_iter().for_each(|a| self.run(a));
+        // Stable, so equal priorities keep object order.
+        actions.sort_by_key(|(priority, ..)| *priority);
+
+        for (_, owner, from_state, action) in actions {
+            if from_state {
+                let previous = self.store.current.replace(owner.clone());
+                self.run_owned(owner, action);
+                self.store.current = previous;
+            } else {
+                self.run_owned(owner, action);
+            }
+        }
     }
-}
\ No newline at end of file
+}
+
+#[cfg(test)]
+mod tests {
+    use std::cell::RefCell;
+    use std::rc::Rc;
+
+    use crate::canvas::{Canvas, CanvasLayout, CanvasMode};
+    use crate::testing::{canvas, object, run};
+    use crate::types::{Action, GameEvent, Target};
+
+    #[test]
+    fn ticks_run_by_priority_then_object_order() {
+        let mut cv = canvas();
+        let order = Rc::new(RefCell::new(Vec::new()));
+        let note = |label: &'static str| {
+            let order = order.clone();
+            Action::callback(move |_| order.borrow_mut().push(label))
+        };
+        cv.add_game_object("a".into(), object("a", (0.0, 0.0), (10.0, 10.0)));
+        cv.add_game_object("b".into(), object("b", (50.0, 0.0), (10.0, 10.0)));
+        cv.add_event(GameEvent::tick(note("a late"), Target::self_object()).with_priority(5), Target::name("a"));
+        cv.add_event(GameEvent::Tick { action: note("a plain"), target: Target::self_object() }, Target::name("a"));
+        cv.add_event(GameEvent::tick(note("b plain"), Target::self_object()), Target::name("b"));
+        cv.add_event(GameEvent::tick(note("b early"), Target::self_object()).with_priority(-1), Target::name("b"));
+
+        run(&mut cv, 1);
+        assert_eq!(*order.borrow(), ["b early", "a plain", "b plain", "a late"]);
+    }
+
+    #[test]
+    fn with_priority_leaves_other_events_alone() {
+        let event = GameEvent::Custom { name: "x".into(), target: Target::all() }.with_priority(3);
+        assert!(event.is_custom());
+        assert_eq!(event.priority(), 0);
+        assert_eq!(GameEvent::tick(Action::Multi(vec![]), Target::all()).with_priority(3).priority(), 3);
+    }
+
+    fn close(a: (f32, f32), b: (f32, f32)) -> bool {
+        (a.0 - b.0).abs() < 1e-3 && (a.1 - b.1).abs() < 1e-3
+    }
+
+    /// Checks that the canvas maps to `expected_padding` and back at a
+    /// window of `size`, with and without camera zoom.
+    fn assert_round_trip(mode: CanvasMode, size: (f32, f32), expected_padding: (f32, f32)) {
+        let mut cv = Canvas::headless(mode);
+        cv.set_window_size(size);
+        let (_, padding, vres) = CanvasLayout::fit(mode, size);
+        assert!(close(padding, expected_padding), "{size:?}: padding {padding:?}");
+        assert!(close(cv.virtual_to_screen((0.0, 0.0)), padding), "{size:?}: origin");
+        assert!(close(cv.virtual_to_screen(vres), (size.0 - padding.0, size.1 - padding.1)), "{size:?}: far corner");
+        assert!(close(cv.screen_to_virtual(padding), (0.0, 0.0)), "{size:?}: origin back");
+        for zoom in [1.0, 2.5] {
+            cv.layout.zoom.set(zoom);
+            let point = (123.0, 456.0);
+            assert!(close(cv.screen_to_virtual(cv.virtual_to_screen(point)), point), "{size:?} at {zoom}x");
+        }
+    }
+
+    #[test]
+    fn windowed_landscape_letterboxes_off_16_9_sizes() {
+        assert_round_trip(CanvasMode::Landscape, (1280.0, 1024.0), (0.0, 152.0));
+        assert_round_trip(CanvasMode::Landscape, (1920.0, 1200.0), (0.0, 60.0));
+        assert_round_trip(CanvasMode::Landscape, (2560.0, 1080.0), (320.0, 0.0));
+        assert_round_trip(CanvasMode::Portrait, (1280.0, 1024.0), (352.0, 0.0));
+    }
+
+    #[test]
+    fn fullscreen_maps_one_to_one_at_any_aspect() {
+        for size in [(1366.0, 768.0), (2560.0, 1080.0), (1024.0, 1280.0)] {
+            assert_round_trip(CanvasMode::Fullscreen, size, (0.0, 0.0));
+            let mut cv = Canvas::headless(CanvasMode::Fullscreen);
+            cv.set_window_size(size);
+            assert_eq!(cv.window_rect(), (0.0, 0.0, size.0, size.1));
+        }
+    }
+
+    #[test]
+    fn input_before_the_first_frame_uses_the_primed_size() {
+        let mut cv = canvas();
+        cv.set_window_size((2560.0, 1080.0));
+        assert!(close(cv.screen_to_virtual((320.0, 0.0)), (0.0, 0.0)));
+        assert!(close(cv.screen_to_virtual((1280.0, 540.0)), (1920.0, 1080.0)));
+        let (x, y, w, h) = cv.window_rect();
+        assert!(close((x, y), (-640.0, 0.0)) && close((w, h), (5120.0, 2160.0)));
+    }
+}
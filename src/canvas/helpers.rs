@@ -1,5 +1,25 @@
 use super::core::Canvas;
 use prism::canvas::Image;
+use crate::assets::AnimationHandle;
+use crate::sprite::AnimatedSprite;
+use crate::types::{GameEvent, Target};
+use crate::value::Value;
+
+/// Snapshot taken by `Canvas::mark_initial_state`.
+#[derive(Clone)]
+pub(crate) struct InitialState {
+    store:       crate::store::ObjectStore,
+    offsets:     Vec<(f32, f32)>,
+    game_vars:   std::collections::HashMap<String, Value>,
+    attachments: Vec<super::attach::Attachment>,
+    steering:    std::collections::HashMap<String, super::steering::Steering>,
+    grapples:    std::collections::HashMap<String, crate::constraints::GrappleConstraint>,
+    blinks:      std::collections::HashMap<String, super::blink::Blink>,
+    oscillations: std::collections::HashMap<String, Vec<super::oscillate::Oscillation>>,
+    camera:      Option<crate::camera::Camera>,
+    entropy:     crate::entropy::Entropy,
+    spawn_counter: u64,
+}
 
 impl Canvas {
     pub fn get_names_by_tag(&self, tag: &str) -> Vec<String> {
@@ -17,6 +37,273 @@ impl Canvas {
     pub fn has_tag(&self, tag: &str) -> bool {
         self.store.tag_to_indices.get(tag).map_or(false, |v| !v.is_empty())
     }
+
+    /// Tags every target with `tag`, so it joins `Target::ByTag` queries
+    /// immediately. Same as `Action::AddTag`.
+    pub fn add_tag(&mut self, target: &Target, tag: &str) {
+        for idx in self.store.get_indices(target) {
+            self.store.add_tag(idx, tag);
+        }
+    }
+
+    /// Same as `Action::RemoveTag`.
+    pub fn remove_tag(&mut self, target: &Target, tag: &str) {
+        for idx in self.store.get_indices(target) {
+            self.store.remove_tag(idx, tag);
+        }
+    }
+
+    /// Same as `Action::ChangeTag`. Targets are resolved before any tag
+    /// changes, so `Target::tag(from)` moves the whole group.
+    pub fn change_tag(&mut self, target: &Target, from: &str, to: &str) {
+        for idx in self.store.get_indices(target) {
+            if self.store.remove_tag(idx, from) {
+                self.store.add_tag(idx, to);
+            }
+        }
+    }
+
+    /// Renames an object, carrying over its events and any canvas state
+    /// keyed by its name (grapples, steering, emitter bindings, grapple
+    /// anchors). Returns false if `old` doesn't exist or `new` is taken.
+    pub fn rename_object(&mut self, old: &str, new: impl Into<String>) -> bool {
+        let new = new.into();
+        if !self.store.rename(old, new.clone()) { return false; }
+
+        if let Some(g) = self.grapple_constraints.remove(old) {
+            self.grapple_constraints.insert(new.clone(), g);
+        }
+        for g in self.grapple_constraints.values_mut() {
+            if g.anchor_object.as_deref() == Some(old) { g.anchor_object = Some(new.clone()); }
+        }
+        if let Some(s) = self.steering.remove(old) {
+            self.steering.insert(new.clone(), s);
+        }
+        if let Some(b) = self.blinks.remove(old) {
+            self.blinks.insert(new.clone(), b);
+        }
+        if let Some(o) = self.oscillations.remove(old) {
+            self.oscillations.insert(new.clone(), o);
+        }
+        for a in self.attachments.iter_mut() {
+            if a.child == old { a.child = new.clone(); }
+            if a.parent == old { a.parent = new.clone(); }
+        }
+        let rekey = |n: String| if n == old { new.clone() } else { n };
+        self.contacts = std::mem::take(&mut self.contacts).into_iter()
+            .map(|(a, b)| {
+                let (a, b) = (rekey(a), rekey(b));
+                if a < b { (a, b) } else { (b, a) }
+            })
+            .collect();
+        if let Some(list) = self.step_contacts.remove(old) {
+            self.step_contacts.insert(new.clone(), list);
+        }
+        for contact in self.step_contacts.values_mut().flatten() {
+            if contact.other == old { contact.other = new.clone(); }
+        }
+        let timers: Vec<_> = self.hold_timers.keys().filter(|(owner, ..)| owner == old).cloned().collect();
+        for key in timers {
+            if let Some(elapsed) = self.hold_timers.remove(&key) {
+                self.hold_timers.insert((new.clone(), key.1, key.2), elapsed);
+            }
+        }
+        for (key, value) in self.game_vars.iter_mut() {
+            if key.starts_with("_emitter_bind_")
+                && matches!(value, Value::Str(n) if n == old)
+            {
+                *value = Value::Str(new.clone());
+            }
+        }
+        true
+    }
+}
+
+impl Canvas {
+    /// Removes every object and resets all index maps, event lists and
+    /// per-object state (contacts, grapples, steering, blinks,
+    /// oscillations, hold timers, emitter bindings). Use between levels.
+    pub fn clear(&mut self) {
+        let names = std::mem::take(&mut self.store.names);
+        self.store = crate::store::ObjectStore::new();
+        self.layout.offsets.clear();
+        self.mouse.hovered_indices.clear();
+        self.contacts.clear();
+        self.step_contacts.clear();
+        self.forget_objects(&names);
+        self.rebuild_render_order();
+    }
+
+    /// Remembers the scene as it is now — objects with their positions,
+    /// momenta, visibility and events, game vars, attachments, steering,
+    /// grapples, blinks, oscillations, the camera, the `entropy` state and
+    /// the spawn counter — for `reset`. Call it at the end of setup.
+    /// Marking again replaces the snapshot.
+    pub fn mark_initial_state(&mut self) {
+        self.initial_state = Some(Box::new(InitialState {
+            store:       self.store.clone(),
+            offsets:     self.layout.offsets.clone(),
+            game_vars:   self.game_vars.clone(),
+            attachments: self.attachments.clone(),
+            steering:    self.steering.clone(),
+            grapples:    self.grapple_constraints.clone(),
+            blinks:      self.blinks.clone(),
+            oscillations: self.oscillations.clone(),
+            camera:      self.active_camera.clone(),
+            entropy:     self.entropy.clone(),
+            spawn_counter: self.spawn_counter,
+        }));
+    }
+
+    /// Restores the scene saved by `mark_initial_state`: objects spawned
+    /// since are gone, removed ones are back, and everything else it saved
+    /// is as it was, so the next steps play out as they did after marking
+    /// (reseed `entropy` for a different run). Contacts, cooldowns,
+    /// held-key timers, screen shake, hitstop and leftover step time start
+    /// fresh, and a recording or playback in progress stops. Callbacks,
+    /// sounds and settings are untouched. Does nothing if no state was
+    /// marked.
+    pub fn reset(&mut self) {
+        let Some(initial) = self.initial_state.clone() else { return; };
+        let InitialState {
+            store, offsets, game_vars, attachments, steering, grapples, blinks, oscillations, camera, entropy,
+            spawn_counter,
+        } = *initial;
+        self.clear();
+        self.store                = store;
+        self.layout.offsets       = offsets;
+        self.game_vars            = game_vars;
+        self.attachments          = attachments;
+        self.steering             = steering;
+        self.grapple_constraints  = grapples;
+        self.blinks               = blinks;
+        self.oscillations         = oscillations;
+        self.active_camera        = camera;
+        self.entropy              = entropy;
+        self.spawn_counter        = spawn_counter;
+        self.cooldowns.clear();
+        self.time_accumulator     = 0.0;
+        self.hitstop_remaining    = 0.0;
+        self.screen_shake         = None;
+        self.layout.shake_offset.set((0.0, 0.0));
+        self.recording            = None;
+        self.playback             = None;
+        self.store.objects.iter_mut().for_each(|obj| obj.blink_hidden = false);
+        self.advance_blinks(0.0);
+        self.rebuild_render_order();
+    }
+
+    /// Removes every object carrying `tag` in a single pass, instead of
+    /// shifting indices once per object like repeated `remove_game_object`.
+    pub fn clear_tag(&mut self, tag: &str) {
+        let doomed: std::collections::HashSet<usize> = self.store.tag_to_indices.get(tag)
+            .map(|v| v.iter().copied().collect())
+            .unwrap_or_default();
+        if doomed.is_empty() { return; }
+
+        let old         = std::mem::take(&mut self.store);
+        let old_offsets = std::mem::take(&mut self.layout.offsets);
+        let mut remap   = std::collections::HashMap::new();
+        let mut removed = Vec::new();
+
+        let entries = old.names.into_iter().zip(old.objects).zip(old.events).zip(old_offsets);
+        for (i, (((name, obj), events), offset)) in entries.enumerate() {
+            if doomed.contains(&i) { removed.push(name); continue; }
+            let idx = self.store.objects.len();
+            remap.insert(i, idx);
+            self.store.add(name, obj);
+            self.store.events[idx] = events;
+            self.layout.offsets.push(offset);
+        }
+        self.store.current = old.current;
+        self.store.owner = old.owner;
+
+        self.mouse.hovered_indices = self.mouse.hovered_indices.iter()
+            .filter_map(|i| remap.get(i).copied())
+            .collect();
+        self.forget_objects(&removed);
+        self.rebuild_render_order();
+    }
+
+    /// Adds an object at position `index` in draw order (clamped to the end)
+    /// instead of on top, e.g. a backdrop added after the scene. Objects
+    /// from `index` on move up one. Layers still come first: this orders
+    /// the object among the ones sharing its `layer`.
+    pub fn add_game_object_at(&mut self, index: usize, name: String, obj: crate::GameObject) {
+        self.add_game_object(name, obj);
+        let last = self.store.objects.len() - 1;
+        let index = index.min(last);
+        if index == last { return; }
+        let order: Vec<usize> = (0..index).chain(std::iter::once(last)).chain(index..last).collect();
+        self.reorder_objects(&order);
+    }
+
+    /// Moves the targets above every other object in their layer, keeping
+    /// their order among themselves.
+    pub fn move_to_front(&mut self, target: &Target) {
+        let moved = self.store.get_indices(target);
+        let rest: Vec<usize> = (0..self.store.objects.len()).filter(|i| !moved.contains(i)).collect();
+        let order: Vec<usize> = rest.into_iter().chain(sorted(moved)).collect();
+        self.reorder_objects(&order);
+    }
+
+    /// Moves the targets below every other object in their layer, keeping
+    /// their order among themselves.
+    pub fn move_to_back(&mut self, target: &Target) {
+        let moved = self.store.get_indices(target);
+        let rest: Vec<usize> = (0..self.store.objects.len()).filter(|i| !moved.contains(i)).collect();
+        let order: Vec<usize> = sorted(moved).into_iter().chain(rest).collect();
+        self.reorder_objects(&order);
+    }
+
+    /// Puts the object at `order[k]` at index `k`, with its offset and
+    /// hover state, and redraws in the new order.
+    pub(crate) fn reorder_objects(&mut self, order: &[usize]) {
+        if order.iter().enumerate().all(|(new, &old)| new == old) { return; }
+        let mut new_index = vec![0; order.len()];
+        for (new, &old) in order.iter().enumerate() { new_index[old] = new; }
+
+        self.store.reorder(order);
+        self.layout.offsets = order.iter().map(|&old| self.layout.offsets[old]).collect();
+        self.mouse.hovered_indices = self.mouse.hovered_indices.iter().map(|&i| new_index[i]).collect();
+        self.rebuild_render_order();
+    }
+
+    /// Drops canvas state keyed by object name for objects that no longer
+    /// exist. Their contacts end here: surviving partners get
+    /// `CollisionExit` now, and a new object reusing a name starts fresh.
+    pub(crate) fn forget_objects(&mut self, names: &[String]) {
+        for name in names {
+            self.grapple_constraints.remove(name);
+            self.steering.remove(name);
+            self.blinks.remove(name);
+            self.oscillations.remove(name);
+            self.step_contacts.remove(name);
+        }
+        for list in self.step_contacts.values_mut() {
+            list.retain(|c| !names.contains(&c.other));
+        }
+        self.hold_timers.retain(|(owner, ..), _| !names.contains(owner));
+        // Removed parents orphan their children in place.
+        self.attachments.retain(|a| !names.contains(&a.child) && !names.contains(&a.parent));
+        self.game_vars.retain(|key, value| {
+            !(key.starts_with("_emitter_bind_")
+                && matches!(value, crate::value::Value::Str(n) if names.contains(n)))
+        });
+
+        let ended: Vec<(String, String)> = self.contacts.iter()
+            .filter(|(a, b)| names.contains(a) || names.contains(b))
+            .cloned()
+            .collect();
+        for pair in &ended { self.contacts.remove(pair); }
+        for (a, b) in ended {
+            for (this, other) in [(&a, &b), (&b, &a)] {
+                if let Some(&idx) = self.store.name_to_index.get(this) {
+                    self.trigger_collision_events(idx, other, GameEvent::is_collision_exit);
+                }
+            }
+        }
+    }
 }
 
 impl Canvas {
@@ -27,8 +314,12 @@ impl Canvas {
             obj.visible = false;
             obj.tags.push(format!("_pool:{}", pool_tag));
             obj.tags.push("_pool_free".to_string());
+            let events = std::mem::take(&mut obj.events);
             self.store.add(name, obj);
             self.layout.offsets.push((-9999.0, -9999.0));
+            let idx = self.store.objects.len() - 1;
+            self.store.events[idx] = events;
+            self.attach_template_events(idx);
         }
     }
 
@@ -114,6 +405,23 @@ impl Canvas {
     pub fn clear_image_cache(&mut self) {
         self.image_cache.clear();
     }
+
+    /// Decodes a GIF once at its own resolution; apply it with
+    /// `Action::SetAnimationHandle`. Panics on invalid bytes, like `load_animation`.
+    pub fn preload_animation(&mut self, bytes: &[u8], fps: f32) -> AnimationHandle {
+        let sprite = AnimatedSprite::decode_native(bytes, fps)
+            .expect("quartz: failed to decode animation from bytes");
+        self.animation_cache.insert(sprite)
+    }
+
+    pub fn release_animation(&mut self, handle: AnimationHandle) {
+        self.animation_cache.release(handle);
+    }
+
+    /// Frees all decoded animations, including those `SetAnimation` cached.
+    pub fn clear_animation_cache(&mut self) {
+        self.animation_cache.clear();
+    }
 }
 
 pub fn orbit_speed(gravity_strength: f32, planet_radius: f32, orbit_dist: f32) -> f32 {
@@ -123,4 +431,277 @@ pub fn orbit_speed(gravity_strength: f32, planet_radius: f32, orbit_dist: f32) -
 
 pub fn escape_speed(gravity_strength: f32, planet_radius: f32, dist: f32) -> f32 {
     orbit_speed(gravity_strength, planet_radius, dist) * std::f32::consts::SQRT_2
-}
\ No newline at end of file
+}
+
+/// Indices in draw order, without duplicates.
+fn sorted(mut indices: Vec<usize>) -> Vec<usize> {
+    indices.sort_unstable();
+    indices.dedup();
+    indices
+}
+
+#[cfg(test)]
+mod tests {
+    use super::super::core::RenderSlot;
+    use crate::canvas::Canvas;
+    use crate::testing::{canvas, count, object, platform, run};
+    use crate::types::{Action, Axis, Condition, GameEvent, Location, Target};
+
+    /// a, b, c, d at x = 0, 10, 20, 30; a and c tagged "even".
+    fn four() -> Canvas {
+        let mut cv = canvas();
+        for (i, name) in ["a", "b", "c", "d"].into_iter().enumerate() {
+            let mut obj = object(name, (i as f32 * 10.0, 0.0), (5.0, 5.0));
+            if i % 2 == 0 { obj = obj.with_tag("even"); }
+            cv.add_game_object(name.to_string(), obj);
+        }
+        cv
+    }
+
+    fn names(cv: &Canvas) -> Vec<&str> {
+        cv.store.names.iter().map(String::as_str).collect()
+    }
+
+    fn draw_order(cv: &Canvas) -> Vec<&str> {
+        cv.render_order.iter().filter_map(|slot| match slot {
+            RenderSlot::Object(i) => Some(cv.store.names[*i].as_str()),
+            _ => None,
+        }).collect()
+    }
+
+    /// Every index map and offset agrees with the object vectors.
+    fn assert_consistent(cv: &Canvas) {
+        assert_eq!(cv.store.objects.len(), cv.layout.offsets.len());
+        for (idx, name) in cv.store.names.iter().enumerate() {
+            let obj = &cv.store.objects[idx];
+            assert_eq!(cv.store.name_to_index[name], idx, "name {name}");
+            assert_eq!(cv.store.id_to_index[&obj.id], idx, "id of {name}");
+            assert_eq!(cv.layout.offsets[idx], obj.position, "offset of {name}");
+            for tag in obj.tags() {
+                assert!(cv.store.tag_to_indices[tag].contains(&idx), "{name} missing from {tag}");
+            }
+        }
+        for (tag, indices) in &cv.store.tag_to_indices {
+            assert!(indices.windows(2).all(|w| w[0] < w[1]), "{tag} out of order");
+            for &i in indices { assert!(cv.store.objects[i].has_tag(tag)); }
+        }
+        assert_eq!(draw_order(cv), names(cv));
+    }
+
+    fn tagged(cv: &Canvas, tag: &str) -> Vec<String> {
+        cv.store.get_names(&Target::tag(tag))
+    }
+
+    #[test]
+    fn add_at_index_inserts_below_later_objects() {
+        let mut cv = four();
+        cv.add_game_object_at(1, "x".into(), object("x", (99.0, 0.0), (5.0, 5.0)).with_tag("even"));
+        assert_eq!(names(&cv), ["a", "x", "b", "c", "d"]);
+        assert_consistent(&cv);
+        assert_eq!(tagged(&cv, "even"), ["a", "x", "c"]);
+        assert_eq!(cv.get_game_object("c").unwrap().position, (20.0, 0.0));
+        assert_eq!(cv.layout.offsets[1], (99.0, 0.0));
+    }
+
+    #[test]
+    fn add_at_index_past_the_end_appends() {
+        let mut cv = four();
+        cv.add_game_object_at(42, "x".into(), object("x", (99.0, 0.0), (5.0, 5.0)));
+        assert_eq!(names(&cv), ["a", "b", "c", "d", "x"]);
+        assert_consistent(&cv);
+    }
+
+    #[test]
+    fn move_to_front_keeps_relative_order() {
+        let mut cv = four();
+        cv.move_to_front(&Target::tag("even"));
+        assert_eq!(names(&cv), ["b", "d", "a", "c"]);
+        assert_consistent(&cv);
+        assert_eq!(tagged(&cv, "even"), ["a", "c"]);
+        assert_eq!(cv.store.get_indices(&Target::tag("even")), [2, 3]);
+    }
+
+    #[test]
+    fn move_to_back_keeps_relative_order() {
+        let mut cv = four();
+        cv.move_to_back(&Target::union(vec![Target::name("d"), Target::name("b")]));
+        assert_eq!(names(&cv), ["b", "d", "a", "c"]);
+        assert_consistent(&cv);
+        assert_eq!(cv.store.get_indices(&Target::tag("even")), [2, 3]);
+    }
+
+    #[test]
+    fn moving_a_middle_object_shifts_only_those_above_it() {
+        let mut cv = four();
+        cv.move_to_front(&Target::name("b"));
+        assert_eq!(names(&cv), ["a", "c", "d", "b"]);
+        assert_consistent(&cv);
+        assert_eq!(cv.store.get_indices(&Target::id("b")), [3]);
+        assert_eq!(cv.store.get_indices(&Target::name("c")), [1]);
+        assert_eq!(cv.get_game_object("b").unwrap().position, (10.0, 0.0));
+
+        cv.move_to_back(&Target::name("b"));
+        assert_eq!(names(&cv), ["b", "a", "c", "d"]);
+        assert_consistent(&cv);
+    }
+
+    #[test]
+    fn removal_after_reorder_keeps_lookups() {
+        let mut cv = four();
+        cv.move_to_front(&Target::name("a"));
+        cv.remove_game_object("c");
+        assert_eq!(names(&cv), ["b", "d", "a"]);
+        assert_consistent(&cv);
+        assert_eq!(tagged(&cv, "even"), ["a"]);
+    }
+
+    #[test]
+    fn retagging_shows_up_in_tag_queries() {
+        let mut cv = four();
+        cv.add_tag(&Target::name("d"), "boss");
+        cv.add_tag(&Target::name("b"), "even");
+        assert_consistent(&cv);
+        assert_eq!(tagged(&cv, "boss"), ["d"]);
+        assert_eq!(tagged(&cv, "even"), ["a", "b", "c"]);
+        assert_eq!(cv.count_by_tag("even"), 3);
+
+        cv.remove_tag(&Target::name("a"), "even");
+        cv.remove_tag(&Target::name("d"), "boss");
+        assert_consistent(&cv);
+        assert_eq!(tagged(&cv, "even"), ["b", "c"]);
+        assert!(tagged(&cv, "boss").is_empty());
+        assert!(!cv.has_tag("boss"));
+    }
+
+    #[test]
+    fn retagged_objects_stay_findable_after_reorder_and_removal() {
+        let mut cv = four();
+        cv.add_tag(&Target::name("d"), "even");
+        cv.move_to_back(&Target::name("d"));
+        assert_eq!(names(&cv), ["d", "a", "b", "c"]);
+        assert_consistent(&cv);
+        assert_eq!(tagged(&cv, "even"), ["d", "a", "c"]);
+
+        cv.remove_game_object("a");
+        cv.change_tag(&Target::name("c"), "even", "odd");
+        assert_consistent(&cv);
+        assert_eq!(tagged(&cv, "even"), ["d"]);
+        assert_eq!(tagged(&cv, "odd"), ["c"]);
+
+        cv.rename_object("d", "boss");
+        assert_consistent(&cv);
+        assert_eq!(tagged(&cv, "even"), ["boss"]);
+    }
+
+    #[test]
+    fn removing_one_copy_keeps_the_id_on_the_others() {
+        let mut cv = canvas();
+        for name in ["bat1", "bat2", "bat3"] {
+            cv.add_game_object(name.into(), object("bat", (0.0, 0.0), (5.0, 5.0)));
+        }
+        cv.remove_game_object("bat2");
+        assert_eq!(cv.store.get_names(&Target::id("bat")), ["bat3"]);
+        cv.remove_game_object("bat3");
+        assert_eq!(cv.store.get_names(&Target::id("bat")), ["bat1"]);
+        cv.remove_game_object("bat1");
+        assert!(cv.store.get_indices(&Target::id("bat")).is_empty());
+        assert!(cv.store.id_to_index.is_empty());
+    }
+
+    #[test]
+    fn removal_ends_contacts_so_a_reused_name_enters_again() {
+        let mut cv = canvas();
+        cv.add_game_object("a".into(), object("a", (0.0, 0.0), (20.0, 20.0)));
+        cv.add_game_object("b".into(), object("b", (10.0, 0.0), (20.0, 20.0)).with_tag("enemy"));
+        for var in ["enters", "exits"] { cv.set_var(var, 0); }
+        cv.add_event(GameEvent::CollisionEnter { action: count("enters"), target: Target::all() }, Target::name("a"));
+        cv.add_event(GameEvent::CollisionExit { action: count("exits"), target: Target::all() }, Target::name("a"));
+        run(&mut cv, 2);
+        assert_eq!((cv.get_i32("enters"), cv.get_i32("exits")), (1, 0));
+
+        cv.clear_tag("enemy");
+        assert_eq!((cv.get_i32("enters"), cv.get_i32("exits")), (1, 1));
+        assert!(cv.contacts.is_empty());
+
+        cv.add_game_object("b".into(), object("b", (10.0, 0.0), (20.0, 20.0)));
+        run(&mut cv, 1);
+        assert_eq!((cv.get_i32("enters"), cv.get_i32("exits")), (2, 1));
+
+        cv.remove_game_object("b");
+        run(&mut cv, 1);
+        assert_eq!((cv.get_i32("enters"), cv.get_i32("exits")), (2, 2));
+    }
+
+    #[test]
+    fn cleared_objects_leave_no_tweens_or_timers_behind() {
+        let mut cv = four();
+        let hold = GameEvent::key_hold(prism::event::Key::Character("x".into()), Action::Multi(vec![]), Target::self_object())
+            .with_min_interval(0.5);
+        cv.add_event(hold, Target::name("a"));
+        cv.inject_key(prism::event::KeyboardState::Pressed, prism::event::Key::Character("x".into()));
+        cv.oscillate(&Target::tag("even"), Axis::X, 5.0, 1.0);
+        cv.blink(&Target::name("c"), 2.0, 0.1);
+        run(&mut cv, 2);
+        assert!(!cv.hold_timers.is_empty());
+
+        cv.clear_tag("even");
+        assert!(!cv.is_oscillating("a") && !cv.is_oscillating("c"));
+        assert!(!cv.is_blinking("c"));
+        assert!(cv.hold_timers.is_empty());
+
+        cv.add_game_object("a".into(), object("a", (0.0, 0.0), (5.0, 5.0)));
+        run(&mut cv, 2);
+        assert_eq!(cv.get_game_object("a").unwrap().position, (0.0, 0.0));
+        assert!(cv.get_game_object("a").unwrap().visible);
+    }
+
+    /// A falling, swinging, blinking ball over a floor, with ticks that
+    /// count and randomly spawn sparks, marked as its initial state.
+    fn busy_scene() -> Canvas {
+        let mut cv = canvas();
+        cv.add_game_object("ball".into(), object("ball", (100.0, 0.0), (10.0, 10.0)).with_velocity((2.0, 3.0)));
+        cv.add_game_object("floor".into(), platform("floor", (0.0, 200.0), (400.0, 10.0)));
+        cv.set_var("ticks", 0);
+        cv.add_event(GameEvent::tick(count("ticks"), Target::self_object()), Target::name("ball"));
+        let spark = Action::spawn(object("spark", (0.0, 0.0), (2.0, 2.0)), Location::at(50.0, 50.0));
+        cv.add_event(GameEvent::tick(Action::when_if(Condition::chance(0.2), spark), Target::self_object()),
+                     Target::name("ball"));
+        cv.oscillate(&Target::name("ball"), Axis::X, 20.0, 1.0);
+        cv.blink(&Target::name("ball"), 2.0, 0.1);
+        cv.mark_initial_state();
+        cv
+    }
+
+    fn state(cv: &Canvas) -> Vec<String> {
+        let mut state: Vec<String> = cv.store.names.iter().zip(&cv.store.objects)
+            .map(|(name, o)| format!("{name} {:?} {:?} {} {}", o.position, o.momentum, o.visible, o.blink_hidden))
+            .collect();
+        let mut vars: Vec<String> = cv.game_vars.iter().map(|(k, v)| format!("{k}={v:?}")).collect();
+        vars.sort();
+        state.extend(vars);
+        state.push(format!("{} {}", cv.is_blinking("ball"), cv.is_oscillating("ball")));
+        state
+    }
+
+    #[test]
+    fn reset_plays_out_like_a_fresh_scene() {
+        let mut fresh = busy_scene();
+        let mut cv = busy_scene();
+        run(&mut cv, 45);
+        cv.remove_game_object("floor");
+        cv.shake(8.0, 1.0);
+        cv.hitstop(0.5);
+        cv.start_recording();
+
+        cv.reset();
+        assert_eq!(state(&cv), state(&fresh));
+        assert!(!cv.is_shaking());
+        assert_eq!(cv.hitstop_remaining(), 0.0);
+        assert!(!cv.is_recording());
+
+        run(&mut cv, 45);
+        run(&mut fresh, 45);
+        assert_eq!(state(&cv), state(&fresh));
+        assert!(cv.store.names.iter().any(|n| n.starts_with("spawned_spark")));
+    }
+}
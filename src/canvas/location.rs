@@ -1,8 +1,17 @@
+use super::core::Canvas;
 use crate::store::ObjectStore;
-use crate::types::{Location, Anchor};
+use crate::types::{Location, Anchor, Target};
+
+impl Canvas {
+    /// World position `location` names right now.
+    pub(crate) fn resolve_location(&self, location: &Location) -> (f32, f32) {
+        location.resolve_position(&self.store, self.layout.canvas_size.get())
+    }
+}
 
 impl Location {
-    pub(crate) fn resolve_position(&self, store: &ObjectStore) -> (f32, f32) {
+    /// `canvas_size` is the virtual canvas, for `CanvasAnchor`.
+    pub(crate) fn resolve_position(&self, store: &ObjectStore, canvas_size: (f32, f32)) -> (f32, f32) {
         match self {
             Location::Position(pos) => *pos,
             Location::AtTarget(t) => {
@@ -37,6 +46,75 @@ impl Location {
                     })
                     .unwrap_or(*offset)
             }
+            Location::AwayFrom { from, distance } => {
+                // Outside a ForEach, the object whose event is running.
+                let subject = [Target::Current, Target::SelfObject].iter()
+                    .find_map(|t| store.get_indices(t).first().copied())
+                    .and_then(|i| store.objects.get(i));
+                let source = store.get_indices(from).first()
+                    .and_then(|&i| store.objects.get(i));
+                match (subject, source) {
+                    (Some(o), Some(src)) => {
+                        let (cx, cy) = o.center();
+                        let (sx, sy) = src.center();
+                        let (dx, dy) = (cx - sx, cy - sy);
+                        let len = (dx * dx + dy * dy).sqrt();
+                        if len < f32::EPSILON { return o.position; }
+                        (o.position.0 + dx / len * distance, o.position.1 + dy / len * distance)
+                    }
+                    (Some(o), None) => o.position,
+                    _ => (0.0, 0.0),
+                }
+            }
+            Location::GridCell { col, row, tile_size, origin } => {
+                (origin.0 + *col as f32 * tile_size.0, origin.1 + *row as f32 * tile_size.1)
+            }
+            Location::CanvasAnchor { anchor, offset } => {
+                (canvas_size.0 * anchor.x + offset.0, canvas_size.1 * anchor.y + offset.1)
+            }
         }
     }
-}
\ No newline at end of file
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::canvas::Canvas;
+    use crate::testing::{canvas, object, run};
+    use crate::types::{Action, GameEvent, Location, Target};
+
+    /// Player at (100, 100); one enemy to its right, one above.
+    fn arena() -> Canvas {
+        let mut cv = canvas();
+        cv.add_game_object("player".into(), object("player", (100.0, 100.0), (20.0, 20.0)));
+        cv.add_game_object("right".into(), object("right", (200.0, 100.0), (20.0, 20.0)).with_tag("enemy"));
+        cv.add_game_object("above".into(), object("above", (100.0, 0.0), (20.0, 20.0)).with_tag("enemy"));
+        cv
+    }
+
+    fn position(cv: &Canvas, name: &str) -> (f32, f32) {
+        cv.get_game_object(name).unwrap().position
+    }
+
+    #[test]
+    fn for_each_pushes_each_object_along_its_own_line() {
+        let mut cv = arena();
+        let shove = Action::teleport(Target::current(), Location::away_from(Target::name("player"), 60.0));
+        cv.run(Action::for_each(Target::tag("enemy"), shove));
+        assert_eq!(position(&cv, "right"), (260.0, 100.0));
+        assert_eq!(position(&cv, "above"), (100.0, -60.0));
+        assert_eq!(position(&cv, "player"), (100.0, 100.0));
+    }
+
+    #[test]
+    fn outside_for_each_it_moves_away_from_the_event_owner() {
+        let mut cv = arena();
+        let back_off = Action::teleport(Target::self_object(), Location::away_from(Target::name("player"), 10.0));
+        cv.add_event(GameEvent::tick(back_off, Target::self_object()), Target::name("right"));
+        run(&mut cv, 3);
+        assert_eq!(position(&cv, "right"), (230.0, 100.0));
+
+        // With no ForEach object and no owner there's nothing to move from.
+        let nowhere = Location::away_from(Target::name("player"), 10.0);
+        assert_eq!(cv.resolve_location(&nowhere), (0.0, 0.0));
+    }
+}
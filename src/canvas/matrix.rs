@@ -0,0 +1,107 @@
+use super::core::Canvas;
+
+/// Tag pairs that never collide. See `Canvas::set_tags_collide`.
+#[derive(Clone, Debug, Default)]
+pub(crate) struct CollisionMatrix {
+    /// Tags named by a rule; a tag's index is its bit in the masks.
+    tags:    Vec<String>,
+    /// Per tag bit, the bits of the tags it doesn't collide with.
+    ignores: Vec<u64>,
+}
+
+impl CollisionMatrix {
+    /// Most distinct tags the matrix can hold.
+    const MAX_TAGS: usize = 64;
+
+    fn bit(&self, tag: &str) -> Option<usize> {
+        self.tags.iter().position(|t| t == tag)
+    }
+
+    fn bit_or_insert(&mut self, tag: &str) -> Option<usize> {
+        if let Some(bit) = self.bit(tag) { return Some(bit); }
+        if self.tags.len() == Self::MAX_TAGS { return None; }
+        self.tags.push(tag.to_string());
+        self.ignores.push(0);
+        Some(self.tags.len() - 1)
+    }
+
+    /// False if `collide` is false and the matrix is already full.
+    fn set(&mut self, a: &str, b: &str, collide: bool) -> bool {
+        if collide {
+            if let (Some(a), Some(b)) = (self.bit(a), self.bit(b)) {
+                self.ignores[a] &= !(1 << b);
+                self.ignores[b] &= !(1 << a);
+            }
+            return true;
+        }
+        let (Some(a), Some(b)) = (self.bit_or_insert(a), self.bit_or_insert(b)) else { return false; };
+        self.ignores[a] |= 1 << b;
+        self.ignores[b] |= 1 << a;
+        true
+    }
+
+    fn collides(&self, a: &str, b: &str) -> bool {
+        match (self.bit(a), self.bit(b)) {
+            (Some(a), Some(b)) => self.ignores[a] & (1 << b) == 0,
+            _ => true,
+        }
+    }
+
+    fn is_empty(&self) -> bool {
+        self.ignores.iter().all(|&bits| bits == 0)
+    }
+
+    /// (tag bits, ignored bits) of an object with `tags`. Two objects
+    /// collide unless one's tag bits meet the other's ignored bits.
+    fn masks(&self, tags: &[String]) -> (u64, u64) {
+        tags.iter()
+            .filter_map(|tag| self.bit(tag))
+            .fold((0, 0), |(own, ignored), bit| (own | 1 << bit, ignored | self.ignores[bit]))
+    }
+}
+
+impl Canvas {
+    /// Declares whether objects tagged `a` and objects tagged `b` collide
+    /// (`a == b` for objects of one tag among themselves). Everything
+    /// collides by default; a pair switched off is skipped by the built-in
+    /// physics before its boxes are even compared: no platform push-out,
+    /// no sweep stop, no collision events, no contacts. Bullets that pass
+    /// through platforms, background tiles nothing lands on. Objects with
+    /// several tags are skipped if any of their tags is switched off
+    /// against any of the other's. Crystalline physics uses
+    /// `collision_layer`/`collision_mask` instead. Up to 64 tags can be
+    /// named; returns false (and changes nothing) past that.
+    pub fn set_tags_collide(&mut self, a: &str, b: &str, collide: bool) -> bool {
+        self.collision_matrix.set(a, b, collide)
+    }
+
+    /// False if `set_tags_collide(a, b, false)` is in effect.
+    pub fn tags_collide(&self, a: &str, b: &str) -> bool {
+        self.collision_matrix.collides(a, b)
+    }
+
+    /// Whether the built-in physics tests platforms against each other.
+    /// Off by default: overlapping platforms are skipped entirely, so a
+    /// moving platform passes through the ground and level geometry can
+    /// overlap freely. On, a non-kinematic platform (a crate you can stand
+    /// on) is pushed out of the platforms it overlaps and can land on them
+    /// like any object; kinematic platforms still never move, so two
+    /// overlapping kinematic ones stay put either way.
+    pub fn set_platforms_collide(&mut self, collide: bool) {
+        self.platforms_collide = collide;
+    }
+
+    pub fn platforms_collide(&self) -> bool { self.platforms_collide }
+
+    /// Per-object (tag bits, ignored bits) for this step's pair tests, or
+    /// None when no pair is switched off.
+    pub(crate) fn collision_filter(&self) -> Option<Vec<(u64, u64)>> {
+        if self.collision_matrix.is_empty() { return None; }
+        Some(self.store.objects.iter().map(|obj| self.collision_matrix.masks(&obj.tags)).collect())
+    }
+}
+
+/// True if the pair (`a`, `b`) is switched off by the filter.
+pub(crate) fn filtered(filter: &Option<Vec<(u64, u64)>>, a: usize, b: usize) -> bool {
+    filter.as_ref().is_some_and(|masks| masks[a].0 & masks[b].1 != 0)
+}
@@ -7,8 +7,30 @@ pub mod events;
 pub mod watch;
 pub mod location;
 pub mod physics_bridge;
+pub mod emitters;
+pub mod backdrop;
+pub mod debug;
+pub mod stats;
+pub mod tilemap;
+pub mod steering;
+pub mod shake;
+pub mod state_machine;
+pub mod query;
+pub mod attach;
+pub mod matrix;
+pub mod blink;
+pub mod boundary;
+pub mod replay;
+pub mod oscillate;
+pub mod broadphase;
 
 // Flatten the public surface: callers use `crate::canvas::Canvas` etc.
 pub use core::{Canvas, CanvasMode, CanvasLayout};
+pub use stats::TickStats;
+pub use tilemap::TileSpec;
+pub use query::{Contact, RayHit};
+pub use boundary::{Edge, BoundaryBehavior};
+pub use replay::{Recording, RecordedKey};
+pub use broadphase::BroadPhase;
 // physics helper needed by object update path
 pub(crate) use physics::rotation_adjusted_offset;
\ No newline at end of file
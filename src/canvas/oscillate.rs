@@ -0,0 +1,73 @@
+use super::core::Canvas;
+use super::physics::rotation_adjusted_offset;
+use crate::types::{Axis, Target};
+
+/// A sine swing on one axis. Registered by `Action::Oscillate`.
+#[derive(Clone, Debug)]
+pub(crate) struct Oscillation {
+    pub(crate) axis:      Axis,
+    pub(crate) amplitude: f32,
+    /// Cycles per second.
+    pub(crate) frequency: f32,
+    /// Simulation seconds since it started.
+    pub(crate) elapsed:   f32,
+    /// Offset from the anchor as of the last step.
+    pub(crate) offset:    f32,
+}
+
+impl Canvas {
+    /// Swings each target along `axis` by `amplitude * sin(2π · frequency ·
+    /// t)` pixels around where it was when this was called, `t` being the
+    /// simulation seconds since. Only the change in offset is applied each
+    /// step, so the object can still move otherwise and a moving platform
+    /// carries what stands on it. One oscillation per axis: oscillating
+    /// again on the same axis replaces it; X and Y together trace an ellipse
+    /// or a figure eight.
+    pub fn oscillate(&mut self, target: &Target, axis: Axis, amplitude: f32, frequency: f32) {
+        for name in self.store.get_names(target) {
+            let list = self.oscillations.entry(name).or_default();
+            list.retain(|o| o.axis != axis);
+            list.push(Oscillation { axis, amplitude, frequency, elapsed: 0.0, offset: 0.0 });
+        }
+    }
+
+    /// Stops each target's oscillation on `axis`, or on both axes with
+    /// None, leaving it wherever it is in the swing.
+    pub fn stop_oscillation(&mut self, target: &Target, axis: Option<Axis>) {
+        for name in self.store.get_names(target) {
+            let Some(list) = self.oscillations.get_mut(&name) else { continue; };
+            list.retain(|o| axis.is_some_and(|a| a != o.axis));
+            if list.is_empty() { self.oscillations.remove(&name); }
+        }
+    }
+
+    pub fn is_oscillating(&self, name: &str) -> bool {
+        self.oscillations.contains_key(name)
+    }
+
+    /// Advances every oscillation by `dt` and moves its object by the change
+    /// in offset.
+    pub(crate) fn advance_oscillations(&mut self, dt: f32) {
+        if self.oscillations.is_empty() { return; }
+        for (name, list) in self.oscillations.iter_mut() {
+            let Some(&idx) = self.store.name_to_index.get(name) else { continue; };
+            let obj = &mut self.store.objects[idx];
+            for osc in list.iter_mut() {
+                osc.elapsed += dt;
+                let offset = osc.amplitude * (std::f32::consts::TAU * osc.frequency * osc.elapsed).sin();
+                match osc.axis {
+                    Axis::X => obj.position.0 += offset - osc.offset,
+                    Axis::Y => obj.position.1 += offset - osc.offset,
+                }
+                osc.offset = offset;
+            }
+            self.layout.offsets[idx] = rotation_adjusted_offset(
+                obj.position,
+                obj.size,
+                obj.rotation,
+                obj.slope.is_some(),
+                obj.pivot,
+            );
+        }
+    }
+}
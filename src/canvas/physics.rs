@@ -1,4 +1,8 @@
+use std::collections::{BTreeMap, HashSet};
+
 use super::core::Canvas;
+use super::matrix::filtered;
+use super::query::Contact;
 use crate::object;
 use crate::types::{CollisionMode, CollisionShape, GameEvent, GravityFalloff, Target};
 
@@ -12,6 +16,9 @@ pub(crate) const GRAVITY_INFLUENCE_MULT: f32 = 3.0;
 /// 0.9 means non-dominant forces are reduced to 10 % at the surface.
 pub(crate) const NESTED_GRAVITY_DAMPENING: f32 = 0.9;
 
+/// Upper bound on legacy movement sub-steps per fixed step.
+const MAX_SUBSTEPS: usize = 16;
+
 /// Compute the gravitational force vector from one planet onto one receiver.
 ///
 /// Returns Some((fx, fy, pull_magnitude)) when the planet is in range,
@@ -53,41 +60,88 @@ impl Canvas {
     pub(crate) fn check_collision(o1: &object::GameObject, o2: &object::GameObject) -> bool {
         if !o1.visible || !o2.visible { return false; }
 
-        let (ax, ay, aw, ah) = if o1.is_platform && o1.slope.is_some() {
-            o1.slope_aabb()
-        } else if o1.is_platform && o1.rotation != 0.0 {
-            rotated_aabb(o1)
-        } else {
-            (o1.position.0, o1.position.1, o1.size.0, o1.size.1)
-        };
-
-        let (bx, by, bw, bh) = if o2.is_platform && o2.slope.is_some() {
-            o2.slope_aabb()
-        } else if o2.is_platform && o2.rotation != 0.0 {
-            rotated_aabb(o2)
-        } else {
-            (o2.position.0, o2.position.1, o2.size.0, o2.size.1)
-        };
+        let (ax, ay, aw, ah) = collision_aabb(o1);
+        let (bx, by, bw, bh) = collision_aabb(o2);
 
         ax < bx + bw && ax + aw > bx && ay < by + bh && ay + ah > by
     }
 
-    pub(crate) fn trigger_collision_events(&mut self, idx: usize) {
-        let actions: Vec<_> = self.store.events.get(idx).into_iter().flatten()
-            .filter_map(|e| {
-                if let GameEvent::Collision { action, .. } = e { Some(action.clone()) } else { None }
+    /// Runs the owner's collision events of one kind (`GameEvent::is_collision`,
+    /// `is_collision_enter` or `is_collision_exit`), with `partner`, the
+    /// name of the object it collided with, visible to `Condition::Partner`.
+    pub(crate) fn trigger_collision_events<F>(&mut self, idx: usize, partner: &str, predicate: F)
+    where
+        F: Fn(&GameEvent) -> bool,
+    {
+        let Some(owner) = self.store.names.get(idx).cloned() else { return; };
+        let actions: Vec<_> = self.store.events_of(idx)
+            .filter(|e| predicate(e))
+            .map(|e| e.action().clone())
+            .collect();
+        let previous = self.store.partner.replace(partner.to_string());
+        actions.into_iter().for_each(|a| self.run_owned(owner.clone(), a));
+        self.store.partner = previous;
+    }
+
+    /// Fires `Collision` for this step's overlapping pairs, plus
+    /// `CollisionEnter`/`CollisionExit` for pairs that started or stopped
+    /// overlapping since the last step. Pairs are remembered by name so
+    /// index shifts from removals don't register as new contacts.
+    fn dispatch_collisions(&mut self, pairs: Vec<(usize, usize)>) {
+        let names = &self.store.names;
+        let current: HashSet<(String, String)> = pairs.iter()
+            .map(|&(i, j)| {
+                let (a, b) = (names[i].clone(), names[j].clone());
+                if a < b { (a, b) } else { (b, a) }
             })
             .collect();
-        actions.into_iter().for_each(|a| self.run(a));
+        let entered: Vec<_> = current.iter().filter(|p| !self.contacts.contains(*p)).cloned().collect();
+        let exited:  Vec<_> = self.contacts.iter().filter(|p| !current.contains(*p)).cloned().collect();
+        self.contacts = current;
+
+        for (a, b) in exited {
+            let (ia, ib) = (self.store.name_to_index.get(&a).copied(), self.store.name_to_index.get(&b).copied());
+            if let Some(i) = ia { self.trigger_collision_events(i, &b, GameEvent::is_collision_exit); }
+            if let Some(j) = ib { self.trigger_collision_events(j, &a, GameEvent::is_collision_exit); }
+        }
+        for (a, b) in entered {
+            let (ia, ib) = (self.store.name_to_index.get(&a).copied(), self.store.name_to_index.get(&b).copied());
+            if let (Some(i), Some(j)) = (ia, ib) {
+                self.trigger_collision_events(i, &b, GameEvent::is_collision_enter);
+                self.trigger_collision_events(j, &a, GameEvent::is_collision_enter);
+            }
+        }
+        for (i, j) in pairs {
+            let (Some(a), Some(b)) = (self.store.names.get(i).cloned(), self.store.names.get(j).cloned())
+            else { continue; };
+            self.trigger_collision_events(i, &b, GameEvent::is_collision);
+            self.trigger_collision_events(j, &a, GameEvent::is_collision);
+        }
+    }
+
+    /// Records that `a` and `b` touched this step, with `normal` pushing
+    /// `a` away from `b`. A pair seen again (a later sub-step) keeps the
+    /// latest normal.
+    fn record_contact(&mut self, a: usize, b: usize, normal: (f32, f32)) {
+        for (this, other, normal) in [(a, b, normal), (b, a, (-normal.0, -normal.1))] {
+            let (Some(this), Some(other)) = (self.store.names.get(this), self.store.names.get(other))
+            else { continue; };
+            let list = self.step_contacts.entry(this.clone()).or_default();
+            match list.iter_mut().find(|c| c.other == *other) {
+                Some(contact) => contact.normal = normal,
+                None => list.push(Contact { other: other.clone(), normal }),
+            }
+        }
     }
 
     pub(crate) fn trigger_boundary_collision_events(&mut self, idx: usize) {
-        let actions: Vec<_> = self.store.events.get(idx).into_iter().flatten()
+        let Some(owner) = self.store.names.get(idx).cloned() else { return; };
+        let actions: Vec<_> = self.store.events_of(idx)
             .filter_map(|e| {
                 if let GameEvent::BoundaryCollision { action, .. } = e { Some(action.clone()) } else { None }
             })
             .collect();
-        actions.into_iter().for_each(|a| self.run(a));
+        actions.into_iter().for_each(|a| self.run_owned(owner.clone(), a));
     }
 
     pub(crate) fn update_objects(&mut self, delta_time: f32) {
@@ -98,23 +152,32 @@ impl Canvas {
 
         // ignore_zoom objects need base_scale (without zoom) for their
         // shape/text sizing so it matches what build() applies to them.
-        let zoom = self.layout.zoom.get().max(0.01);
-        let base_scale = if zoom.abs() > f32::EPSILON { scale / zoom } else { scale };
+        let base_scale = self.layout.base_scale.get();
 
         for (idx, obj) in self.store.objects.iter_mut().enumerate() {
             obj.grounded = false;
             let obj_scale = if obj.ignore_zoom { base_scale } else { scale };
-            obj.scaled_size.set((obj.size.0 * obj_scale, obj.size.1 * obj_scale));
-            obj.render_scale.set(obj_scale);
+            let visual = obj.visual_scale();
+            obj.scaled_size.set((
+                obj.size.0 * visual.0 * obj_scale,
+                obj.size.1 * visual.1 * obj_scale,
+            ));
+            obj.canvas_scale.set(obj_scale);
+            obj.update_label();
+            obj.update_auto_flip();
             obj.update_animation(delta_time);
 
             if obj.visible {
-                if !has_crystalline {
-                    obj.apply_gravity();
-                    obj.update_position();
-                    obj.apply_resistance();
+                // Before the crystalline snapshot, so both paths see it. The
+                // crystalline solver adds gravity itself.
+                if !obj.kinematic { obj.accelerate(delta_time, !has_crystalline); }
+                obj.apply_spin(delta_time);
+                if !has_crystalline && !obj.kinematic {
+                    // Position and resistance are applied in move_and_collide
+                    // so movement can be sub-stepped against platforms.
                     obj.apply_rotation_momentum();
-                }                
+                }
+                obj.update_patrol();
                 if obj.animated_sprite.is_none() {
                     obj.update_image_shape();
                 }
@@ -131,14 +194,53 @@ impl Canvas {
         self.handle_infinite_scroll();
     }
 
+    /// Bounds for `clamp_to_canvas`/`ClampToBounds`: the camera's world when
+    /// one is active (objects live in world space), else the canvas size the
+    /// boundary events use.
+    pub(crate) fn clamp_bounds(&self) -> (f32, f32) {
+        self.active_camera.as_ref()
+            .map(|cam| cam.world_size)
+            .unwrap_or_else(|| self.layout.canvas_size.get())
+    }
+
+    pub(crate) fn clamp_to_bounds(&mut self, indices: &[usize]) {
+        let bounds = self.clamp_bounds();
+        for &idx in indices {
+            let Some(obj) = self.store.objects.get_mut(idx) else { continue; };
+            obj.clamp_within(bounds);
+            self.layout.offsets[idx] = rotation_adjusted_offset(
+                obj.position,
+                obj.size,
+                obj.rotation,
+                obj.slope.is_some(),
+                obj.pivot,
+            );
+        }
+    }
+
+    /// Wraps every visible `wrap` object around the clamp bounds.
+    pub(crate) fn apply_wrap(&mut self) {
+        let bounds = self.clamp_bounds();
+        for (idx, obj) in self.store.objects.iter_mut().enumerate() {
+            if !obj.visible || !obj.wrap || !obj.wrap_within(bounds) { continue; }
+            self.layout.offsets[idx] = rotation_adjusted_offset(
+                obj.position,
+                obj.size,
+                obj.rotation,
+                obj.slope.is_some(),
+                obj.pivot,
+            );
+        }
+    }
+
     pub(crate) fn apply_camera_transform(&mut self) {
         let mut cam = match self.active_camera.take() {
             Some(c) => c,
-            None => return,
+            None => { self.apply_screen_pins(); return; }
         };
 
         // Advance the zoom lerp (always, even without a follow target).
-        cam.advance_zoom_lerp();
+        cam.advance_zoom_lerp(self.fixed_timestep);
 
         if let Some(target) = cam.follow_target.clone() {
             if let Some(&idx) = self.store.get_indices(&target).first() {
@@ -151,7 +253,7 @@ impl Canvas {
         }
 
         // Advance camera effects (shake, flash, zoom punch).
-        cam.effects.update(1.0 / 60.0);
+        cam.effects.update(self.fixed_timestep);
 
         // Additive offsets from effects — not fed back into cam.position/zoom.
         let shake_offset = cam.effects.shake_offset();
@@ -168,13 +270,7 @@ impl Canvas {
                 obj.pivot,
             );
 
-            if let Some(pin) = &obj.screen_pin {
-                let (vw, vh) = self.layout.canvas_size.get();
-                let px = vw * pin.anchor.0 + pin.offset.0 - obj.size.0 * pin.anchor.0;
-                let py = vh * pin.anchor.1 + pin.offset.1 - obj.size.1 * pin.anchor.1;
-                let safe = self.layout.safe_area_offset.get();
-                self.layout.offsets[idx] = (px + safe.0, py + safe.1);
-            } else if obj.ignore_zoom {
+            if obj.ignore_zoom {
                 self.layout.offsets[idx] = adj;
             } else {
                 self.layout.offsets[idx] = (adj.0 - cam_x, adj.1 - cam_y);
@@ -192,6 +288,23 @@ impl Canvas {
         self.drive_flash_overlay(&cam);
 
         self.active_camera = Some(cam);
+        self.apply_screen_pins();
+    }
+
+    /// Places every `ScreenPin` object relative to the virtual canvas, or to
+    /// the whole window (letterbox bars included) when `pin.window` is set.
+    /// Offsets are virtual pixels; `build()` adds the letterbox padding.
+    pub(crate) fn apply_screen_pins(&mut self) {
+        let window = self.window_rect();
+        let (vw, vh) = self.layout.canvas_size.get();
+        for (idx, obj) in self.store.objects.iter().enumerate() {
+            let Some(pin) = &obj.screen_pin else { continue; };
+            let (rx, ry, rw, rh) = if pin.window { window } else { (0.0, 0.0, vw, vh) };
+            self.layout.offsets[idx] = (
+                rx + rw * pin.anchor.0 + pin.offset.0 - obj.size.0 * pin.anchor.0,
+                ry + rh * pin.anchor.1 + pin.offset.1 - obj.size.1 * pin.anchor.1,
+            );
+        }
     }
 
     /// Internal name for the auto-managed flash overlay object.
@@ -233,109 +346,334 @@ impl Canvas {
         }
     }
 
-    pub(crate) fn handle_collisions(&mut self) {
-        let mut adjustments: Vec<(usize, f32, f32, usize)> = Vec::new();
-        let mut collision_pairs: Vec<(usize, usize)> = Vec::new();
+    /// Legacy (non-crystalline) movement. Splits this step's displacement
+    /// into sub-steps no longer than the smallest visible object dimension
+    /// and resolves platform collisions after each one. Within a sub-step,
+    /// objects are swept against axis-aligned platforms (`sweep_move`), so
+    /// even past the sub-step cap a fast object stops exactly on a thin
+    /// platform instead of passing through it. Collision events fire once
+    /// per pair per step regardless of the sub-step count.
+    pub(crate) fn move_and_collide(&mut self) {
+        let visible = || self.store.objects.iter().filter(|o| o.visible);
+        let max_travel = visible()
+            .filter(|o| !o.is_platform && !o.kinematic)
+            .map(|o| o.momentum.0.abs().max(o.momentum.1.abs()))
+            .fold(0.0_f32, f32::max);
+        let min_dim = visible()
+            .map(|o| o.size.0.min(o.size.1))
+            .fold(f32::MAX, f32::min)
+            .max(1.0);
+        let substeps = ((max_travel / min_dim).ceil() as usize).clamp(1, MAX_SUBSTEPS);
+        let fraction = 1.0 / substeps as f32;
+        self.step_contacts.clear();
+
+        let mut pairs: Vec<(usize, usize)> = Vec::new();
+        let mut riders: Vec<(usize, usize)> = Vec::new();
+        for _ in 0..substeps {
+            let landed = self.sweep_move(fraction);
+            let (overlaps, standing) = self.resolve_collisions();
+            for pair in overlaps {
+                if !pairs.contains(&pair) { pairs.push(pair); }
+            }
+            for (obj_idx, plat_idx) in landed.into_iter().chain(standing) {
+                if !riders.iter().any(|&(o, _)| o == obj_idx) { riders.push((obj_idx, plat_idx)); }
+            }
+        }
+        self.apply_platform_contact(&riders);
 
-        let n = self.store.objects.len();
-        for i in 0..n {
-            if !self.store.objects[i].visible { continue; }
-            for j in (i + 1)..n {
-                if !self.store.objects[j].visible { continue; }
+        for (idx, obj) in self.store.objects.iter_mut().enumerate() {
+            if !obj.visible { continue; }
+            if !obj.kinematic { obj.apply_resistance(); }
+            self.layout.offsets[idx] = rotation_adjusted_offset(
+                obj.position,
+                obj.size,
+                obj.rotation,
+                obj.slope.is_some(),
+                obj.pivot,
+            );
+        }
 
-                let o1 = &self.store.objects[i];
-                let o2 = &self.store.objects[j];
-                if !Self::check_collision(o1, o2) { continue; }
+        self.dispatch_collisions(pairs);
+    }
 
-                if !o1.is_platform && !o2.is_platform {
-                    collision_pairs.push((i, j));
-                    continue;
+    /// Moves every visible object by `fraction` of its momentum. Non-platform
+    /// objects are swept against axis-aligned rectangular platforms: one that
+    /// would cross a platform face during the move stops flush against the
+    /// face it hits first (moving platforms are swept relative to their own
+    /// motion), keeps its motion along the face and loses its momentum into
+    /// it. Surface platforms only block from their `surface_normal` side.
+    /// Candidates come from the broadphase, binned by the box each object
+    /// sweeps. Returns the (object, platform) pairs landed on a top face.
+    fn sweep_move(&mut self, fraction: f32) -> Vec<(usize, usize)> {
+        let filter = self.collision_filter();
+        let objects = &self.store.objects;
+        let step = |o: &object::GameObject| {
+            if o.kinematic { (0.0, 0.0) } else { (o.momentum.0 * fraction, o.momentum.1 * fraction) }
+        };
+        let mover   = |o: &object::GameObject| o.visible && !o.is_platform && !o.kinematic;
+        let blocker = |o: &object::GameObject| o.visible && o.is_platform && is_sweepable(o);
+
+        // Earliest (time, platform, normal) per object; ties go to the
+        // lower platform index.
+        let mut first_hits: BTreeMap<usize, (f32, usize, (f32, f32))> = BTreeMap::new();
+        let mut checks = 0u64;
+        for (a, b) in self.swept_candidate_pairs(fraction) {
+            let (i, j) = if mover(&objects[a]) && blocker(&objects[b]) {
+                (a, b)
+            } else if mover(&objects[b]) && blocker(&objects[a]) {
+                (b, a)
+            } else {
+                continue;
+            };
+            if filtered(&filter, i, j) { continue; }
+            checks += 1;
+
+            let plat = &objects[j];
+            let (dx, dy) = step(&objects[i]);
+            let (px, py) = step(plat);
+            let Some((t, normal)) = swept_aabb(collision_aabb(&objects[i]), collision_aabb(plat), (dx - px, dy - py))
+            else { continue; };
+            let blocks = match plat.collision_mode {
+                CollisionMode::Surface => {
+                    normal.0 * plat.surface_normal.0 + normal.1 * plat.surface_normal.1 > 0.7
                 }
+                _ => true,
+            };
+            if !blocks { continue; }
+            let earlier = first_hits.get(&i).map_or(true, |&(bt, bj, _)| t < bt || (t == bt && j < bj));
+            if earlier { first_hits.insert(i, (t, j, normal)); }
+        }
+        let hits: Vec<(usize, usize, (f32, f32))> = first_hits.into_iter()
+            .map(|(i, (_, j, normal))| (i, j, normal))
+            .collect();
+        self.frame_stats.collision_checks += checks;
 
-                let (obj_idx, plat_idx) = if o2.is_platform && !o1.is_platform {
-                    (i, j)
-                } else if o1.is_platform && !o2.is_platform {
-                    (j, i)
-                } else {
-                    continue;
-                };
-
-                let obj  = &self.store.objects[obj_idx];
-                let plat = &self.store.objects[plat_idx];
-                let obj_center_x = obj.position.0 + obj.size.0 * 0.5;
-
-                match &plat.collision_mode {
-                    CollisionMode::NonPlatform => { continue; }
-                    CollisionMode::Solid(shape) => {
-                        let result = match shape {
-                            CollisionShape::Rectangle => {
-                                resolve_solid_collision(obj, plat).map(|(dx, dy, _)| (dx, dy))
-                            }
-                            CollisionShape::Circle { radius } => {
-                                resolve_circle_collision(obj, plat, radius)
-                            }
-                        };
-                        if let Some((dx, dy)) = result {
-                            let dist = (dx * dx + dy * dy).sqrt().max(0.001);
-                            let nx = dx / dist;
-                            let ny = dy / dist;
-                            let approach = obj.momentum.0 * (-nx) + obj.momentum.1 * (-ny);
-                            if approach > 0.0 {
-                                adjustments.push((obj_idx, dx, dy, plat_idx));
-                            }
-                        }
-                        continue;
-                    }
-                    CollisionMode::Surface => {}
-                }
+        for obj in self.store.objects.iter_mut().filter(|o| o.visible && !o.kinematic) {
+            obj.position.0 += obj.momentum.0 * fraction;
+            obj.position.1 += obj.momentum.1 * fraction;
+        }
+
+        let mut landed = Vec::new();
+        for (obj_idx, plat_idx, (nx, ny)) in hits {
+            self.record_contact(obj_idx, plat_idx, (nx, ny));
+            let (px, py, pw, ph) = collision_aabb(&self.store.objects[plat_idx]);
+            let obj = &mut self.store.objects[obj_idx];
+            // Snap flush to the face, where the platform ended up.
+            if ny < 0.0 {
+                obj.position.1 = py - obj.size.1;
+            } else if ny > 0.0 {
+                obj.position.1 = py + ph;
+            } else if nx < 0.0 {
+                obj.position.0 = px - obj.size.0;
+            } else {
+                obj.position.0 = px + pw;
+            }
+            if ny < 0.0 {
+                obj.land();
+                landed.push((obj_idx, plat_idx));
+            }
+            self.respond_to_platform(obj_idx, plat_idx, (nx, ny));
+        }
+        landed
+    }
 
-                let (mut nx, mut ny) = plat.surface_normal_at(obj_center_x);
-                if plat.rotation != 0.0 && plat.slope.is_none() && ny > 0.0 {
-                    nx = -nx; ny = -ny;
+    /// Momentum response of an object pushed out of a platform along
+    /// `normal`: the `set_collision_response` handler if there is one,
+    /// otherwise the inward momentum is cancelled.
+    fn respond_to_platform(&mut self, obj_idx: usize, plat_idx: usize, normal: (f32, f32)) {
+        match self.callbacks.collision_response.take() {
+            Some(mut respond) => {
+                let contact = Contact { other: self.store.names[plat_idx].clone(), normal };
+                let (obj, plat) = pair_mut(&mut self.store.objects, obj_idx, plat_idx);
+                respond(obj, plat, contact);
+                self.callbacks.collision_response = Some(respond);
+            }
+            None => {
+                let obj = &mut self.store.objects[obj_idx];
+                let inward_speed = obj.momentum.0 * (-normal.0) + obj.momentum.1 * (-normal.1);
+                if inward_speed > 0.0 {
+                    obj.momentum.0 += normal.0 * inward_speed;
+                    obj.momentum.1 += normal.1 * inward_speed;
                 }
+            }
+        }
+    }
 
-                let approach_speed = obj.momentum.0 * (-nx) + obj.momentum.1 * (-ny);
-                if approach_speed <= 0.0 { continue; }
+    /// Separates two overlapping `rigid` objects (`i < j`) along `normal`,
+    /// which points away from `j`, in inverse proportion to their mass, and
+    /// exchanges momentum along it with an impulse that conserves the
+    /// pair's total momentum. The combined `elasticity` (geometric mean)
+    /// scales the rebound: 1 is a perfectly elastic bounce, 0 leaves them
+    /// moving together. Kinematic objects count as infinitely heavy.
+    fn resolve_rigid_pair(&mut self, i: usize, j: usize, normal: (f32, f32), cam_off: (f32, f32)) {
+        let (left, right) = self.store.objects.split_at_mut(j);
+        let (a, b) = (&mut left[i], &mut right[0]);
+        if !a.rigid || !b.rigid { return; }
+        let inverse_mass = |o: &object::GameObject| if o.kinematic { 0.0 } else { 1.0 / o.mass.max(0.001) };
+        let (inv_a, inv_b) = (inverse_mass(a), inverse_mass(b));
+        let total = inv_a + inv_b;
+        if total <= 0.0 { return; }
+
+        let (ax, ay, aw, ah) = collision_aabb(a);
+        let (bx, by, bw, bh) = collision_aabb(b);
+        let depth = if normal.0 != 0.0 {
+            (ax + aw).min(bx + bw) - ax.max(bx)
+        } else {
+            (ay + ah).min(by + bh) - ay.max(by)
+        };
+        if depth > 0.0 {
+            let (push_a, push_b) = (depth * inv_a / total, depth * inv_b / total);
+            a.position.0 += normal.0 * push_a;
+            a.position.1 += normal.1 * push_a;
+            b.position.0 -= normal.0 * push_b;
+            b.position.1 -= normal.1 * push_b;
+        }
 
-                if plat.one_way {
-                    if plat.slope.is_some() {
-                        let prev_bottom = (obj.position.1 + obj.size.1) - obj.momentum.1;
-                        let prev_cx = obj_center_x - obj.momentum.0;
-                        if prev_bottom > plat.slope_surface_y(prev_cx) + 2.0 { continue; }
-                    } else {
-                        let obj_cx = obj.position.0 + obj.size.0 * 0.5;
-                        let obj_cy = obj.position.1 + obj.size.1 * 0.5;
-                        let plat_cx = plat.position.0 + plat.size.0 * 0.5;
-                        let plat_cy = plat.position.1 + plat.size.1 * 0.5;
-                        let prev_rel_x = (obj_cx - obj.momentum.0) - plat_cx;
-                        let prev_rel_y = (obj_cy - obj.momentum.1) - plat_cy;
-                        if !(prev_rel_x * nx + prev_rel_y * ny > 0.0) { continue; }
+        let closing = (a.momentum.0 - b.momentum.0) * normal.0 + (a.momentum.1 - b.momentum.1) * normal.1;
+        if closing < 0.0 {
+            let elasticity = (a.material.elasticity * b.material.elasticity).sqrt().clamp(0.0, 1.0);
+            let impulse = -(1.0 + elasticity) * closing / total;
+            a.momentum.0 += normal.0 * impulse * inv_a;
+            a.momentum.1 += normal.1 * impulse * inv_a;
+            b.momentum.0 -= normal.0 * impulse * inv_b;
+            b.momentum.1 -= normal.1 * impulse * inv_b;
+        }
+
+        for idx in [i, j] {
+            let obj = &self.store.objects[idx];
+            let adj = rotation_adjusted_offset(obj.position, obj.size, obj.rotation, obj.slope.is_some(), obj.pivot);
+            self.layout.offsets[idx] = (adj.0 - cam_off.0, adj.1 - cam_off.1);
+        }
+    }
+
+    /// Couples objects to the platform they stand on: the platform's
+    /// horizontal movement this step is added to the rider's position, and
+    /// `platform_friction` bleeds off the rider's own horizontal momentum
+    /// (before `resistance`, which still applies as usual).
+    fn apply_platform_contact(&mut self, riders: &[(usize, usize)]) {
+        for &(obj_idx, plat_idx) in riders {
+            let plat = &self.store.objects[plat_idx];
+            let carry    = plat.position.0 - plat.previous_position.0;
+            let friction = plat.platform_friction;
+            let obj = &mut self.store.objects[obj_idx];
+            obj.position.0 += carry;
+            obj.momentum.0 *= 1.0 - friction;
+        }
+    }
+
+    pub(crate) fn handle_collisions(&mut self) {
+        self.step_contacts.clear();
+        let pairs = self.resolve_collisions().0;
+        self.dispatch_collisions(pairs);
+    }
+
+    /// Pushes objects out of the platforms they overlap, without firing any
+    /// events. Returns the overlapping non-platform pairs, and the
+    /// (object, platform) pairs where the object was pushed up onto a top face.
+    fn resolve_collisions(&mut self) -> (Vec<(usize, usize)>, Vec<(usize, usize)>) {
+        let started = std::time::Instant::now();
+        let mut adjustments: Vec<(usize, f32, f32, usize)> = Vec::new();
+        let mut collision_pairs: Vec<(usize, usize)> = Vec::new();
+        let mut standing: Vec<(usize, usize)> = Vec::new();
+        let mut checks = 0u64;
+
+        let filter = self.collision_filter();
+        for (i, j) in self.candidate_pairs() {
+            if filtered(&filter, i, j) { continue; }
+            let o1 = &self.store.objects[i];
+            let o2 = &self.store.objects[j];
+            if o1.is_platform && o2.is_platform && !self.platforms_collide { continue; }
+            checks += 1;
+            if !Self::check_collision(o1, o2) { continue; }
+
+            if !o1.is_platform && !o2.is_platform {
+                collision_pairs.push((i, j));
+                continue;
+            }
+
+            let (obj_idx, plat_idx) = match (o1.is_platform, o2.is_platform) {
+                (false, _) => (i, j),
+                (true, false) => (j, i),
+                // Both platforms (set_platforms_collide): the dynamic
+                // one gives way.
+                (true, true) => if o1.kinematic { (j, i) } else { (i, j) },
+            };
+            // Kinematic objects hold their place against platforms.
+            if self.store.objects[obj_idx].kinematic { continue; }
+
+            let obj  = &self.store.objects[obj_idx];
+            let plat = &self.store.objects[plat_idx];
+            let obj_center_x = obj.position.0 + obj.size.0 * 0.5;
+
+            match &plat.collision_mode {
+                CollisionMode::NonPlatform => { continue; }
+                CollisionMode::Solid(shape) => {
+                    let result = match shape {
+                        CollisionShape::Rectangle => {
+                            resolve_solid_collision(obj, plat).map(|(dx, dy, _)| (dx, dy))
+                        }
+                        CollisionShape::Circle { radius } => {
+                            resolve_circle_collision(obj, plat, radius)
+                        }
+                    };
+                    // Always push out along the minimum translation vector,
+                    // even when the object isn't moving inward (it was
+                    // spawned inside, or the platform moved into it). Only
+                    // the inward momentum is cancelled, below.
+                    if let Some((dx, dy)) = result {
+                        adjustments.push((obj_idx, dx, dy, plat_idx));
                     }
+                    continue;
                 }
+                CollisionMode::Surface => {}
+            }
 
-                let (dx, dy) = if plat.slope.is_some() {
-                    let surface_y = plat.slope_surface_y(obj_center_x);
-                    if obj.position.1 + obj.size.1 <= surface_y { continue; }
+            let (mut nx, mut ny) = plat.surface_normal_at(obj_center_x);
+            if plat.rotation != 0.0 && plat.slope.is_none() && ny > 0.0 {
+                nx = -nx; ny = -ny;
+            }
+
+            let approach_speed = obj.momentum.0 * (-nx) + obj.momentum.1 * (-ny);
+            if approach_speed <= 0.0 { continue; }
+
+            if plat.one_way {
+                if plat.slope.is_some() {
                     let prev_bottom = (obj.position.1 + obj.size.1) - obj.momentum.1;
                     let prev_cx = obj_center_x - obj.momentum.0;
-                    if prev_bottom > plat.slope_surface_y(prev_cx) + 20.0 { continue; }
-                    (0.0, (surface_y - obj.size.1) - obj.position.1)
-                } else if plat.rotation != 0.0 {
-                    let surface_y = rotated_surface_y(plat, obj_center_x);
-                    let obj_bottom = obj.position.1 + obj.size.1;
-                    if obj_bottom <= surface_y { continue; }
-                    let prev_bottom = obj_bottom - obj.momentum.1;
-                    let prev_cx = obj_center_x - obj.momentum.0;
-                    if prev_bottom > rotated_surface_y(plat, prev_cx) + 20.0 { continue; }
-                    (0.0, (surface_y - obj.size.1) - obj.position.1)
+                    if prev_bottom > plat.slope_surface_y(prev_cx) + 2.0 { continue; }
                 } else {
-                    let depth = penetration_depth(obj, plat, nx, ny);
-                    if depth <= 0.0 { continue; }
-                    (nx * depth, ny * depth)
-                };
-
-                adjustments.push((obj_idx, dx, dy, plat_idx));
+                    let obj_cx = obj.position.0 + obj.size.0 * 0.5;
+                    let obj_cy = obj.position.1 + obj.size.1 * 0.5;
+                    let plat_cx = plat.position.0 + plat.size.0 * 0.5;
+                    let plat_cy = plat.position.1 + plat.size.1 * 0.5;
+                    let prev_rel_x = (obj_cx - obj.momentum.0) - plat_cx;
+                    let prev_rel_y = (obj_cy - obj.momentum.1) - plat_cy;
+                    if !(prev_rel_x * nx + prev_rel_y * ny > 0.0) { continue; }
+                }
             }
+
+            let (dx, dy) = if plat.slope.is_some() {
+                let surface_y = plat.slope_surface_y(obj_center_x);
+                if obj.position.1 + obj.size.1 <= surface_y { continue; }
+                let prev_bottom = (obj.position.1 + obj.size.1) - obj.momentum.1;
+                let prev_cx = obj_center_x - obj.momentum.0;
+                if prev_bottom > plat.slope_surface_y(prev_cx) + 20.0 { continue; }
+                (0.0, (surface_y - obj.size.1) - obj.position.1)
+            } else if plat.rotation != 0.0 {
+                let surface_y = rotated_surface_y(plat, obj_center_x);
+                let obj_bottom = obj.position.1 + obj.size.1;
+                if obj_bottom <= surface_y { continue; }
+                let prev_bottom = obj_bottom - obj.momentum.1;
+                let prev_cx = obj_center_x - obj.momentum.0;
+                if prev_bottom > rotated_surface_y(plat, prev_cx) + 20.0 { continue; }
+                (0.0, (surface_y - obj.size.1) - obj.position.1)
+            } else {
+                let depth = penetration_depth(obj, plat, nx, ny);
+                if depth <= 0.0 { continue; }
+                (nx * depth, ny * depth)
+            };
+
+            adjustments.push((obj_idx, dx, dy, plat_idx));
         }
 
         let cam_off = self.active_camera.as_ref().map(|c| c.position).unwrap_or((0.0, 0.0));
@@ -355,17 +693,14 @@ impl Canvas {
             };
 
             let surf_vel = plat.surface_velocity;
+            self.record_contact(obj_idx, plat_idx, (nx, ny));
             let obj = &mut self.store.objects[obj_idx];
-
-            let inward_speed = obj.momentum.0 * (-nx) + obj.momentum.1 * (-ny);
-            if inward_speed > 0.0 {
-                obj.momentum.0 += nx * inward_speed;
-                obj.momentum.1 += ny * inward_speed;
-            }
-
             obj.position.0 += dx;
             obj.position.1 += dy;
-            if ny < -0.3 { obj.grounded = true; }
+            if ny < -0.3 {
+                obj.land();
+                standing.push((obj_idx, plat_idx));
+            }
 
             let adj = rotation_adjusted_offset(
                 obj.position,
@@ -376,16 +711,23 @@ impl Canvas {
             );
             self.layout.offsets[obj_idx] = (adj.0 - cam_off.0, adj.1 - cam_off.1);
 
+            self.respond_to_platform(obj_idx, plat_idx, (nx, ny));
             if let Some(vx) = surf_vel {
                 self.store.objects[obj_idx].momentum.0 += -ny * vx;
                 self.store.objects[obj_idx].momentum.1 +=  nx * vx;
             }
         }
 
-        for (i, j) in collision_pairs {
-            self.trigger_collision_events(i);
-            self.trigger_collision_events(j);
+        for &(i, j) in &collision_pairs {
+            let normal = overlap_normal(&self.store.objects[i], &self.store.objects[j]);
+            self.record_contact(i, j, normal);
+            // Crystalline resolves object pairs in its own solver.
+            if self.crystalline.is_none() { self.resolve_rigid_pair(i, j, normal, cam_off); }
         }
+
+        self.frame_stats.collision_checks += checks;
+        self.frame_stats.collision_ms += super::stats::elapsed_ms(started);
+        (collision_pairs, standing)
     }
 
     pub(crate) fn handle_infinite_scroll(&mut self) {
@@ -473,7 +815,7 @@ impl Canvas {
         let mut results: Vec<GravityResult> = Vec::new();
 
         for (obj_idx, obj) in self.store.objects.iter().enumerate() {
-            if !obj.visible { continue; }
+            if !obj.visible || !obj.gravity_enabled || obj.kinematic { continue; }
 
             let tag_filter: Option<&str> = if obj.gravity_all_sources {
                 None
@@ -755,7 +1097,7 @@ impl Canvas {
     }
 }
 
-fn shortest_angle_diff(from: f32, to: f32) -> f32 {
+pub(crate) fn shortest_angle_diff(from: f32, to: f32) -> f32 {
     let diff = (to - from).rem_euclid(360.0);
     if diff > 180.0 { diff - 360.0 } else { diff }
 }
@@ -793,6 +1135,87 @@ pub(crate) fn rotation_adjusted_offset(
     (cx - hw, cy - hh)
 }
 
+/// The (x, y, w, h) box `check_collision` tests: sloped and rotated
+/// platforms use their swept bounds, everything else its plain rect.
+pub(crate) fn collision_aabb(obj: &object::GameObject) -> (f32, f32, f32, f32) {
+    if obj.is_platform && obj.slope.is_some() {
+        obj.slope_aabb()
+    } else if obj.is_platform && obj.rotation != 0.0 {
+        rotated_aabb(obj)
+    } else {
+        (obj.position.0, obj.position.1, obj.size.0, obj.size.1)
+    }
+}
+
+/// The object at `a` mutably alongside the one at `b`; `a != b`.
+fn pair_mut(objects: &mut [object::GameObject], a: usize, b: usize) -> (&mut object::GameObject, &object::GameObject) {
+    if a < b {
+        let (left, right) = objects.split_at_mut(b);
+        (&mut left[a], &right[0])
+    } else {
+        let (left, right) = objects.split_at_mut(a);
+        (&mut right[0], &left[b])
+    }
+}
+
+/// Axis of least overlap between two overlapping boxes, as a unit normal
+/// pushing `a` away from `b`.
+fn overlap_normal(a: &object::GameObject, b: &object::GameObject) -> (f32, f32) {
+    let (ax, ay, aw, ah) = collision_aabb(a);
+    let (bx, by, bw, bh) = collision_aabb(b);
+    let overlap_x = (ax + aw).min(bx + bw) - ax.max(bx);
+    let overlap_y = (ay + ah).min(by + bh) - ay.max(by);
+    if overlap_x < overlap_y {
+        (((ax + aw * 0.5) - (bx + bw * 0.5)).signum(), 0.0)
+    } else {
+        (0.0, ((ay + ah * 0.5) - (by + bh * 0.5)).signum())
+    }
+}
+
+/// Platforms `sweep_move` handles: unrotated, unsloped rectangles.
+fn is_sweepable(plat: &object::GameObject) -> bool {
+    plat.rotation == 0.0
+        && plat.slope.is_none()
+        && matches!(plat.collision_mode, CollisionMode::Surface | CollisionMode::Solid(CollisionShape::Rectangle))
+}
+
+/// Earliest time of impact in [0, 1] of box `a` moving by `delta` against
+/// static box `b`, with the normal of the face of `b` it hits. None if it
+/// misses, or if the boxes already overlap (left to penetration resolution).
+/// Boxes that only touch count as a hit at t = 0 when moving into each other.
+fn swept_aabb(
+    (ax, ay, aw, ah): (f32, f32, f32, f32),
+    (bx, by, bw, bh): (f32, f32, f32, f32),
+    delta: (f32, f32),
+) -> Option<(f32, (f32, f32))> {
+    // Entry and exit times along one axis.
+    let axis = |a: f32, a_len: f32, b: f32, b_len: f32, d: f32| -> Option<(f32, f32)> {
+        if d > 0.0 {
+            Some(((b - (a + a_len)) / d, (b + b_len - a) / d))
+        } else if d < 0.0 {
+            Some(((b + b_len - a) / d, (b - (a + a_len)) / d))
+        } else if a < b + b_len && a + a_len > b {
+            Some((f32::NEG_INFINITY, f32::INFINITY))
+        } else {
+            None
+        }
+    };
+    let (x_entry, x_exit) = axis(ax, aw, bx, bw, delta.0)?;
+    let (y_entry, y_exit) = axis(ay, ah, by, bh, delta.1)?;
+
+    let entry = x_entry.max(y_entry);
+    let exit  = x_exit.min(y_exit);
+    if entry < 0.0 || entry > 1.0 || entry >= exit { return None; }
+
+    // Ties go to the vertical face, so corner landings land.
+    let normal = if y_entry >= x_entry {
+        (0.0, -delta.1.signum())
+    } else {
+        (-delta.0.signum(), 0.0)
+    };
+    Some((entry, normal))
+}
+
 fn rotated_aabb(obj: &object::GameObject) -> (f32, f32, f32, f32) {
     if obj.rotation == 0.0 {
         return (obj.position.0, obj.position.1, obj.size.0, obj.size.1);
@@ -936,4 +1359,261 @@ fn resolve_circle_collision(
     if dist < 0.001 { return Some((0.0, -combined)); }
     let overlap = combined - dist;
     Some((dx / dist * overlap, dy / dist * overlap))
-}
\ No newline at end of file
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::canvas::{BroadPhase, Canvas};
+    use crate::object::GameObject;
+    use crate::testing::{canvas, count, object, platform, run};
+    use crate::types::{Action, GameEvent, Target};
+
+    /// A 10px box falling 500px per step onto a 2px platform 300px below.
+    fn fast_faller(broadphase: BroadPhase) -> Canvas {
+        let mut cv = canvas();
+        cv.set_broadphase(broadphase);
+        cv.add_game_object("ball".into(), object("ball", (100.0, 0.0), (10.0, 10.0)).with_velocity((0.0, 500.0)));
+        cv.add_game_object("ledge".into(), platform("ledge", (0.0, 300.0), (400.0, 2.0)));
+        cv
+    }
+
+    #[test]
+    fn fast_object_lands_instead_of_tunnelling() {
+        for broadphase in [BroadPhase::BruteForce, BroadPhase::Grid { cell_size: 16.0 }] {
+            let mut cv = fast_faller(broadphase);
+            run(&mut cv, 1);
+            let ball = cv.get_game_object("ball").unwrap();
+            assert_eq!(ball.position.1, 290.0, "{broadphase:?}");
+            assert_eq!(ball.momentum.1, 0.0, "{broadphase:?}");
+            assert!(ball.grounded, "{broadphase:?}");
+        }
+    }
+
+    #[test]
+    fn accelerating_faller_lands_on_a_thin_ledge() {
+        let mut cv = fast_faller(BroadPhase::BruteForce);
+        let ball = cv.get_game_object_mut("ball").unwrap();
+        ball.momentum = (0.0, 0.0);
+        ball.set_gravity(40.0);
+        run(&mut cv, 30);
+        let ball = cv.get_game_object("ball").unwrap();
+        assert_eq!(ball.position.1, 290.0);
+        assert!(ball.grounded);
+    }
+
+    #[test]
+    fn bullet_stops_at_a_thin_wall() {
+        let mut cv = canvas();
+        let wall = GameObject::build("wall").position(300.0, 0.0).size(2.0, 400.0).solid().kinematic(true).finish();
+        cv.add_game_object("wall".into(), wall);
+        cv.add_game_object("bullet".into(), object("bullet", (100.0, 100.0), (4.0, 4.0)).with_velocity((400.0, 0.0)));
+        run(&mut cv, 1);
+        let bullet = cv.get_game_object("bullet").unwrap();
+        assert_eq!(bullet.position, (296.0, 100.0));
+        assert_eq!(bullet.momentum.0, 0.0);
+    }
+
+    #[test]
+    fn acceleration_and_gravity_add_the_same_speed_per_second_at_any_rate() {
+        for hz in [30, 60, 120] {
+            let mut cv = canvas();
+            cv.add_game_object("rocket".into(), object("rocket", (0.0, 1000.0), (10.0, 10.0)).with_acceleration((0.0, -20.0)));
+            cv.add_game_object("rock".into(), object("rock", (100.0, 0.0), (10.0, 10.0)));
+            cv.get_game_object_mut("rock").unwrap().set_gravity(0.5);
+            for _ in 0..hz { cv.step(1.0 / hz as f32); }
+
+            let rocket = cv.get_game_object("rocket").unwrap().velocity();
+            assert!((rocket.1 + 20.0).abs() < 1e-3, "{hz} Hz: {rocket:?}");
+            // Gravity counts per 1/60 s: a second of 0.5 is 30 px/step.
+            let rock = cv.get_game_object("rock").unwrap().velocity();
+            assert!((rock.1 - 30.0).abs() < 1e-3, "{hz} Hz: {rock:?}");
+        }
+    }
+
+    #[test]
+    fn grid_sweep_skips_distant_platforms() {
+        let checks = |broadphase| {
+            let mut cv = fast_faller(broadphase);
+            for i in 0..20 {
+                let name = format!("far{i}");
+                cv.add_game_object(name.clone(), platform(&name, (2000.0 + i as f32 * 50.0, 1500.0), (40.0, 10.0)));
+            }
+            run(&mut cv, 1);
+            assert_eq!(cv.get_game_object("ball").unwrap().position.1, 290.0);
+            cv.frame_stats.collision_checks
+        };
+        assert!(checks(BroadPhase::Grid { cell_size: 32.0 }) < checks(BroadPhase::BruteForce));
+    }
+
+    #[test]
+    fn collision_events_fire_for_any_partner_unless_gated() {
+        let mut cv = canvas();
+        cv.add_game_object("player".into(), object("player", (0.0, 0.0), (20.0, 20.0)));
+        cv.add_game_object("coin".into(), object("coin", (10.0, 0.0), (20.0, 20.0)).with_tag("coin"));
+        cv.add_game_object("enemy".into(), object("enemy", (0.0, 10.0), (20.0, 20.0)).with_tag("enemy"));
+        for var in ["touches", "coins", "coin_exits", "enemy_exits"] { cv.set_var(var, 0); }
+
+        // `target` naming the owner, as before partner filtering existed.
+        cv.add_event(GameEvent::Collision { action: count("touches"), target: Target::self_object() }, Target::name("player"));
+        cv.add_event(GameEvent::CollisionEnter { action: count("coins"), target: Target::tag("coin") }
+            .with_partner(Target::tag("coin")), Target::name("player"));
+        cv.add_event(GameEvent::CollisionExit { action: count("coin_exits"), target: Target::name("coin") }
+            .with_partner(Target::name("coin")), Target::name("player"));
+        cv.add_event(GameEvent::CollisionExit { action: count("enemy_exits"), target: Target::tag("enemy") }
+            .with_partner(Target::tag("enemy")), Target::name("player"));
+
+        run(&mut cv, 1);
+        assert_eq!(cv.get_i32("touches"), 2);
+        assert_eq!(cv.get_i32("coins"), 1);
+
+        // A removed partner is still matched by name.
+        cv.run(Action::remove(Target::name("coin")));
+        run(&mut cv, 1);
+        assert_eq!(cv.get_i32("touches"), 3);
+        assert_eq!(cv.get_i32("coin_exits"), 1);
+        assert_eq!(cv.get_i32("enemy_exits"), 0);
+    }
+
+    #[test]
+    fn enter_and_exit_fire_once_per_contact() {
+        let mut cv = canvas();
+        cv.add_game_object("a".into(), object("a", (0.0, 0.0), (20.0, 20.0)));
+        cv.add_game_object("b".into(), object("b", (10.0, 0.0), (20.0, 20.0)));
+        for var in ["stays", "enters", "exits"] { cv.set_var(var, 0); }
+        cv.add_event(GameEvent::Collision { action: count("stays"), target: Target::all() }, Target::name("a"));
+        cv.add_event(GameEvent::CollisionEnter { action: count("enters"), target: Target::all() }, Target::name("a"));
+        cv.add_event(GameEvent::CollisionExit { action: count("exits"), target: Target::all() }, Target::name("a"));
+
+        run(&mut cv, 5);
+        assert_eq!((cv.get_i32("stays"), cv.get_i32("enters"), cv.get_i32("exits")), (5, 1, 0));
+
+        cv.get_game_object_mut("b").unwrap().position.0 = 200.0;
+        run(&mut cv, 5);
+        assert_eq!((cv.get_i32("stays"), cv.get_i32("enters"), cv.get_i32("exits")), (5, 1, 1));
+    }
+
+    #[test]
+    fn jumps_are_spent_in_the_air_and_refilled_on_landing() {
+        let mut cv = canvas();
+        cv.add_game_object("hero".into(), object("hero", (100.0, 180.0), (20.0, 20.0)).with_max_jumps(2));
+        cv.get_game_object_mut("hero").unwrap().set_gravity(1.0);
+        cv.add_game_object("floor".into(), platform("floor", (0.0, 200.0), (400.0, 20.0)));
+        let hero = |cv: &Canvas| cv.get_game_object("hero").unwrap().clone();
+        let jump = Action::jump(Target::name("hero"), 10.0);
+
+        run(&mut cv, 3);
+        assert!(hero(&cv).grounded);
+        assert_eq!(hero(&cv).jumps_remaining, 2);
+
+        cv.run(jump.clone());
+        assert_eq!((hero(&cv).jumps_remaining, hero(&cv).momentum.1), (1, -10.0));
+        run(&mut cv, 2);
+        assert!(!hero(&cv).grounded);
+
+        cv.run(jump.clone());
+        assert_eq!((hero(&cv).jumps_remaining, hero(&cv).momentum.1), (0, -10.0));
+        run(&mut cv, 2);
+
+        // Out of jumps: a third press changes nothing.
+        let falling = hero(&cv).momentum.1;
+        cv.run(jump.clone());
+        assert_eq!((hero(&cv).jumps_remaining, hero(&cv).momentum.1), (0, falling));
+
+        run(&mut cv, 120);
+        let landed = hero(&cv);
+        assert!(landed.grounded);
+        assert!((landed.position.1 - 180.0).abs() < 1e-3, "{:?}", landed.position);
+        assert_eq!(landed.jumps_remaining, 2);
+
+        cv.run(jump);
+        assert_eq!(hero(&cv).jumps_remaining, 1);
+    }
+
+    #[test]
+    fn kinematic_patrol_moves_and_carries_its_rider() {
+        let mut cv = canvas();
+        let lift = platform("lift", (0.0, 100.0), (60.0, 10.0)).with_patrol(vec![(0.0, 100.0), (100.0, 100.0)], 5.0, false);
+        cv.add_game_object("lift".into(), lift);
+        cv.add_game_object("rider".into(), object("rider", (20.0, 80.0), (10.0, 20.0)));
+        cv.get_game_object_mut("rider").unwrap().set_gravity(1.0);
+
+        run(&mut cv, 10);
+        assert_eq!(cv.get_game_object("lift").unwrap().position, (50.0, 100.0));
+        let rider = cv.get_game_object("rider").unwrap();
+        assert!(rider.grounded);
+        assert!(rider.position.0 >= 60.0, "{:?}", rider.position);
+
+        // Back and forth: the far end turns it around.
+        run(&mut cv, 20);
+        assert_eq!(cv.get_game_object("lift").unwrap().position, (50.0, 100.0));
+    }
+
+    /// Pushes a 20px ball from `start` at `push` px/step into a solid
+    /// 100px block at (200, 200) for six steps; returns the ball and the
+    /// normals it reported against the block on the last one.
+    fn push_into_block(start: (f32, f32), push: (f32, f32)) -> (GameObject, Vec<(f32, f32)>) {
+        let mut cv = canvas();
+        let block = GameObject::build("block").position(200.0, 200.0).size(100.0, 100.0).solid().kinematic(true).finish();
+        cv.add_game_object("block".into(), block);
+        cv.add_game_object("ball".into(), object("ball", start, (20.0, 20.0)));
+        let shove = Action::set_velocity(Target::self_object(), push.0, push.1);
+        cv.add_event(GameEvent::tick(shove, Target::self_object()), Target::name("ball"));
+        run(&mut cv, 6);
+        let normals = cv.contacts_of(&Target::name("ball")).into_iter()
+            .filter(|c| c.other == "block")
+            .map(|c| c.normal)
+            .collect();
+        (cv.get_game_object("ball").unwrap().clone(), normals)
+    }
+
+    #[test]
+    fn solid_block_stops_an_object_from_the_left() {
+        let (ball, normals) = push_into_block((150.0, 240.0), (10.0, 0.0));
+        assert_eq!(ball.position, (180.0, 240.0));
+        assert_eq!(ball.momentum.0, 0.0);
+        assert_eq!(normals, [(-1.0, 0.0)]);
+    }
+
+    #[test]
+    fn solid_block_stops_an_object_from_the_right() {
+        let (ball, normals) = push_into_block((330.0, 240.0), (-10.0, 0.0));
+        assert_eq!(ball.position, (300.0, 240.0));
+        assert_eq!(ball.momentum.0, 0.0);
+        assert_eq!(normals, [(1.0, 0.0)]);
+    }
+
+    #[test]
+    fn solid_block_stops_an_object_from_above() {
+        let (ball, normals) = push_into_block((240.0, 150.0), (0.0, 10.0));
+        assert_eq!(ball.position, (240.0, 180.0));
+        assert_eq!(ball.momentum.1, 0.0);
+        assert!(ball.grounded);
+        assert_eq!(normals, [(0.0, -1.0)]);
+    }
+
+    #[test]
+    fn solid_block_stops_an_object_from_below() {
+        let (ball, normals) = push_into_block((240.0, 330.0), (0.0, -10.0));
+        assert_eq!(ball.position, (240.0, 300.0));
+        assert_eq!(ball.momentum.1, 0.0);
+        assert!(!ball.grounded);
+        assert_eq!(normals, [(0.0, 1.0)]);
+    }
+
+    #[test]
+    fn overlapping_platforms_separate_only_when_enabled() {
+        for enabled in [false, true] {
+            let mut cv = canvas();
+            cv.set_platforms_collide(enabled);
+            let ground = GameObject::build("ground").position(0.0, 100.0).size(200.0, 20.0).solid().kinematic(true).finish();
+            let crate_ = GameObject::build("crate").position(50.0, 90.0).size(40.0, 20.0).solid().finish();
+            cv.add_game_object("ground".into(), ground);
+            cv.add_game_object("crate".into(), crate_);
+            run(&mut cv, 3);
+
+            let expected = if enabled { (50.0, 80.0) } else { (50.0, 90.0) };
+            assert_eq!(cv.get_game_object("crate").unwrap().position, expected, "enabled: {enabled}");
+            assert_eq!(cv.get_game_object("ground").unwrap().position, (0.0, 100.0), "enabled: {enabled}");
+        }
+    }
+}
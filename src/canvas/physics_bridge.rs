@@ -410,7 +410,7 @@ impl Canvas {
         let mut results: Vec<GravityResult> = Vec::new();
 
         for (obj_idx, obj) in self.store.objects.iter().enumerate() {
-            if !obj.visible || obj.is_platform { continue; }
+            if !obj.visible || obj.is_platform || !obj.gravity_enabled || obj.kinematic { continue; }
 
             let tag_filter: Option<&str> = if obj.gravity_all_sources {
                 None
@@ -654,16 +654,33 @@ impl Canvas {
 
     /// Refresh sorted_offsets from the live offset arrays without re-sorting.
     /// Call this at the end of every tick so `build()` sees current positions.
+    /// Object offsets are pulled back toward `previous_position` by the
+    /// unconsumed fraction of the fixed step, so motion renders smoothly.
     pub(crate) fn sync_sorted_offsets(&mut self) {
         use super::core::RenderSlot;
+        let lag = 1.0 - self.interpolation_alpha();
         for (i, slot) in self.render_order.iter().enumerate() {
             let off = match slot {
                 RenderSlot::Object(obj_i) => {
-                    self.layout.offsets.get(*obj_i).copied().unwrap_or((0.0, 0.0))
+                    let off = self.layout.offsets.get(*obj_i).copied().unwrap_or((0.0, 0.0));
+                    match self.store.objects.get(*obj_i) {
+                        Some(obj) if obj.screen_pin.is_none() => (
+                            off.0 - (obj.position.0 - obj.previous_position.0) * lag,
+                            off.1 - (obj.position.1 - obj.previous_position.1) * lag,
+                        ),
+                        _ => off,
+                    }
                 }
                 RenderSlot::Particle(p_i) => {
                     self.layout.particle_offsets.get(*p_i).copied().unwrap_or((0.0, 0.0))
                 }
+                RenderSlot::Background => (0.0, 0.0),
+                RenderSlot::Letterbox(b_i) => {
+                    self.layout.letterbox_offsets.get(*b_i).copied().unwrap_or((0.0, 0.0))
+                }
+                RenderSlot::Debug(d_i) => {
+                    self.layout.debug_offsets.get(*d_i).copied().unwrap_or((0.0, 0.0))
+                }
             };
             if let Some(s) = self.layout.sorted_offsets.get_mut(i) {
                 *s = off;
@@ -671,6 +688,17 @@ impl Canvas {
         }
     }
 
+    /// Top-down depth sorting: within each layer, objects whose bottom edge
+    /// (`position.1 + size.1`) is lower on screen draw in front. `layer`
+    /// stays the primary key; ties keep insertion order. Re-sorted every
+    /// frame, independent of the physics order.
+    pub fn set_y_sort(&mut self, enabled: bool) {
+        self.y_sort = enabled;
+        self.rebuild_render_order();
+    }
+
+    pub fn y_sort(&self) -> bool { self.y_sort }
+
     /// Build sorted render_order + sorted_offsets from object & particle layers.
     pub(crate) fn rebuild_render_order(&mut self) {
         use super::core::RenderSlot;
@@ -678,28 +706,44 @@ impl Canvas {
         let obj_count = self.store.objects.len();
         let part_count = self.particle_images.len();
 
-        let mut slots: Vec<(i32, usize, RenderSlot)> = Vec::with_capacity(obj_count + part_count);
+        let mut slots: Vec<(i32, f32, usize, RenderSlot)> = Vec::with_capacity(obj_count + part_count);
 
         for i in 0..obj_count {
-            let layer = self.store.objects[i].layer;
-            slots.push((layer, i, RenderSlot::Object(i)));
+            let obj = &self.store.objects[i];
+            let depth = if self.y_sort { obj.position.1 + obj.size.1 } else { 0.0 };
+            slots.push((obj.layer, depth, i, RenderSlot::Object(i)));
         }
         for i in 0..part_count {
             let layer = self.particle_render_layers.get(i).copied().unwrap_or(0);
             // Use obj_count + i as secondary key so particles at the same layer
             // sort after objects (preserving backward-compatible default).
-            slots.push((layer, obj_count + i, RenderSlot::Particle(i)));
+            let depth = if self.y_sort { f32::INFINITY } else { 0.0 };
+            slots.push((layer, depth, obj_count + i, RenderSlot::Particle(i)));
         }
 
-        // Stable sort by layer first, then by original insertion order.
-        slots.sort_by(|a, b| a.0.cmp(&b.0).then(a.1.cmp(&b.1)));
+        // Sort by layer first, then depth (y_sort only), then insertion order.
+        slots.sort_by(|a, b| a.0.cmp(&b.0).then(a.1.total_cmp(&b.1)).then(a.2.cmp(&b.2)));
 
         self.render_order.clear();
         self.layout.sorted_offsets.clear();
         self.layout.sorted_ignore_zoom.clear();
-
-        for &(_, _, slot) in &slots {
+        self.layout.sorted_shakes.clear();
+
+        // Backdrop sits outside the layer sort: background behind everything,
+        // letterbox bars on top so nothing bleeds outside the virtual region.
+        let background = self.background_image.is_some().then_some(RenderSlot::Background);
+        let debug = (0..self.debug_images.len()).map(RenderSlot::Debug);
+        let bars  = (0..self.letterbox_images.len()).map(RenderSlot::Letterbox);
+        let slots = background.into_iter()
+            .chain(slots.into_iter().map(|(_, _, _, slot)| slot))
+            .chain(debug)
+            .chain(bars)
+            .collect::<Vec<_>>();
+
+        for &slot in &slots {
             self.render_order.push(slot);
+            let backdrop = matches!(slot, RenderSlot::Background | RenderSlot::Letterbox(_));
+            self.layout.sorted_shakes.push(!backdrop);
             match slot {
                 RenderSlot::Object(i)   => {
                     let off = self.layout.offsets.get(i).copied().unwrap_or((0.0, 0.0));
@@ -712,6 +756,20 @@ impl Canvas {
                     self.layout.sorted_offsets.push(off);
                     self.layout.sorted_ignore_zoom.push(false);
                 }
+                RenderSlot::Background => {
+                    self.layout.sorted_offsets.push((0.0, 0.0));
+                    self.layout.sorted_ignore_zoom.push(true);
+                }
+                RenderSlot::Letterbox(i) => {
+                    let off = self.layout.letterbox_offsets.get(i).copied().unwrap_or((0.0, 0.0));
+                    self.layout.sorted_offsets.push(off);
+                    self.layout.sorted_ignore_zoom.push(true);
+                }
+                RenderSlot::Debug(i) => {
+                    let off = self.layout.debug_offsets.get(i).copied().unwrap_or((0.0, 0.0));
+                    self.layout.sorted_offsets.push(off);
+                    self.layout.sorted_ignore_zoom.push(false);
+                }
             }
         }
     }
@@ -728,8 +786,8 @@ pub(crate) fn build_physics_bodies(canvas: &Canvas) -> Vec<PhysicsBody> {
             id: idx,
             position: obj.position,
             size: obj.size,
-            momentum: obj.momentum,
-            gravity: obj.gravity,
+            momentum: if obj.kinematic { (0.0, 0.0) } else { obj.momentum },
+            gravity: if obj.gravity_enabled && !obj.kinematic { obj.gravity } else { 0.0 },
             resistance: obj.resistance,
             rotation: obj.rotation,
             rotation_momentum: obj.rotation_momentum,
@@ -754,11 +812,15 @@ pub(crate) fn build_physics_bodies(canvas: &Canvas) -> Vec<PhysicsBody> {
 pub(crate) fn apply_physics_result(canvas: &mut Canvas, result: PhysicsStepResult) {
     for update in result.body_updates {
         let (size, has_slope, pivot) = if let Some(obj) = canvas.store.objects.get_mut(update.id) {
-            obj.position = update.position;
-            obj.momentum = update.momentum;
-            obj.rotation = update.rotation;
-            obj.rotation_momentum = update.rotation_momentum;
+            // Kinematic bodies keep their own position and momentum.
+            if !obj.kinematic {
+                obj.position = update.position;
+                obj.momentum = update.momentum;
+                obj.rotation = update.rotation;
+                obj.rotation_momentum = update.rotation_momentum;
+            }
             obj.grounded = update.grounded;
+            if update.grounded { obj.land(); }
 
             // ── Slope alignment ──────────────────────────────────
             // When an object has align_to_slope enabled and is grounded on
@@ -0,0 +1,225 @@
+use super::core::Canvas;
+use super::physics::collision_aabb;
+use crate::object::GameObject;
+use crate::types::{CollisionMode, CollisionShape, Target};
+
+/// The nearest object hit by `Canvas::raycast`.
+#[derive(Clone, Debug, PartialEq)]
+pub struct RayHit {
+    pub name:     String,
+    pub point:    (f32, f32),
+    /// Distance from the ray origin to `point`.
+    pub distance: f32,
+    /// Unit surface normal at `point`, facing back toward the origin.
+    pub normal:   (f32, f32),
+}
+
+/// One object touching another during the last step. See `Canvas::contacts_of`.
+#[derive(Clone, Debug, PartialEq)]
+pub struct Contact {
+    /// Name of the other object.
+    pub other:  String,
+    /// Unit normal pointing away from `other`: the direction this object
+    /// was (or would be) pushed out. (0, -1) is standing on a floor.
+    pub normal: (f32, f32),
+}
+
+impl Canvas {
+    /// Everything `target`'s objects touched in the most recent step, as
+    /// found by that step's collision pass: platforms they were pushed out
+    /// of or stopped against, and overlapping non-platform objects. One
+    /// entry per (object, other) pair, in target order. Empty before the
+    /// first step. Under crystalline physics platform contacts are resolved
+    /// by the solver and only overlaps are reported.
+    pub fn contacts_of(&self, target: &Target) -> Vec<Contact> {
+        self.store.get_names(target).iter()
+            .filter_map(|name| self.step_contacts.get(name))
+            .flatten()
+            .cloned()
+            .collect()
+    }
+
+    /// First visible object along the ray from `origin` in `direction`
+    /// (any length), up to `max_dist` px. `mask` limits the candidates to
+    /// the objects it resolves to. Objects are tested with the same boxes as
+    /// collision (circles for `Solid(Circle)`); objects containing `origin`
+    /// are skipped, so a ray cast from an object's centre ignores itself.
+    pub fn raycast(
+        &self,
+        origin:    (f32, f32),
+        direction: (f32, f32),
+        max_dist:  f32,
+        mask:      Option<Target>,
+    ) -> Option<RayHit> {
+        match mask {
+            Some(target) => self.nearest_ray_hit(origin, direction, max_dist, self.store.get_indices(&target)),
+            None         => self.nearest_ray_hit(origin, direction, max_dist, 0..self.store.objects.len()),
+        }
+    }
+
+    /// `raycast` against objects whose `collision_layer` shares a bit with
+    /// `layers` (see `collision_layers`).
+    pub fn raycast_layers(
+        &self,
+        origin:    (f32, f32),
+        direction: (f32, f32),
+        max_dist:  f32,
+        layers:    u32,
+    ) -> Option<RayHit> {
+        let candidates = (0..self.store.objects.len())
+            .filter(|&i| self.store.objects[i].collision_layer & layers != 0);
+        self.nearest_ray_hit(origin, direction, max_dist, candidates)
+    }
+
+    /// True if a ray from the centre of `from` reaches the centre of `to`
+    /// without hitting anything else first. Uses the first object of each.
+    pub fn line_of_sight(&self, from: &Target, to: &Target) -> bool {
+        let (Some(&a), Some(&b)) = (self.store.get_indices(from).first(), self.store.get_indices(to).first())
+        else { return false; };
+        let (origin, goal) = (self.store.objects[a].center(), self.store.objects[b].center());
+        let (dx, dy) = (goal.0 - origin.0, goal.1 - origin.1);
+        let dist = (dx * dx + dy * dy).sqrt();
+        match self.raycast(origin, (dx, dy), dist, None) {
+            Some(hit) => self.store.name_to_index.get(&hit.name) == Some(&b),
+            None      => true,
+        }
+    }
+
+    /// Visible objects whose collision box overlaps the region from `min`
+    /// to `max` (top-left, bottom-right), in insertion order. Hidden
+    /// objects are skipped, as they are by collision.
+    pub fn query_region(&self, min: (f32, f32), max: (f32, f32)) -> Vec<&GameObject> {
+        self.store.objects.iter()
+            .filter(|obj| obj.visible)
+            .filter(|obj| {
+                let (x, y, w, h) = collision_aabb(obj);
+                x < max.0 && x + w > min.0 && y < max.1 && y + h > min.1
+            })
+            .collect()
+    }
+
+    /// Visible objects whose collision box touches the circle, e.g. an
+    /// explosion radius.
+    pub fn query_circle(&self, center: (f32, f32), radius: f32) -> Vec<&GameObject> {
+        self.store.objects.iter()
+            .filter(|obj| obj.visible)
+            .filter(|obj| {
+                let (x, y, w, h) = collision_aabb(obj);
+                let dx = center.0 - center.0.clamp(x, x + w);
+                let dy = center.1 - center.1.clamp(y, y + h);
+                dx * dx + dy * dy <= radius * radius
+            })
+            .collect()
+    }
+
+    /// Name of the topmost visible object whose box contains `point`
+    /// (world coordinates, as mouse events use): the one drawn in front,
+    /// by `layer`, then y-sort depth when on, then insertion order.
+    pub fn object_at(&self, point: (f32, f32)) -> Option<&str> {
+        self.object_at_with(point, false)
+    }
+
+    /// `object_at`, also considering hidden objects if `include_hidden`.
+    pub fn object_at_with(&self, point: (f32, f32), include_hidden: bool) -> Option<&str> {
+        let depth = |obj: &GameObject| if self.y_sort { obj.position.1 + obj.size.1 } else { 0.0 };
+        self.store.objects.iter().enumerate()
+            .filter(|(_, obj)| (include_hidden || obj.visible) && obj.contains_point(point))
+            .max_by(|(a, oa), (b, ob)| {
+                oa.layer.cmp(&ob.layer).then(depth(oa).total_cmp(&depth(ob))).then(a.cmp(b))
+            })
+            .map(|(idx, _)| self.store.names[idx].as_str())
+    }
+
+    /// Indices of objects overlapping any of `target`'s objects (excluding
+    /// those objects), by the same test collision events use. Names are in
+    /// `store.names`.
+    pub fn query_overlapping(&self, target: &Target) -> Vec<usize> {
+        let sources = self.store.get_indices(target);
+        (0..self.store.objects.len())
+            .filter(|i| !sources.contains(i))
+            .filter(|&i| sources.iter().any(|&s| {
+                Self::check_collision(&self.store.objects[s], &self.store.objects[i])
+            }))
+            .collect()
+    }
+
+    fn nearest_ray_hit(
+        &self,
+        origin:     (f32, f32),
+        direction:  (f32, f32),
+        max_dist:   f32,
+        candidates: impl IntoIterator<Item = usize>,
+    ) -> Option<RayHit> {
+        let len = (direction.0 * direction.0 + direction.1 * direction.1).sqrt();
+        if len < f32::EPSILON || max_dist <= 0.0 { return None; }
+        let dir = (direction.0 / len, direction.1 / len);
+
+        let mut best: Option<(usize, f32, (f32, f32))> = None;
+        for idx in candidates {
+            let Some(obj) = self.store.objects.get(idx) else { continue; };
+            if !obj.visible { continue; }
+            let hit = match &obj.collision_mode {
+                CollisionMode::Solid(CollisionShape::Circle { radius }) => {
+                    let r = if *radius <= 0.0 { obj.size.0.min(obj.size.1) * 0.5 } else { *radius };
+                    ray_circle(origin, dir, obj.rotated_center(), r)
+                }
+                _ => ray_aabb(origin, dir, collision_aabb(obj)),
+            };
+            if let Some((t, normal)) = hit {
+                if t <= max_dist && best.map_or(true, |(_, best_t, _)| t < best_t) {
+                    best = Some((idx, t, normal));
+                }
+            }
+        }
+
+        best.map(|(idx, t, normal)| RayHit {
+            name:     self.store.names[idx].clone(),
+            point:    (origin.0 + dir.0 * t, origin.1 + dir.1 * t),
+            distance: t,
+            normal,
+        })
+    }
+}
+
+/// Entry distance along unit `dir` into the box, with the entered face's
+/// normal. None on a miss or when `origin` is inside.
+fn ray_aabb(origin: (f32, f32), dir: (f32, f32), (x, y, w, h): (f32, f32, f32, f32)) -> Option<(f32, (f32, f32))> {
+    let mut t_enter = f32::NEG_INFINITY;
+    let mut t_exit  = f32::INFINITY;
+    let mut normal  = (0.0, 0.0);
+
+    for (o, d, min, max, axis_normal) in [
+        (origin.0, dir.0, x, x + w, (-1.0, 0.0)),
+        (origin.1, dir.1, y, y + h, (0.0, -1.0)),
+    ] {
+        if d.abs() < f32::EPSILON {
+            if o < min || o > max { return None; }
+            continue;
+        }
+        let (mut near, mut far) = ((min - o) / d, (max - o) / d);
+        let mut n = axis_normal;
+        if near > far {
+            std::mem::swap(&mut near, &mut far);
+            n = (-n.0, -n.1);
+        }
+        if near > t_enter { t_enter = near; normal = n; }
+        t_exit = t_exit.min(far);
+    }
+
+    (t_enter >= 0.0 && t_enter <= t_exit).then_some((t_enter, normal))
+}
+
+/// Like `ray_aabb`, for a circle.
+fn ray_circle(origin: (f32, f32), dir: (f32, f32), center: (f32, f32), radius: f32) -> Option<(f32, (f32, f32))> {
+    let m = (origin.0 - center.0, origin.1 - center.1);
+    let c = m.0 * m.0 + m.1 * m.1 - radius * radius;
+    if c <= 0.0 { return None; }
+    let b = m.0 * dir.0 + m.1 * dir.1;
+    let disc = b * b - c;
+    if disc < 0.0 { return None; }
+    let t = -b - disc.sqrt();
+    if t < 0.0 { return None; }
+    let point = (origin.0 + dir.0 * t, origin.1 + dir.1 * t);
+    let r = radius.max(f32::EPSILON);
+    Some((t, ((point.0 - center.0) / r, (point.1 - center.1) / r)))
+}
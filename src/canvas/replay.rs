@@ -0,0 +1,253 @@
+use prism::event::{Key, KeyboardState, Modifiers, NamedKey};
+
+use super::core::Canvas;
+
+/// Key input captured by `Canvas::start_recording`, step by step, for
+/// `Canvas::play_recording` to feed back. Save it with `to_json` to attach
+/// to a bug report.
+#[derive(Debug, Clone, Default, PartialEq)]
+pub struct Recording {
+    /// `entropy` state when recording started; playback restores it.
+    pub seed: u64,
+    pub keys: Vec<RecordedKey>,
+}
+
+/// One key press or release in a `Recording`.
+#[derive(Debug, Clone, PartialEq)]
+pub struct RecordedKey {
+    /// Fixed steps run since recording started when the key changed; it's
+    /// replayed just before the next one.
+    pub step:    u64,
+    pub pressed: bool,
+    pub key:     Key,
+    /// Modifier state the key arrived with; None for injected keys, which
+    /// only match events without a modifier requirement.
+    pub modifiers: Option<Modifiers>,
+}
+
+/// A recording being played back. See `Canvas::play_recording`.
+#[derive(Debug, Clone)]
+pub(crate) struct Playback {
+    recording:  Recording,
+    /// Index of the next key to replay.
+    next:       usize,
+    start_step: u64,
+}
+
+impl Recording {
+    /// `{"seed": .., "keys": [{"step": .., "pressed": .., "key": ..}]}`,
+    /// with named keys as `{"named": "ArrowLeft"}`, characters as
+    /// `{"char": "a"}` and, for keys that arrived with modifier state,
+    /// `"modifiers": ["shift", "ctrl"]`. Fails on a named key outside
+    /// `NAMED_KEYS`, which recording never captures.
+    pub fn to_json(&self) -> Result<String, String> {
+        let mut keys = Vec::with_capacity(self.keys.len());
+        for k in &self.keys {
+            let key = match &k.key {
+                Key::Character(s) => serde_json::json!({ "char": s.to_string() }),
+                Key::Named(n) => {
+                    let name = key_name(n).ok_or_else(|| format!("key {n:?} can't be saved"))?;
+                    serde_json::json!({ "named": name })
+                }
+            };
+            let mut entry = serde_json::json!({ "step": k.step, "pressed": k.pressed, "key": key });
+            if let Some(m) = &k.modifiers {
+                let held: Vec<&str> = [("shift", m.shift), ("ctrl", m.ctrl), ("alt", m.alt), ("meta", m.meta)]
+                    .into_iter().filter(|&(_, on)| on).map(|(name, _)| name).collect();
+                entry["modifiers"] = serde_json::json!(held);
+            }
+            keys.push(entry);
+        }
+        Ok(serde_json::json!({ "seed": self.seed, "keys": keys }).to_string())
+    }
+
+    /// Reads a recording saved by `to_json`.
+    pub fn from_json(json: &str) -> Result<Self, String> {
+        let root: serde_json::Value = serde_json::from_str(json).map_err(|e| e.to_string())?;
+        let seed = root.get("seed").and_then(|v| v.as_u64()).ok_or("recording has no seed")?;
+        let entries = root.get("keys").and_then(|v| v.as_array()).ok_or("recording has no keys")?;
+        let mut keys = Vec::with_capacity(entries.len());
+        for entry in entries {
+            let step    = entry.get("step").and_then(|v| v.as_u64()).ok_or("key without a step")?;
+            let pressed = entry.get("pressed").and_then(|v| v.as_bool()).ok_or("key without pressed")?;
+            let raw     = entry.get("key").ok_or("entry without a key")?;
+            let key = if let Some(c) = raw.get("char").and_then(|v| v.as_str()) {
+                Key::Character(c.into())
+            } else {
+                let name = raw.get("named").and_then(|v| v.as_str()).ok_or("key is neither char nor named")?;
+                Key::Named(named_key(name).ok_or_else(|| format!("unknown key name '{name}'"))?)
+            };
+            let modifiers = match entry.get("modifiers") {
+                None => None,
+                Some(list) => {
+                    let list = list.as_array().ok_or("modifiers isn't a list")?;
+                    let mut m = Modifiers::none();
+                    for name in list {
+                        match name.as_str() {
+                            Some("shift") => m.shift = true,
+                            Some("ctrl")  => m.ctrl = true,
+                            Some("alt")   => m.alt = true,
+                            Some("meta")  => m.meta = true,
+                            _ => return Err(format!("unknown modifier {name}")),
+                        }
+                    }
+                    Some(m)
+                }
+            };
+            keys.push(RecordedKey { step, pressed, key, modifiers });
+        }
+        Ok(Self { seed, keys })
+    }
+}
+
+/// Named keys a recording can hold, by their saved name. Recording skips
+/// any other named key, so everything captured can be saved and loaded.
+const NAMED_KEYS: [(&str, NamedKey); 16] = [
+    ("ArrowLeft",  NamedKey::ArrowLeft),
+    ("ArrowRight", NamedKey::ArrowRight),
+    ("ArrowUp",    NamedKey::ArrowUp),
+    ("ArrowDown",  NamedKey::ArrowDown),
+    ("Space",      NamedKey::Space),
+    ("Enter",      NamedKey::Enter),
+    ("Tab",        NamedKey::Tab),
+    ("Delete",     NamedKey::Delete),
+    ("Escape",     NamedKey::Escape),
+    ("Shift",      NamedKey::Shift),
+    ("Control",    NamedKey::Control),
+    ("Alt",        NamedKey::Alt),
+    ("Meta",       NamedKey::Meta),
+    ("CapsLock",   NamedKey::CapsLock),
+    ("NumLock",    NamedKey::NumLock),
+    ("ScrollLock", NamedKey::ScrollLock),
+];
+
+fn named_key(name: &str) -> Option<NamedKey> {
+    NAMED_KEYS.iter().find(|(n, _)| *n == name).map(|(_, key)| key.clone())
+}
+
+fn key_name(key: &NamedKey) -> Option<&'static str> {
+    NAMED_KEYS.iter().find(|(_, k)| k == key).map(|(name, _)| *name)
+}
+
+impl Canvas {
+    /// Starts capturing every key press and release, from the window or
+    /// `inject_key`, with the step it happened before, plus the current
+    /// `entropy` state and the modifiers each key arrived with. Restarts if
+    /// already recording. Named keys outside `NAMED_KEYS` (function keys,
+    /// media keys..) and mouse input aren't captured.
+    pub fn start_recording(&mut self) {
+        self.recording = Some((Recording { seed: self.entropy.state(), keys: Vec::new() }, self.step_count));
+    }
+
+    /// Ends the recording and hands it over; None if none was running.
+    pub fn stop_recording(&mut self) -> Option<Recording> {
+        self.recording.take().map(|(recording, _)| recording)
+    }
+
+    pub fn is_recording(&self) -> bool { self.recording.is_some() }
+
+    /// Replays `recording` from the next step: restores its `entropy` state
+    /// and re-injects each key before the same step it originally preceded,
+    /// ignoring the real keyboard until the last key has played. Started
+    /// from the same scene state (e.g. right after `load_scene`) a session
+    /// plays out exactly as recorded, with `step` or in real time.
+    pub fn play_recording(&mut self, recording: Recording) {
+        self.entropy.seed(recording.seed);
+        self.playback = Some(Playback { recording, next: 0, start_step: self.step_count });
+    }
+
+    pub fn stop_playback(&mut self) { self.playback = None; }
+
+    pub fn is_playing_recording(&self) -> bool { self.playback.is_some() }
+
+    pub(crate) fn record_key(&mut self, state: &KeyboardState, key: &Key, modifiers: Option<&Modifiers>) {
+        let Some((recording, start_step)) = self.recording.as_mut() else { return; };
+        if let Key::Named(named) = key { if key_name(named).is_none() { return; } }
+        let pressed = matches!(state, KeyboardState::Pressed);
+        recording.keys.push(RecordedKey {
+            step: self.step_count - *start_step,
+            pressed,
+            key: key.clone(),
+            modifiers: modifiers.copied(),
+        });
+    }
+
+    /// Injects the recorded keys due before the step about to run.
+    pub(crate) fn replay_due_keys(&mut self) {
+        let Some(mut playback) = self.playback.take() else { return; };
+        let step = self.step_count - playback.start_step;
+        while let Some(recorded) = playback.recording.keys.get(playback.next) {
+            if recorded.step > step { break; }
+            let state = if recorded.pressed { KeyboardState::Pressed } else { KeyboardState::Released };
+            let (key, modifiers) = (recorded.key.clone(), recorded.modifiers);
+            playback.next += 1;
+            self.handle_key(&state, &key, modifiers.as_ref());
+        }
+        if playback.next < playback.recording.keys.len() { self.playback = Some(playback); }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use prism::event::{Key, KeyboardState, Modifiers};
+
+    use super::{RecordedKey, Recording, NAMED_KEYS};
+    use crate::testing::{canvas, count, object, run};
+    use crate::types::{GameEvent, Target};
+
+    #[test]
+    fn json_keeps_every_named_key_and_modifier() {
+        let mut keys: Vec<RecordedKey> = NAMED_KEYS.iter().enumerate().map(|(i, (_, key))| RecordedKey {
+            step: i as u64,
+            pressed: i % 2 == 0,
+            key: Key::Named(key.clone()),
+            modifiers: None,
+        }).collect();
+        let mut ctrl_shift = Modifiers::ctrl();
+        ctrl_shift.shift = true;
+        keys.push(RecordedKey { step: 40, pressed: true, key: Key::Character("s".into()), modifiers: Some(ctrl_shift) });
+        keys.push(RecordedKey { step: 41, pressed: false, key: Key::Character("s".into()), modifiers: Some(Modifiers::none()) });
+        let recording = Recording { seed: 7, keys };
+
+        let json = recording.to_json().unwrap();
+        assert_eq!(Recording::from_json(&json).unwrap(), recording);
+    }
+
+    #[test]
+    fn unknown_names_and_modifiers_are_rejected() {
+        let entry = |key: &str, extra: &str| format!(r#"{{"seed": 1, "keys": [{{"step": 0, "pressed": true, "key": {key}{extra}}}]}}"#);
+        assert!(Recording::from_json(&entry(r#"{"named": "Escape"}"#, "")).is_ok());
+        assert!(Recording::from_json(&entry(r#"{"named": "Hyper"}"#, "")).is_err());
+        assert!(Recording::from_json(&entry(r#"{"char": "a"}"#, r#", "modifiers": ["super"]"#)).is_err());
+    }
+
+    #[test]
+    fn replay_keeps_the_modifiers_a_key_was_pressed_with() {
+        let mut cv = canvas();
+        cv.add_game_object("hero".into(), object("hero", (0.0, 0.0), (10.0, 10.0)));
+        cv.set_var("saves", 0);
+        cv.set_var("presses", 0);
+        let s = Key::Character("s".into());
+        cv.add_event(GameEvent::KeyPress { key: s.clone(), action: count("saves"), target: Target::self_object(), modifiers: Some(Modifiers::ctrl()) },
+                     Target::name("hero"));
+        cv.add_event(GameEvent::KeyPress { key: s.clone(), action: count("presses"), target: Target::self_object(), modifiers: None },
+                     Target::name("hero"));
+
+        cv.start_recording();
+        cv.handle_key(&KeyboardState::Pressed, &s, Some(&Modifiers::ctrl()));
+        run(&mut cv, 1);
+        cv.handle_key(&KeyboardState::Released, &s, Some(&Modifiers::ctrl()));
+        cv.inject_key(KeyboardState::Pressed, s.clone());
+        cv.inject_key(KeyboardState::Released, s.clone());
+        run(&mut cv, 1);
+        let recording = Recording::from_json(&cv.stop_recording().unwrap().to_json().unwrap()).unwrap();
+        assert_eq!((cv.get_i32("saves"), cv.get_i32("presses")), (1, 2));
+
+        cv.set_var("saves", 0);
+        cv.set_var("presses", 0);
+        cv.play_recording(recording);
+        run(&mut cv, 3);
+        assert_eq!((cv.get_i32("saves"), cv.get_i32("presses")), (1, 2));
+        assert!(!cv.is_playing_recording());
+    }
+}
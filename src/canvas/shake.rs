@@ -0,0 +1,79 @@
+use super::core::Canvas;
+use crate::camera::ShakeEffect;
+
+impl Canvas {
+    /// Shakes the drawn scene (objects, particles, debug outlines) by up to
+    /// `intensity` virtual pixels, decaying linearly to zero over `duration`
+    /// seconds. The background fill and letterbox bars stay put. Offsets
+    /// come from `entropy`, so a seeded canvas shakes the same way every run.
+    ///
+    /// Render-only: positions, collisions and mouse mapping are unaffected.
+    /// Stacks on top of the camera (including the camera's own shake).
+    pub fn shake(&mut self, intensity: f32, duration: f32) {
+        if duration <= 0.0 || intensity <= 0.0 { return; }
+        self.screen_shake = Some(ShakeEffect { intensity, duration, elapsed: 0.0, offset: (0.0, 0.0) });
+    }
+
+    pub fn is_shaking(&self) -> bool { self.screen_shake.is_some() }
+
+    /// Advances the shake by one step and publishes its offset to the layout.
+    pub(crate) fn advance_screen_shake(&mut self, dt: f32) {
+        let Some(mut s) = self.screen_shake.take() else { return; };
+        s.elapsed += dt;
+        if s.elapsed >= s.duration {
+            self.layout.shake_offset.set((0.0, 0.0));
+            return;
+        }
+        let decay = 1.0 - s.elapsed / s.duration;
+        s.offset = (
+            self.entropy.range(-s.intensity, s.intensity) * decay,
+            self.entropy.range(-s.intensity, s.intensity) * decay,
+        );
+        self.layout.shake_offset.set(s.offset);
+        self.screen_shake = Some(s);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use prism::canvas::Color;
+    use prism::layout::{Layout, SizeRequest};
+
+    use crate::canvas::core::RenderSlot;
+    use crate::testing::{canvas, object, run};
+
+    #[test]
+    fn shake_moves_objects_but_not_the_backdrop() {
+        let mut cv = canvas();
+        let size = (1280.0, 1024.0);
+        cv.set_window_size(size);
+        cv.set_background_color(Color(0, 0, 0, 255));
+        cv.set_letterbox_color(Color(0, 0, 0, 255));
+        cv.add_game_object("crate".into(), object("crate", (100.0, 100.0), (20.0, 20.0)));
+        cv.shake(50.0, 1.0);
+        run(&mut cv, 1);
+        let shake = cv.layout.shake_offset.get();
+        assert_ne!(shake, (0.0, 0.0));
+
+        let children = cv.render_order.iter().map(|_| SizeRequest::new(0.0, 0.0, 1.0, 1.0)).collect();
+        let areas = cv.layout.build(size, children);
+        let scale = cv.layout.base_scale.get();
+        let mut seen = (0, 0);
+        for ((slot, area), &offset) in cv.render_order.iter().zip(&areas).zip(&cv.layout.sorted_offsets) {
+            let still = cv.layout.virtual_to_screen(offset, true);
+            match slot {
+                RenderSlot::Background | RenderSlot::Letterbox(_) => {
+                    assert_eq!(area.offset, still, "{slot:?}");
+                    seen.0 += 1;
+                }
+                RenderSlot::Object(_) => {
+                    let moved = (area.offset.0 - still.0, area.offset.1 - still.1);
+                    assert!((moved.0 - shake.0 * scale).abs() < 1e-3 && (moved.1 - shake.1 * scale).abs() < 1e-3);
+                    seen.1 += 1;
+                }
+                _ => {}
+            }
+        }
+        assert_eq!(seen, (5, 1));
+    }
+}
@@ -0,0 +1,100 @@
+use super::core::Canvas;
+use crate::types::Target;
+
+impl Canvas {
+    /// Advances every object's `StateMachine` by one step: enters the
+    /// initial state on the first step, then switches on the first active
+    /// transition whose condition holds. Conditions and `on_enter` actions
+    /// run with `Target::Current` (and `Target::SelfObject`) set to the owner.
+    pub(crate) fn apply_state_machines(&mut self, dt: f32) {
+        let owners: Vec<String> = self.store.objects.iter()
+            .zip(&self.store.names)
+            .filter(|(obj, _)| obj.state_machine.is_some())
+            .map(|(_, name)| name.clone())
+            .collect();
+        if owners.is_empty() { return; }
+        let previous = self.store.current.take();
+        let previous_owner = self.store.owner.take();
+
+        for name in owners {
+            let Some(&idx) = self.store.name_to_index.get(&name) else { continue; };
+            let Some(machine) = self.store.objects[idx].state_machine.as_mut() else { continue; };
+            machine.elapsed += dt;
+            let initial = (!machine.entered).then(|| machine.current.clone());
+            let transitions = machine.states.get(&machine.current)
+                .map(|s| s.transitions.clone())
+                .unwrap_or_default();
+
+            self.store.current = Some(name.clone());
+            self.store.owner = Some(name.clone());
+            if let Some(state) = initial {
+                self.enter_state(&name, &state);
+            }
+            let next = transitions.into_iter()
+                .find(|(condition, _)| self.evaluate_condition(condition))
+                .map(|(_, to)| to);
+            if let Some(state) = next {
+                self.enter_state(&name, &state);
+            }
+        }
+
+        self.store.current = previous;
+        self.store.owner = previous_owner;
+    }
+
+    /// Switches each target's state machine to `state` and runs its
+    /// `on_enter`. Entering the current state restarts it. Objects without a
+    /// machine, or whose machine has no such state, are left alone.
+    pub fn set_state(&mut self, target: &Target, state: &str) {
+        let names = self.store.get_names(target);
+        let previous = self.store.current.take();
+        let previous_owner = self.store.owner.take();
+        for name in names {
+            self.store.current = Some(name.clone());
+            self.store.owner = Some(name.clone());
+            self.enter_state(&name, state);
+        }
+        self.store.current = previous;
+        self.store.owner = previous_owner;
+    }
+
+    /// Active state of the named object's machine, if it has one.
+    pub fn current_state(&self, name: &str) -> Option<&str> {
+        let &idx = self.store.name_to_index.get(name)?;
+        self.store.objects[idx].state_machine.as_ref().map(|m| m.state())
+    }
+
+    fn enter_state(&mut self, name: &str, state: &str) {
+        let Some(&idx) = self.store.name_to_index.get(name) else { return; };
+        let on_enter = self.store.objects[idx].state_machine.as_mut()
+            .and_then(|m| m.enter(state));
+        if let Some(action) = on_enter { self.run(action); }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use prism::event::{Key, KeyboardState, NamedKey};
+
+    use crate::object::StateMachine;
+    use crate::testing::{canvas, object};
+    use crate::types::{Action, GameEvent, Target};
+
+    #[test]
+    fn state_key_events_see_the_owner_as_current() {
+        let mut cv = canvas();
+        let dash = GameEvent::KeyPress {
+            key: Key::Named(NamedKey::Space),
+            action: Action::set_velocity(Target::Current, 3.0, 0.0),
+            target: Target::Current,
+            modifiers: None,
+        };
+        let machine = StateMachine::new("idle").event("idle", dash);
+        cv.add_game_object("fox".into(), object("fox", (0.0, 0.0), (10.0, 10.0)).with_state_machine(machine));
+        cv.add_game_object("hen".into(), object("hen", (50.0, 0.0), (10.0, 10.0)));
+
+        cv.inject_key(KeyboardState::Pressed, Key::Named(NamedKey::Space));
+        assert_eq!(cv.get_game_object("fox").unwrap().momentum, (3.0, 0.0));
+        assert_eq!(cv.get_game_object("hen").unwrap().momentum, (0.0, 0.0));
+    }
+}
@@ -0,0 +1,58 @@
+use std::time::Instant;
+
+use super::core::Canvas;
+
+/// Timing and workload of the last rendered frame. Cheap enough to leave on;
+/// read it with `Canvas::stats`.
+#[derive(Clone, Copy, Debug, Default, PartialEq)]
+pub struct TickStats {
+    /// Frames per second, smoothed over roughly the last half second.
+    pub fps:              f32,
+    /// Total time spent handling the last `TickEvent`, in milliseconds.
+    pub tick_ms:          f32,
+    /// Movement, gravity and animation. Includes `collision_ms`.
+    pub physics_ms:       f32,
+    /// Collision detection and response.
+    pub collision_ms:     f32,
+    /// Tick/key/custom events and tick callbacks.
+    pub events_ms:        f32,
+    /// Fixed simulation steps run during the frame.
+    pub steps:            u32,
+    pub object_count:     usize,
+    /// Object pairs compared for overlap during the frame, after the
+    /// broadphase (see `Canvas::set_broadphase`) has ruled out distant ones.
+    pub collision_checks: u64,
+}
+
+/// Weight of the newest frame in the fps moving average.
+const FPS_SMOOTHING: f32 = 0.1;
+
+impl Canvas {
+    /// Timing and workload of the last frame.
+    pub fn stats(&self) -> TickStats {
+        self.stats
+    }
+
+    /// Resets the in-progress counters at the start of a frame.
+    pub(crate) fn begin_frame_stats(&mut self) {
+        let fps = self.stats.fps;
+        self.frame_stats = TickStats { fps, ..TickStats::default() };
+    }
+
+    /// Publishes the in-progress counters as `stats()`.
+    pub(crate) fn end_frame_stats(&mut self, started: Instant, frame_time: f32) {
+        let mut s = self.frame_stats;
+        s.tick_ms      = elapsed_ms(started);
+        s.object_count = self.store.objects.len();
+        if frame_time > 0.0 {
+            let instant = 1.0 / frame_time;
+            s.fps = if s.fps == 0.0 { instant } else { s.fps + (instant - s.fps) * FPS_SMOOTHING };
+        }
+        self.stats = s;
+    }
+}
+
+/// Milliseconds since `start`.
+pub(crate) fn elapsed_ms(start: Instant) -> f32 {
+    start.elapsed().as_secs_f32() * 1000.0
+}
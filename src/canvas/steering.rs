@@ -0,0 +1,133 @@
+use super::core::Canvas;
+use super::physics::{collision_aabb, shortest_angle_diff};
+use crate::types::{Location, Target};
+
+/// An ongoing steering behaviour, re-evaluated every fixed step.
+/// Registered by `Action::MoveToward` / `Action::Flee`.
+#[derive(Clone, Debug)]
+pub(crate) struct Steering {
+    pub(crate) location: Location,
+    /// Pixels per step.
+    pub(crate) speed:    f32,
+    /// Toward: stop within this distance. Flee: only flee within it (0 = always).
+    pub(crate) radius:   f32,
+    pub(crate) flee:     bool,
+}
+
+impl Canvas {
+    /// Sets each steered object's momentum toward (or away from) its
+    /// location. Locations resolve with `Target::Current` set to the object.
+    /// Distances run from the centre of the object's collision box.
+    pub(crate) fn apply_steering(&mut self) {
+        if self.steering.is_empty() { return; }
+        let previous = self.store.current.take();
+        let names: Vec<String> = self.steering.keys().cloned().collect();
+
+        for name in names {
+            let Some(&idx) = self.store.name_to_index.get(&name) else { continue; };
+            let Some(steer) = self.steering.get(&name) else { continue; };
+            self.store.current = Some(name.clone());
+            let goal = self.steering_goal(&steer.location);
+            let (speed, radius, flee) = (steer.speed, steer.radius, steer.flee);
+
+            let obj = &mut self.store.objects[idx];
+            let (x, y, w, h) = collision_aabb(obj);
+            let (dx, dy) = (goal.0 - (x + w / 2.0), goal.1 - (y + h / 2.0));
+            let dist = (dx * dx + dy * dy).sqrt();
+
+            obj.momentum = if flee {
+                if (radius > 0.0 && dist >= radius) || dist < f32::EPSILON {
+                    obj.momentum
+                } else {
+                    (-dx / dist * speed, -dy / dist * speed)
+                }
+            } else if dist <= radius.max(f32::EPSILON) {
+                (0.0, 0.0)
+            } else {
+                // Never step past the goal, so arrival doesn't jitter.
+                let step = speed.min(dist - radius);
+                (dx / dist * step, dy / dist * step)
+            };
+        }
+
+        self.store.current = previous;
+    }
+
+    /// The point steering heads for: an object's collision-box centre when
+    /// the location is `AtTarget`, otherwise the resolved point itself.
+    fn steering_goal(&self, location: &Location) -> (f32, f32) {
+        if let Location::AtTarget(target) = location {
+            let centre = self.store.get_indices(target).first()
+                .and_then(|&i| self.store.objects.get(i))
+                .map(|o| {
+                    let (x, y, w, h) = collision_aabb(o);
+                    (x + w / 2.0, y + h / 2.0)
+                });
+            if let Some(centre) = centre { return centre; }
+        }
+        self.resolve_location(location)
+    }
+
+    /// Rotates each target to face `at`, by at most `max_turn` degrees if given.
+    pub(crate) fn look_at(&mut self, target: &Target, at: &Location, max_turn: Option<f32>) {
+        let names = self.store.get_names(target);
+        let previous = self.store.current.take();
+        for name in names {
+            let Some(&idx) = self.store.name_to_index.get(&name) else { continue; };
+            self.store.current = Some(name);
+            let goal = self.resolve_location(at);
+
+            let obj = &mut self.store.objects[idx];
+            let (cx, cy) = obj.center();
+            let (dx, dy) = (goal.0 - cx, goal.1 - cy);
+            if dx.abs() < f32::EPSILON && dy.abs() < f32::EPSILON { continue; }
+            let facing = dy.atan2(dx).to_degrees();
+            obj.rotation = match max_turn {
+                None       => facing,
+                Some(rate) => {
+                    let diff = shortest_angle_diff(obj.rotation, facing);
+                    obj.rotation + diff.clamp(-rate, rate)
+                }
+            };
+        }
+        self.store.current = previous;
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::canvas::Canvas;
+    use crate::testing::{canvas, object};
+    use crate::types::{Action, Location, Target};
+
+    fn momentum(cv: &Canvas, name: &str) -> (f32, f32) {
+        cv.get_game_object(name).unwrap().momentum
+    }
+
+    #[test]
+    fn chasers_line_up_centres_not_corners() {
+        let mut cv = canvas();
+        // Centres share y = 100; the top-left corners don't.
+        cv.add_game_object("ogre".into(), object("ogre", (0.0, 50.0), (100.0, 100.0)));
+        cv.add_game_object("imp".into(), object("imp", (500.0, 95.0), (10.0, 10.0)));
+        cv.run(Action::move_toward(Target::name("ogre"), Location::at_target(Target::name("imp")), 5.0, 0.0));
+        cv.apply_steering();
+        assert_eq!(momentum(&cv, "ogre"), (5.0, 0.0));
+
+        // Arrival is measured centre to centre too: 455px apart, 450 allowed.
+        cv.run(Action::move_toward(Target::name("ogre"), Location::at_target(Target::name("imp")), 20.0, 450.0));
+        cv.apply_steering();
+        assert_eq!(momentum(&cv, "ogre"), (5.0, 0.0));
+    }
+
+    #[test]
+    fn fleeing_runs_from_the_centre_of_a_wide_object() {
+        let mut cv = canvas();
+        // The mouse's corner is right of the wall's, but its centre is left of the wall's.
+        cv.add_game_object("wall".into(), object("wall", (0.0, 0.0), (400.0, 20.0)));
+        cv.add_game_object("mouse".into(), object("mouse", (100.0, 0.0), (40.0, 20.0)));
+        cv.run(Action::flee(Target::name("mouse"), Location::at_target(Target::name("wall")), 3.0, 0.0));
+        cv.apply_steering();
+        assert_eq!(momentum(&cv, "mouse"), (-3.0, 0.0));
+    }
+}
@@ -0,0 +1,81 @@
+use std::collections::HashMap;
+use prism::canvas::Image;
+
+use super::core::Canvas;
+use crate::object::GameObject;
+use crate::types::Target;
+
+/// What a tilemap character turns into. See `Canvas::load_tilemap`.
+#[derive(Clone, Debug, Default)]
+pub struct TileSpec {
+    pub image:    Option<Image>,
+    pub tags:     Vec<String>,
+    pub platform: bool,
+    /// Blocks from all four sides (walls, ceilings). Implies `platform`.
+    pub solid:    bool,
+    pub layer:    i32,
+}
+
+impl TileSpec {
+    pub fn new() -> Self { Self::default() }
+
+    pub fn image(mut self, image: Image)            -> Self { self.image = Some(image); self }
+    pub fn tag(mut self, tag: impl Into<String>)    -> Self { self.tags.push(tag.into()); self }
+    pub fn platform(mut self)                       -> Self { self.platform = true; self }
+    pub fn solid(mut self)                          -> Self { self.solid = true; self }
+    pub fn layer(mut self, layer: i32)              -> Self { self.layer = layer; self }
+}
+
+impl Canvas {
+    /// Builds a level from text rows: one object per character found in
+    /// `legend`, placed on a grid of `tile_size` cells starting at `origin`
+    /// (top-left of row 0, column 0). `' '` and `'.'` are empty cells, as is
+    /// any character missing from the legend.
+    ///
+    /// Objects are named `tile_{row}_{col}` and tagged with the legend's tags
+    /// plus `"tile"`. Returns the names in row-major order.
+    pub fn load_tilemap(
+        &mut self,
+        rows:      &[&str],
+        legend:    &HashMap<char, TileSpec>,
+        tile_size: (f32, f32),
+        origin:    (f32, f32),
+    ) -> Vec<String> {
+        let mut names = Vec::new();
+        for (row, line) in rows.iter().enumerate() {
+            for (col, ch) in line.chars().enumerate() {
+                if ch == ' ' || ch == '.' { continue; }
+                let Some(spec) = legend.get(&ch) else { continue; };
+
+                let name = format!("tile_{}_{}", row, col);
+                let mut builder = GameObject::build(name.clone())
+                    .size(tile_size.0, tile_size.1)
+                    .position(origin.0 + col as f32 * tile_size.0, origin.1 + row as f32 * tile_size.1)
+                    .layer(spec.layer)
+                    .tag("tile");
+                for tag in &spec.tags { builder = builder.tag(tag.clone()); }
+                if let Some(image) = &spec.image { builder = builder.image(image.clone()); }
+                if spec.platform { builder = builder.platform(); }
+                if spec.solid { builder = builder.solid(); }
+
+                self.add_game_object(name.clone(), builder.finish());
+                names.push(name);
+            }
+        }
+        names
+    }
+
+    /// Rounds each target's position to the nearest multiple of `tile_size`,
+    /// e.g. to settle objects back onto the grid after physics moves them.
+    pub fn snap_to_grid(&mut self, target: &Target, tile_size: (f32, f32)) {
+        if tile_size.0 <= 0.0 || tile_size.1 <= 0.0 { return; }
+        for idx in self.store.get_indices(target) {
+            if let Some(obj) = self.store.objects.get_mut(idx) {
+                obj.position = (
+                    (obj.position.0 / tile_size.0).round() * tile_size.0,
+                    (obj.position.1 / tile_size.1).round() * tile_size.1,
+                );
+            }
+        }
+    }
+}
@@ -24,13 +24,22 @@ impl Entropy {
         self.seed = self.seed
             .wrapping_mul(6364136223846793005)
             .wrapping_add(1442695040888963407);
-        ((self.seed >> 33) as f32) / (u32::MAX as f32)
+        // Top 24 bits: exactly representable in f32, so the result is in
+        // [0, 1). Before, this returned [0, 0.5) and every seed gave other
+        // values; see CHANGELOG.md.
+        ((self.seed >> 40) as f32) / ((1u64 << 24) as f32)
     }
  
     pub fn seed(&mut self, seed: u64) {
         self.seed = seed;
     }
  
+    /// The generator's current state; `seed` with it to replay the same
+    /// sequence from here.
+    pub fn state(&self) -> u64 {
+        self.seed
+    }
+ 
     pub fn reseed(&mut self) {
         *self = Self::from_time();
     }
@@ -68,4 +77,26 @@ impl Default for Entropy {
         Self::new()
     }
 }
- 
\ No newline at end of file
+ 
+#[cfg(test)]
+mod tests {
+    use super::Entropy;
+
+    #[test]
+    fn a_seed_always_gives_the_same_sequence() {
+        let mut entropy = Entropy::from_seed(1);
+        let values: Vec<f32> = (0..4).map(|_| entropy.next()).collect();
+        assert_eq!(values, [0.42320913, 0.5094074, 0.64835936, 0.38286334]);
+    }
+
+    #[test]
+    fn values_cover_the_whole_unit_range() {
+        let mut entropy = Entropy::from_seed(7);
+        let values: Vec<f32> = (0..10_000).map(|_| entropy.next()).collect();
+        assert!(values.iter().all(|v| (0.0..1.0).contains(v)));
+        let upper = values.iter().filter(|&&v| v >= 0.5).count();
+        assert!((4_500..5_500).contains(&upper), "{upper} of 10000 in the upper half");
+        let hits = (0..10_000).filter(|_| entropy.chance(0.75)).count();
+        assert!((7_000..8_000).contains(&hits), "chance(0.75) hit {hits} times");
+    }
+}
@@ -1,6 +1,6 @@
 use std::collections::{HashSet, HashMap};
 use prism::event::{Key, KeyboardEvent, KeyboardState, Modifiers, NamedKey};
-use crate::{Canvas, MouseButton, ScrollAxis, GameEvent};
+use crate::{Canvas, Contact, GameObject, MouseButton, ScrollAxis, GameEvent};
 
 pub trait Callback: FnMut(&mut Canvas, &Key) + 'static {
     fn clone_box(&self) -> Box<dyn Callback>;
@@ -20,6 +20,9 @@ impl std::fmt::Debug for dyn Callback {
 #[derive(Default, Debug)]
 pub struct InputState {
     pub held_keys:         HashSet<Key>,
+    /// Simulation seconds each held key has been down. Kept through the
+    /// key's release callbacks and events, then dropped.
+    pub held_durations:    HashMap<Key, f32>,
     pub press_callbacks:   Vec<Box<dyn Callback>>,
     pub release_callbacks: Vec<Box<dyn Callback>>,
 }
@@ -28,6 +31,7 @@ impl Clone for InputState {
     fn clone(&self) -> Self {
         Self {
             held_keys:         self.held_keys.clone(),
+            held_durations:    self.held_durations.clone(),
             press_callbacks:   self.press_callbacks.clone(),
             release_callbacks: self.release_callbacks.clone(),
         }
@@ -119,10 +123,29 @@ impl std::fmt::Debug for dyn EventCallback {
     }
 }
 
+pub trait CollisionResponseCallback: FnMut(&mut GameObject, &GameObject, Contact) + 'static {
+    fn clone_box(&self) -> Box<dyn CollisionResponseCallback>;
+}
+impl<F: FnMut(&mut GameObject, &GameObject, Contact) + Clone + 'static> CollisionResponseCallback for F {
+    fn clone_box(&self) -> Box<dyn CollisionResponseCallback> { Box::new(self.clone()) }
+}
+impl Clone for Box<dyn CollisionResponseCallback> {
+    fn clone(&self) -> Self { self.as_ref().clone_box() }
+}
+impl std::fmt::Debug for dyn CollisionResponseCallback {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "CollisionResponseCallback")
+    }
+}
+
 #[derive(Default, Debug)]
 pub struct CallbackStore {
     pub tick:   Vec<Box<dyn EventCallback>>,
     pub custom: HashMap<String, Box<dyn EventCallback>>,
+    /// See `Canvas::on_mode_change`.
+    pub mode_change: Vec<Box<dyn EventCallback>>,
+    /// See `Canvas::set_collision_response`.
+    pub collision_response: Option<Box<dyn CollisionResponseCallback>>,
 }
 
 impl Clone for CallbackStore {
@@ -130,6 +153,8 @@ impl Clone for CallbackStore {
         Self {
             tick:   self.tick.clone(),
             custom: self.custom.iter().map(|(k, v)| (k.clone(), v.clone())).collect(),
+            mode_change: self.mode_change.clone(),
+            collision_response: self.collision_response.clone(),
         }
     }
 }
@@ -158,10 +183,33 @@ impl Canvas {
         self.input.held_keys.contains(key)
     }
 
+    /// Seconds of simulation time `key` has been held, 0 if it's up. Inside
+    /// the key's own `on_key_release` callbacks and `KeyRelease` events it
+    /// still reads the hold that just ended, for charge-on-release moves.
+    pub fn key_held_duration(&self, key: &Key) -> f32 {
+        self.input.held_durations.get(key).copied().unwrap_or(0.0)
+    }
+
+    /// Feeds a key press or release through the same path as a window
+    /// `KeyboardEvent`: held-key state, `on_key_press`/`on_key_release`
+    /// callbacks and `KeyPress`/`KeyRelease` events. No modifiers are
+    /// down, so events that require modifiers don't fire. With `step`
+    /// this drives a canvas headlessly, e.g. in integration tests.
+    pub fn inject_key(&mut self, state: KeyboardState, key: Key) {
+        self.handle_key(&state, &key, None);
+    }
+
     pub(crate) fn handle_keyboard_event(&mut self, evt: &KeyboardEvent) {
+        if self.is_playing_recording() { return; }
         let KeyboardEvent { state, key, modifiers } = evt;
+        self.handle_key(state, key, Some(modifiers));
+    }
+
+    pub(crate) fn handle_key(&mut self, state: &KeyboardState, key: &Key, modifiers: Option<&Modifiers>) {
         match state {
             KeyboardState::Pressed if self.input.held_keys.insert(key.clone()) => {
+                self.input.held_durations.insert(key.clone(), 0.0);
+                self.record_key(state, key, modifiers);
                 println!("key {key:?}");
                 let key_clone = key.clone();
                 let mut cbs = std::mem::take(&mut self.input.press_callbacks);
@@ -174,6 +222,7 @@ impl Canvas {
             }
             KeyboardState::Released => {
                 self.input.held_keys.remove(key);
+                self.record_key(state, key, modifiers);
                 let key_clone = key.clone();
                 let mut cbs = std::mem::take(&mut self.input.release_callbacks);
                 for cb in cbs.iter_mut() { cb(self, &key_clone); }
@@ -182,36 +231,61 @@ impl Canvas {
                 if !is_modifier_key(key) {
                     self.process_key_events(key, modifiers, GameEvent::is_key_release);
                 }
+                self.input.held_durations.remove(key);
             }
             _ => {}
         }
     }
 
-    pub(crate) fn process_key_events<F>(&mut self, key: &Key, modifiers: &Modifiers, predicate: F)
+    /// `modifiers` is None for injected keys, which only match events
+    /// without a modifier requirement.
+    pub(crate) fn process_key_events<F>(&mut self, key: &Key, modifiers: Option<&Modifiers>, predicate: F)
     where
         F: Fn(&GameEvent) -> bool,
     {
-        let actions: Vec<_> = self.store.events.iter()
-            .flatten()
-            .filter(|e| predicate(e) && e.key() == Some(key))
-            .filter(|e| e.modifiers().map_or(true, |m| m == modifiers))
-            .map(|e| e.action().clone())
+        let actions: Vec<_> = self.store.owned_events()
+            .filter(|(_, e)| predicate(e) && e.key() == Some(key))
+            .filter(|(_, e)| e.modifiers().map_or(true, |m| Some(m) == modifiers))
+            .map(|(idx, e)| (self.store.names[idx].clone(), e.action().clone()))
             .collect();
-        actions.into_iter().for_each(|a| self.run(a));
+        actions.into_iter().for_each(|(owner, a)| self.run_owned(owner, a));
     }
 
+    /// Runs `KeyHold` actions for the keys currently down. Only the matching
+    /// actions are cloned (`run` takes ownership); the event table and the
+    /// held-key set are borrowed in place. A `ThrottledKeyHold` fires only
+    /// once its `min_interval` has passed since that same event last fired.
     pub(crate) fn process_held_key_events(&mut self) {
-        let held = self.input.held_keys.clone();
+        let held = &self.input.held_keys;
+        if held.is_empty() { return; }
         let modifier_held = held.iter().any(is_modifier_key);
-        let actions: Vec<_> = self.store.events.iter()
-            .flatten()
-            .filter(|e| GameEvent::is_key_hold(e) && e.key().map_or(false, |k| held.contains(k)))
-            .filter(|e| {
+        let store = &self.store;
+        let timers = &mut self.hold_timers;
+        let actions: Vec<_> = (0..store.objects.len())
+            .flat_map(|idx| {
+                let own = store.events.get(idx).into_iter().flatten().enumerate().map(|(slot, e)| (None, slot, e));
+                let state = store.objects[idx].state_machine.as_ref().into_iter().flat_map(|m| {
+                    m.active_events().iter().enumerate().map(move |(slot, e)| (Some(m.state()), slot, e))
+                });
+                own.chain(state).map(move |(state, slot, e)| (idx, state, slot, e))
+            })
+            .filter(|(.., e)| GameEvent::is_key_hold(e) && e.key().map_or(false, |k| held.contains(k)))
+            .filter(|(.., e)| {
                 if modifier_held { e.modifiers().is_some() } else { e.modifiers().is_none() }
             })
-            .map(|e| e.action().clone())
+            .filter(|(idx, state, slot, e)| match e {
+                GameEvent::ThrottledKeyHold { min_interval, .. } => {
+                    let key = (store.names[*idx].clone(), state.map(str::to_string), *slot);
+                    let elapsed = timers.entry(key).or_insert(f32::INFINITY);
+                    let ready = *elapsed >= *min_interval;
+                    if ready { *elapsed = 0.0; }
+                    ready
+                }
+                _ => true,
+            })
+            .map(|(idx, .., e)| (store.names[idx].clone(), e.action().clone()))
             .collect();
-        actions.into_iter().for_each(|a| self.run(a));
+        actions.into_iter().for_each(|(owner, a)| self.run_owned(owner, a));
     }
 
     pub fn on_mouse_press(
@@ -328,7 +402,7 @@ impl Canvas {
     pub(crate) fn process_mouse_press_events(&mut self, vpos: (f32, f32), pressed_btn: MouseButton) {
         let actions: Vec<_> = self.objects_under_cursor(vpos).into_iter()
             .flat_map(|idx| {
-                self.store.events.get(idx).into_iter().flatten()
+                self.store.events_of(idx)
                     .filter_map(|e| {
                         if let GameEvent::MousePress { action, button, .. } = e {
                             if button.map_or(true, |b| b == pressed_btn) {
@@ -336,16 +410,17 @@ impl Canvas {
                             } else { None }
                         } else { None }
                     })
+                    .map(|a| (self.store.names[idx].clone(), a))
                     .collect::<Vec<_>>()
             })
             .collect();
-        actions.into_iter().for_each(|a| self.run(a));
+        actions.into_iter().for_each(|(owner, a)| self.run_owned(owner, a));
     }
 
     pub(crate) fn process_mouse_release_events(&mut self, vpos: (f32, f32), released_btn: MouseButton) {
         let actions: Vec<_> = self.objects_under_cursor(vpos).into_iter()
             .flat_map(|idx| {
-                self.store.events.get(idx).into_iter().flatten()
+                self.store.events_of(idx)
                     .filter_map(|e| {
                         if let GameEvent::MouseRelease { action, button, .. } = e {
                             if button.map_or(true, |b| b == released_btn) {
@@ -353,42 +428,45 @@ impl Canvas {
                             } else { None }
                         } else { None }
                     })
+                    .map(|a| (self.store.names[idx].clone(), a))
                     .collect::<Vec<_>>()
             })
             .collect();
-        actions.into_iter().for_each(|a| self.run(a));
+        actions.into_iter().for_each(|(owner, a)| self.run_owned(owner, a));
     }
 
     pub(crate) fn process_mouse_move_events(&mut self, vpos: (f32, f32)) {
         let actions: Vec<_> = self.objects_under_cursor(vpos).into_iter()
             .flat_map(|idx| {
-                self.store.events.get(idx).into_iter().flatten()
+                self.store.events_of(idx)
                     .filter_map(|e| {
                         if let GameEvent::MouseMove { action, .. } = e { Some(action.clone()) } else { None }
                     })
+                    .map(|a| (self.store.names[idx].clone(), a))
                     .collect::<Vec<_>>()
             })
             .collect();
-        actions.into_iter().for_each(|a| self.run(a));
+        actions.into_iter().for_each(|(owner, a)| self.run_owned(owner, a));
     }
 
     pub(crate) fn process_mouse_over_events(&mut self, vpos: (f32, f32)) {
         let actions: Vec<_> = self.objects_under_cursor(vpos).into_iter()
             .flat_map(|idx| {
-                self.store.events.get(idx).into_iter().flatten()
+                self.store.events_of(idx)
                     .filter_map(|e| {
                         if let GameEvent::MouseOver { action, .. } = e { Some(action.clone()) } else { None }
                     })
+                    .map(|a| (self.store.names[idx].clone(), a))
                     .collect::<Vec<_>>()
             })
             .collect();
-        actions.into_iter().for_each(|a| self.run(a));
+        actions.into_iter().for_each(|(owner, a)| self.run_owned(owner, a));
     }
 
     pub(crate) fn process_mouse_scroll_events(&mut self, vpos: (f32, f32), dx: f32, dy: f32) {
         let actions: Vec<_> = self.objects_under_cursor(vpos).into_iter()
             .flat_map(|idx| {
-                self.store.events.get(idx).into_iter().flatten()
+                self.store.events_of(idx)
                     .filter_map(|e| {
                         if let GameEvent::MouseScroll { action, axis, .. } = e {
                             let matches = match axis {
@@ -401,27 +479,57 @@ impl Canvas {
                             if matches { Some(action.clone()) } else { None }
                         } else { None }
                     })
+                    .map(|a| (self.store.names[idx].clone(), a))
                     .collect::<Vec<_>>()
             })
             .collect();
-        actions.into_iter().for_each(|a| self.run(a));
+        actions.into_iter().for_each(|(owner, a)| self.run_owned(owner, a));
     }
 
     pub(crate) fn trigger_mouse_enter_events(&mut self, idx: usize) {
-        let actions: Vec<_> = self.store.events.get(idx).into_iter().flatten()
+        let Some(owner) = self.store.names.get(idx).cloned() else { return; };
+        let actions: Vec<_> = self.store.events_of(idx)
             .filter_map(|e| {
                 if let GameEvent::MouseEnter { action, .. } = e { Some(action.clone()) } else { None }
             })
             .collect();
-        actions.into_iter().for_each(|a| self.run(a));
+        actions.into_iter().for_each(|a| self.run_owned(owner.clone(), a));
     }
 
     pub(crate) fn trigger_mouse_leave_events(&mut self, idx: usize) {
-        let actions: Vec<_> = self.store.events.get(idx).into_iter().flatten()
+        let Some(owner) = self.store.names.get(idx).cloned() else { return; };
+        let actions: Vec<_> = self.store.events_of(idx)
             .filter_map(|e| {
                 if let GameEvent::MouseLeave { action, .. } = e { Some(action.clone()) } else { None }
             })
             .collect();
-        actions.into_iter().for_each(|a| self.run(a));
+        actions.into_iter().for_each(|a| self.run_owned(owner.clone(), a));
     }
-}
\ No newline at end of file
+}
+
+#[cfg(test)]
+mod tests {
+    use prism::event::{Key, KeyboardState, NamedKey};
+
+    use crate::testing::{canvas, count, object, run};
+    use crate::types::{GameEvent, Target};
+
+    #[test]
+    fn throttled_holds_on_one_key_keep_their_own_rates() {
+        let mut cv = canvas();
+        cv.add_game_object("ship".into(), object("ship", (0.0, 0.0), (10.0, 10.0)));
+        for var in ["every", "fast", "slow"] { cv.set_var(var, 0); }
+        let space = Key::Named(NamedKey::Space);
+        let hold = |var| GameEvent::key_hold(space.clone(), count(var), Target::self_object());
+        cv.add_event(hold("every"), Target::name("ship"));
+        cv.add_event(hold("fast").with_min_interval(0.105), Target::name("ship"));
+        cv.add_event(hold("slow").with_min_interval(0.505), Target::name("ship"));
+
+        cv.inject_key(KeyboardState::Pressed, space.clone());
+        run(&mut cv, 60);
+        assert_eq!(cv.get_i32("every"), 60);
+        // Steps 1, 8, 15, .. 57 and steps 1, 32.
+        assert_eq!(cv.get_i32("fast"), 9);
+        assert_eq!(cv.get_i32("slow"), 2);
+    }
+}
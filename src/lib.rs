@@ -19,6 +19,8 @@ pub mod constraints;
 pub mod assets;
 pub mod timer;
 pub mod json_layout;
+#[cfg(test)]
+pub(crate) mod testing;
 
 pub use std::sync::Arc;
 
@@ -29,21 +31,21 @@ pub use prism::event::{Key, NamedKey};
 
 pub use types::{
     Action, Condition, GameEvent,
-    Target, Location, Anchor,
+    Target, Location, Anchor, Axis,
     CollisionMode, CollisionShape, collision_layers,
     GlowConfig, HighlightEffect,
     MouseButton, ScrollAxis,
     ConditionOps,
     GravityFalloff,    ScreenPin,};
 
-pub use canvas::{Canvas, CanvasMode, CanvasLayout};
+pub use canvas::{Canvas, CanvasMode, CanvasLayout, TickStats, TileSpec, RayHit, Contact, Edge, BoundaryBehavior, Recording, RecordedKey, BroadPhase};
 pub use canvas::helpers::{orbit_speed, escape_speed};
 
-pub use object::{GameObject, GameObjectBuilder};
+pub use object::{GameObject, GameObjectBuilder, ObjectEmitter, Patrol, StateMachine, Label, SquashStretch};
 
 pub use sprite::{
-    AnimatedSprite, RotationOptions, RotationDirection,
-    load_image, load_image_sized, load_animation,
+    AnimatedSprite, AnimationSet, PlaybackMode, PlaybackDirection, RotationOptions, RotationDirection,
+    load_image, load_image_sized, try_load_image, try_load_image_sized, load_animation,
     solid_circle, solid_ellipse, planet_image,
     planet_grayscale, with_tint,
     planet_atmosphere, glow_ring, tint_overlay,
@@ -60,6 +62,7 @@ pub use store::ObjectStore;
 pub use input::{
     InputState, Callback, MouseState, MouseCallback,
     MouseMoveCallback, MouseScrollCallback, CallbackStore, EventCallback,
+    CollisionResponseCallback,
 };
 pub use scroll::{ScrollConfig, ScrollState, ScrollView};
 
@@ -83,7 +86,7 @@ pub use constraints::{
     GrappleConstraint, GrappleCorrection, DistanceConstraint, SpringConstraint,
     SwingBias, solve_distance_constraint,
 };
-pub use assets::ImageCache;
+pub use assets::{ImageCache, AnimationCache, AnimationHandle};
 pub use entropy::Entropy;
 pub use lerp::Lerp;
 pub use file_watcher::{Shared, SourceSettings, FromSource};
@@ -102,21 +105,21 @@ pub mod prelude {
 
     pub use crate::types::{
         Action, Condition, GameEvent,
-        Target, Location, Anchor,
+        Target, Location, Anchor, Axis,
         CollisionMode, CollisionShape, collision_layers,
         GlowConfig, HighlightEffect,
         MouseButton, ScrollAxis,
         ConditionOps,
         GravityFalloff,        ScreenPin,    };
 
-    pub use crate::canvas::{Canvas, CanvasMode, CanvasLayout};
+    pub use crate::canvas::{Canvas, CanvasMode, CanvasLayout, TickStats, TileSpec, RayHit, Contact, Edge, BoundaryBehavior, Recording, RecordedKey, BroadPhase};
     pub use crate::canvas::helpers::{orbit_speed, escape_speed};
 
-    pub use crate::object::{GameObject, GameObjectBuilder};
+    pub use crate::object::{GameObject, GameObjectBuilder, ObjectEmitter, Patrol, StateMachine, Label, SquashStretch};
 
     pub use crate::sprite::{
-        AnimatedSprite, RotationOptions, RotationDirection,
-        load_image, load_image_sized, load_animation,
+        AnimatedSprite, AnimationSet, PlaybackMode, PlaybackDirection, RotationOptions, RotationDirection,
+        load_image, load_image_sized, try_load_image, try_load_image_sized, load_animation,
         solid_circle, solid_ellipse, planet_image,
         planet_grayscale, with_tint,
         planet_atmosphere, glow_ring, tint_overlay,
@@ -133,6 +136,7 @@ pub mod prelude {
     pub use crate::input::{
         InputState, Callback, MouseState, MouseCallback,
         MouseMoveCallback, MouseScrollCallback, CallbackStore, EventCallback,
+        CollisionResponseCallback,
     };
     pub use crate::scroll::{ScrollConfig, ScrollState, ScrollView};
 
@@ -157,7 +161,7 @@ pub mod prelude {
         SwingBias, solve_distance_constraint,
     };
 
-    pub use crate::assets::ImageCache;
+    pub use crate::assets::{ImageCache, AnimationCache, AnimationHandle};
     pub use crate::entropy::Entropy;
     pub use crate::lerp::Lerp;
     pub use crate::file_watcher::{Shared, SourceSettings, FromSource};
@@ -1,15 +1,17 @@
 use prism::drawable::Drawable;
 use prism::canvas::{Image, Color};
 use prism::Context;
-use crate::types::{CollisionMode, GlowConfig, GravityFalloff, HighlightEffect, collision_layers};
+use crate::types::{CollisionMode, GameEvent, GlowConfig, GravityFalloff, HighlightEffect, collision_layers};
 use crate::crystalline::PhysicsMaterial;
+use crate::sprite::{AnimatedSprite, AnimationSet};
 use std::cell::Cell;
 
-use super::GameObject;
+use super::{GameObject, Label, NineSlice, ObjectEmitter, Patrol, SquashStretch, StateMachine};
 
 pub struct GameObjectBuilder {
     pub(super) id:          String,
-    pub(super) image:       Option<Image>,
+    pub(super) image:       Option<Box<dyn Drawable>>,
+    pub(super) animation:   Option<AnimatedSprite>,
     pub(super) size:        (f32, f32),
     pub(super) position:    (f32, f32),
     pub(super) tags:        Vec<String>,
@@ -48,23 +50,100 @@ pub struct GameObjectBuilder {
     pub(super) align_to_slope_speed: f32,
     pub(super) ignore_zoom:          bool,
     pub(super) screen_pin:           Option<crate::types::ScreenPin>,
+    /// Set by `pin_to_window`, so it holds whichever pin comes after it.
+    pub(super) pin_to_window:        bool,
     pub(super) pivot:                (f32, f32),
+    pub(super) lifetime:             Option<f32>,
+    pub(super) fade_out:             bool,
+    pub(super) opacity:              f32,
+    pub(super) emitter:              Option<Box<ObjectEmitter>>,
+    pub(super) flip_x:               bool,
+    pub(super) auto_flip:            bool,
+    pub(super) auto_flip_deadzone:   f32,
+    pub(super) platform_friction:    f32,
+    pub(super) patrol:               Option<Patrol>,
+    pub(super) max_jumps:            u32,
+    pub(super) clamp_to_canvas:      bool,
+    pub(super) wrap:                 bool,
+    pub(super) state_machine:        Option<StateMachine>,
+    pub(super) acceleration:         (f32, f32),
+    pub(super) render_scale:         (f32, f32),
+    pub(super) gravity_enabled:      bool,
+    pub(super) nine_slice:           Option<NineSlice>,
+    pub(super) label:                Option<Label>,
+    pub(super) kinematic:            bool,
+    pub(super) angular_velocity:     f32,
+    pub(super) animations:           Option<AnimationSet>,
+    pub(super) squash_stretch:       Option<SquashStretch>,
+    pub(super) global_gravity:       bool,
+    pub(super) rigid:                bool,
+    pub(super) mass:                 f32,
+    pub(super) events:               Vec<GameEvent>,
 }
 
 impl GameObjectBuilder {
     pub fn layer(mut self, id: i32) -> Self { self.layer = id; self }
 
     pub fn image(mut self, image: Image) -> Self {
-        self.image = Some(image);
+        self.image = Some(Box::new(image));
+        self
+    }
+
+    /// Text instead of an image. See `Label`.
+    pub fn label(mut self, label: Label) -> Self {
+        self.image = None;
+        self.label = Some(label);
+        self
+    }
+
+    /// See `GameObject::with_nine_slice`.
+    pub fn nine_slice(mut self, image: Image, insets: (f32, f32, f32, f32)) -> Self {
+        self.image      = None;
+        self.nine_slice = Some(NineSlice::new(&image, insets));
+        self
+    }
+
+    /// Any drawable, e.g. a `Text` or `RoundedBox`.
+    pub fn drawable(mut self, drawable: impl Drawable + 'static) -> Self {
+        self.image = Some(Box::new(drawable));
+        self
+    }
+
+    pub fn animation(mut self, sprite: AnimatedSprite) -> Self {
+        self.animation = Some(sprite);
+        self
+    }
+
+    /// See `GameObject::with_animations`.
+    pub fn animations(mut self, mut set: AnimationSet) -> Self {
+        if let Some(sprite) = set.start() { self.animation = Some(sprite); }
+        self.animations = Some(set);
         self
     }
 
     pub fn size(mut self, w: f32, h: f32)     -> Self { self.size = (w, h); self }
     pub fn position(mut self, x: f32, y: f32) -> Self { self.position = (x, y); self }
     pub fn tag(mut self, tag: impl Into<String>) -> Self { self.tags.push(tag.into()); self }
-    pub fn momentum(mut self, x: f32, y: f32)   -> Self { self.momentum = (x, y); self }
+    #[deprecated(note = "use velocity")]
+    pub fn momentum(self, x: f32, y: f32)       -> Self { self.velocity(x, y) }
+    /// Starting `GameObject::momentum`, in px/step.
+    pub fn velocity(mut self, x: f32, y: f32)   -> Self { self.momentum = (x, y); self }
     pub fn resistance(mut self, x: f32, y: f32) -> Self { self.resistance = (x, y); self }
+    /// See `GameObject::acceleration`.
+    pub fn acceleration(mut self, x: f32, y: f32) -> Self { self.acceleration = (x, y); self }
     pub fn gravity(mut self, g: f32)             -> Self { self.gravity = g; self }
+    /// See `GameObject::gravity_enabled`.
+    pub fn gravity_enabled(mut self, enabled: bool) -> Self { self.gravity_enabled = enabled; self }
+    /// See `GameObject::global_gravity`. Overrides `gravity`.
+    pub fn global_gravity(mut self) -> Self { self.global_gravity = true; self }
+    /// See `GameObject::rigid`.
+    pub fn rigid(mut self) -> Self { self.rigid = true; self }
+    /// See `GameObject::mass`.
+    pub fn mass(mut self, mass: f32) -> Self { self.mass = mass.max(0.001); self }
+    /// See `GameObject::kinematic`.
+    pub fn kinematic(mut self, kinematic: bool) -> Self { self.kinematic = kinematic; self }
+    /// See `GameObject::render_scale`.
+    pub fn render_scale(mut self, x: f32, y: f32) -> Self { self.render_scale = (x, y); self }
 
     pub fn platform(mut self) -> Self {
         self.is_platform    = true;
@@ -95,6 +174,10 @@ impl GameObjectBuilder {
     }
 
     pub fn rotation(mut self, degrees: f32) -> Self { self.rotation = degrees; self }
+    /// See `GameObject::angular_velocity`.
+    pub fn spin(mut self, degrees_per_second: f32) -> Self { self.angular_velocity = degrees_per_second; self }
+    /// See `GameObject::with_squash_stretch`.
+    pub fn squash_stretch(mut self, squash: SquashStretch) -> Self { self.squash_stretch = Some(squash); self }
     pub fn slope(mut self, left_offset: f32, right_offset: f32) -> Self {
         self.slope = Some((left_offset, right_offset)); self
     }
@@ -107,9 +190,37 @@ impl GameObjectBuilder {
     }
     pub fn one_way(mut self) -> Self { self.one_way = true; self }
     pub fn surface_velocity(mut self, vx: f32) -> Self { self.surface_velocity = Some(vx); self }
+    /// Jumps allowed before landing; 2 = double jump. See `Action::Jump`.
+    pub fn max_jumps(mut self, jumps: u32) -> Self { self.max_jumps = jumps; self }
+    /// Keeps the object fully on the canvas every step. See `Action::ClampToBounds`.
+    pub fn clamp_to_canvas(mut self) -> Self { self.clamp_to_canvas = true; self }
+    /// Exits through one canvas edge re-enter through the opposite one,
+    /// instead of firing boundary events.
+    pub fn wrap(mut self) -> Self { self.wrap = true; self }
+    /// Moves between `points` at `speed` px/step, looping or back and
+    /// forth. See `GameObject::with_patrol`.
+    pub fn patrol(mut self, points: Vec<(f32, f32)>, speed: f32, looping: bool) -> Self {
+        self.patrol = Some(Patrol::new(points, speed, looping)); self
+    }
+    /// Attaches named behaviour states. See `StateMachine`.
+    pub fn state_machine(mut self, machine: StateMachine) -> Self {
+        self.state_machine = Some(machine); self
+    }
+    /// See `GameObject::events`.
+    pub fn event(mut self, event: GameEvent) -> Self {
+        self.events.push(event); self
+    }
+    /// Grip for objects standing on this platform; see `GameObject::platform_friction`.
+    /// Separate from `friction`, which is the crystalline material's.
+    pub fn platform_friction(mut self, friction: f32) -> Self {
+        self.platform_friction = friction.clamp(0.0, 1.0); self
+    }
     pub fn rotation_resistance(mut self, resistance: f32) -> Self {
         self.rotation_resistance = resistance.clamp(0.0, 1.0); self
     }
+    /// Solid from all four sides: overlapping objects are pushed out along
+    /// the axis of least overlap and lose their momentum into the face.
+    /// `platform()` and the other surface builders only block one side.
     pub fn solid(mut self) -> Self {
         self.collision_mode = CollisionMode::solid();
         self.is_platform = true;
@@ -213,6 +324,7 @@ impl GameObjectBuilder {
         self.screen_pin = Some(crate::types::ScreenPin {
             anchor: (anchor_x, anchor_y),
             offset: (0.0, 0.0),
+            window: self.pin_to_window,
         });
         self.ignore_zoom = true;
         self
@@ -223,6 +335,15 @@ impl GameObjectBuilder {
         self
     }
 
+    /// Anchors the pin to the window edges rather than the virtual canvas,
+    /// so it hugs the real screen corner even when letterboxed. Works before
+    /// or after the `pin_*` call.
+    pub fn pin_to_window(mut self) -> Self {
+        self.pin_to_window = true;
+        if let Some(ref mut p) = self.screen_pin { p.window = true; }
+        self
+    }
+
     pub fn pin_top_left(self, ox: f32, oy: f32) -> Self { self.pin(0.0, 0.0).pin_offset(ox, oy) }
     pub fn pin_top_right(self, ox: f32, oy: f32) -> Self { self.pin(1.0, 0.0).pin_offset(ox, oy) }
     pub fn pin_top_center(self, oy: f32) -> Self { self.pin(0.5, 0.0).pin_offset(0.0, oy) }
@@ -236,6 +357,18 @@ impl GameObjectBuilder {
 
     pub fn pivot(mut self, px: f32, py: f32) -> Self { self.pivot = (px, py); self }
 
+    pub fn lifetime(mut self, seconds: f32) -> Self { self.lifetime = Some(seconds.max(0.0)); self }
+    pub fn fade_out(mut self) -> Self { self.fade_out = true; self }
+    pub fn opacity(mut self, opacity: f32) -> Self { self.opacity = opacity.clamp(0.0, 1.0); self }
+    pub fn emitter(mut self, emitter: ObjectEmitter) -> Self { self.emitter = Some(Box::new(emitter)); self }
+
+    pub fn flip_x(mut self) -> Self { self.flip_x = true; self }
+    /// Face the direction of horizontal movement; sprites are assumed to be
+    /// drawn facing right.
+    pub fn auto_flip(mut self) -> Self { self.auto_flip = true; self }
+    /// Ignore |momentum.0| up to `deadzone` so residual drift doesn't flicker.
+    pub fn auto_flip_deadzone(mut self, deadzone: f32) -> Self { self.auto_flip_deadzone = deadzone.max(0.0); self }
+
     pub fn gravity_well(mut self, radius: f32, strength: f32) -> Self {
         self.planet_radius    = Some(radius.max(0.0));
         self.gravity_strength = strength.max(0.0);
@@ -269,6 +402,7 @@ impl GameObjectBuilder {
         self.collision_layer(collision_layers::NONE).collision_mask(collision_layers::NONE)
     }
 
+    #[deprecated(note = "use finish(); ctx is unused")]
     pub fn build(self, _ctx: &mut Context) -> GameObject { self.finish() }
 
     pub fn finish(self) -> GameObject {
@@ -278,15 +412,16 @@ impl GameObjectBuilder {
             layout:              prism::layout::Stack::default(),
             id:                  self.id,
             tags:                self.tags,
-            drawable:            self.image.map(|img| Box::new(img) as Box<dyn Drawable>),
-            animated_sprite:     None,
+            drawable:            self.image,
+            animated_sprite:     self.animation,
             size,
             position:            self.position,
+            previous_position:   self.position,
             momentum:            self.momentum,
             resistance:          self.resistance,
             gravity:             self.gravity,
             scaled_size:         Cell::new(size),
-            render_scale:        Cell::new(1.0),
+            canvas_scale:        Cell::new(1.0),
             is_platform:         self.is_platform,
             visible:             true,
             layer:               self.layer,
@@ -325,7 +460,39 @@ impl GameObjectBuilder {
             ignore_zoom:         self.ignore_zoom,
             screen_pin:          self.screen_pin,
             pivot:               self.pivot,
+            lifetime:            self.lifetime,
+            lifetime_total:      self.lifetime.unwrap_or(0.0),
+            fade_out:            self.fade_out,
+            opacity:             self.opacity,
+            color_base:          None,
+            emitter:             self.emitter,
+            flip_x:              false,
+            auto_flip:           self.auto_flip,
+            auto_flip_deadzone:  self.auto_flip_deadzone,
+            platform_friction:   self.platform_friction,
+            patrol:              self.patrol,
+            max_jumps:           self.max_jumps,
+            jumps_remaining:     0,
+            clamp_to_canvas:     self.clamp_to_canvas,
+            wrap:                self.wrap,
+            state_machine:       self.state_machine,
+            acceleration:        self.acceleration,
+            render_scale:        self.render_scale,
+            gravity_enabled:     self.gravity_enabled,
+            nine_slice:          self.nine_slice,
+            label:               self.label,
+            kinematic:           self.kinematic,
+            angular_velocity:    self.angular_velocity,
+            animations:          self.animations,
+            squash_stretch:      self.squash_stretch,
+            global_gravity:      self.global_gravity,
+            blink_hidden:        false,
+            rigid:               self.rigid,
+            mass:                self.mass,
+            events:              self.events,
         };
+        obj.set_flip_x(self.flip_x);
+        obj.update_label();
         if let Some(effect) = highlight { obj.set_highlight(effect); }
         obj
     }
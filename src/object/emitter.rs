@@ -0,0 +1,77 @@
+use super::GameObject;
+
+/// Spawns short-lived particle `GameObject`s from a host object.
+///
+/// Unlike the crystalline `Emitter`, every particle is a regular object (sprite,
+/// tags, events) with a lifetime, so it removes itself once that runs out.
+/// Attach one with `GameObject::with_emitter` for continuous trails, or fire a
+/// one-shot burst with `Action::EmitBurst`.
+///
+/// For an emitter fixed at a world position, attach it to an invisible,
+/// zero-size host object placed there.
+#[derive(Clone, Debug)]
+pub struct ObjectEmitter {
+    /// Template cloned for every particle.
+    pub particle:  Box<GameObject>,
+    /// Particles per second. 0.0 = bursts only.
+    pub rate:      f32,
+    /// Min/max particle speed in pixels per frame.
+    pub speed:     (f32, f32),
+    /// Centre of the emission cone in degrees. 0 = right, 90 = down.
+    pub direction: f32,
+    /// Full width of the emission cone in degrees. 360 = all directions.
+    pub spread:    f32,
+    /// Min/max particle lifetime in seconds.
+    pub lifetime:  (f32, f32),
+    /// Fade particle opacity from 1.0 to 0.0 over its lifetime.
+    pub fade_out:  bool,
+    /// Offset of the emission point from the host's centre.
+    pub offset:    (f32, f32),
+    pub(crate) accumulator: f32,
+}
+
+impl ObjectEmitter {
+    pub fn new(particle: GameObject) -> Self {
+        Self {
+            particle:    Box::new(particle),
+            rate:        30.0,
+            speed:       (2.0, 4.0),
+            direction:   -90.0,
+            spread:      30.0,
+            lifetime:    (0.5, 1.0),
+            fade_out:    true,
+            offset:      (0.0, 0.0),
+            accumulator: 0.0,
+        }
+    }
+
+    pub fn rate(mut self, per_second: f32)     -> Self { self.rate = per_second.max(0.0); self }
+    pub fn speed(mut self, min: f32, max: f32) -> Self { self.speed = (min, max.max(min)); self }
+    pub fn direction(mut self, degrees: f32)   -> Self { self.direction = degrees; self }
+    pub fn spread(mut self, degrees: f32)      -> Self { self.spread = degrees.clamp(0.0, 360.0); self }
+    pub fn lifetime(mut self, min: f32, max: f32) -> Self {
+        self.lifetime = (min.max(0.0), max.max(min.max(0.0)));
+        self
+    }
+    pub fn fade_out(mut self, fade: bool)      -> Self { self.fade_out = fade; self }
+    pub fn offset(mut self, x: f32, y: f32)    -> Self { self.offset = (x, y); self }
+
+    /// Emits in every direction — typical for explosions.
+    pub fn radial(self) -> Self { self.spread(360.0) }
+
+    /// Builds one particle centred on `origin`. `rolls` are three values in
+    /// 0..1 used for angle, speed and lifetime.
+    pub(crate) fn make_particle(&self, origin: (f32, f32), rolls: (f32, f32, f32)) -> GameObject {
+        let angle = (self.direction + (rolls.0 - 0.5) * self.spread).to_radians();
+        let speed = self.speed.0 + (self.speed.1 - self.speed.0) * rolls.1;
+        let life  = self.lifetime.0 + (self.lifetime.1 - self.lifetime.0) * rolls.2;
+
+        let mut p = (*self.particle).clone();
+        p.set_center(origin.0 + self.offset.0, origin.1 + self.offset.1);
+        p.momentum = (angle.cos() * speed, angle.sin() * speed);
+        p.set_lifetime(life);
+        p.fade_out = self.fade_out;
+        p.emitter  = None;
+        p
+    }
+}
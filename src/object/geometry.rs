@@ -3,26 +3,74 @@ use super::GameObject;
 
 impl GameObject {
     pub fn check_boundary_collision(&self, canvas_size: (f32, f32)) -> bool {
+        let (min_x, min_y, max_x, max_y) = self.extent();
+        min_x <= 0.0 || max_x >= canvas_size.0 || min_y <= 0.0 || max_y >= canvas_size.1
+    }
+
+    /// `(min_x, min_y, max_x, max_y)` of the object's box, rotated ones by
+    /// their corners so the visual extent counts.
+    pub(crate) fn extent(&self) -> (f32, f32, f32, f32) {
         if self.rotation == 0.0 {
-            return self.position.0 <= 0.0
-                || self.position.0 + self.size.0 >= canvas_size.0
-                || self.position.1 <= 0.0
-                || self.position.1 + self.size.1 >= canvas_size.1;
+            return (self.position.0, self.position.1, self.position.0 + self.size.0, self.position.1 + self.size.1);
         }
-        // Use rotated AABB for rotating objects so the visual extent triggers events.
         let corners = self.corners_world();
-        let min_x = corners.iter().map(|c| c.0).fold(f32::MAX, |a, b| a.min(b));
-        let max_x = corners.iter().map(|c| c.0).fold(f32::MIN, |a, b| a.max(b));
-        let min_y = corners.iter().map(|c| c.1).fold(f32::MAX, |a, b| a.min(b));
-        let max_y = corners.iter().map(|c| c.1).fold(f32::MIN, |a, b| a.max(b));
-        min_x <= 0.0 || max_x >= canvas_size.0 || min_y <= 0.0 || max_y >= canvas_size.1
+        (
+            corners.iter().map(|c| c.0).fold(f32::MAX, f32::min),
+            corners.iter().map(|c| c.1).fold(f32::MAX, f32::min),
+            corners.iter().map(|c| c.0).fold(f32::MIN, f32::max),
+            corners.iter().map(|c| c.1).fold(f32::MIN, f32::max),
+        )
+    }
+
+    /// Shifts the object so its (rotated) AABB lies within `(0, 0)..bounds`,
+    /// zeroing momentum on each axis it was pushed back along. An object
+    /// larger than the bounds is aligned to the top-left edge.
+    pub fn clamp_within(&mut self, bounds: (f32, f32)) {
+        let (min_x, min_y, max_x, max_y) = self.extent();
+        let shift = |min: f32, max: f32, limit: f32| {
+            if min < 0.0 { -min } else if max > limit { (limit - max).max(-min) } else { 0.0 }
+        };
+        let dx = shift(min_x, max_x, bounds.0);
+        let dy = shift(min_y, max_y, bounds.1);
+        if dx != 0.0 { self.position.0 += dx; self.momentum.0 = 0.0; }
+        if dy != 0.0 { self.position.1 += dy; self.momentum.1 = 0.0; }
+    }
+
+    /// Once the object is entirely past one edge of `(0, 0)..bounds`, moves
+    /// it by `bounds + size` so it re-enters from the opposite edge. Works for
+    /// objects larger than the bounds too. Returns true if it wrapped.
+    pub fn wrap_within(&mut self, bounds: (f32, f32)) -> bool {
+        let wrap_axis = |pos: f32, size: f32, limit: f32| {
+            let period = limit + size;
+            if pos + size < 0.0 { period } else if pos > limit { -period } else { 0.0 }
+        };
+        let dx = wrap_axis(self.position.0, self.size.0, bounds.0);
+        let dy = wrap_axis(self.position.1, self.size.1, bounds.1);
+        if dx == 0.0 && dy == 0.0 { return false; }
+        // Shift the interpolation origin too, so the render doesn't sweep
+        // across the screen.
+        self.position.0          += dx;
+        self.position.1          += dy;
+        self.previous_position.0 += dx;
+        self.previous_position.1 += dy;
+        true
     }
 
     pub fn get_anchor_position(&self, anchor: Anchor) -> (f32, f32) {
-        (
-            self.position.0 + self.size.0 * anchor.x,
-            self.position.1 + self.size.1 * anchor.y,
-        )
+        let (ox, oy) = self.anchor_offset(anchor);
+        (self.position.0 + ox, self.position.1 + oy)
+    }
+
+    /// Offset from the object's top-left corner to `anchor`.
+    pub fn anchor_offset(&self, anchor: Anchor) -> (f32, f32) {
+        (self.size.0 * anchor.x, self.size.1 * anchor.y)
+    }
+
+    /// Moves the object so its `anchor` point lands on `point`,
+    /// e.g. `Anchor::CENTER` positions by centre instead of top-left.
+    pub fn set_anchor_position(&mut self, anchor: Anchor, point: (f32, f32)) {
+        let (ox, oy) = self.anchor_offset(anchor);
+        self.position = (point.0 - ox, point.1 - oy);
     }
 
     pub fn contains_point(&self, point: (f32, f32)) -> bool {
@@ -40,6 +88,13 @@ impl GameObject {
         if self.is_platform { self.sync_rotation_normal(); }
     }
 
+    /// Advances rotation by `angular_velocity * delta_time`, kept in 0..360.
+    pub fn apply_spin(&mut self, delta_time: f32) {
+        if self.angular_velocity == 0.0 { return; }
+        self.rotation = (self.rotation + self.angular_velocity * delta_time).rem_euclid(360.0);
+        if self.is_platform { self.sync_rotation_normal(); }
+    }
+
     pub fn sync_rotation_normal(&mut self) {
         let theta = self.rotation.to_radians();
         self.surface_normal = (theta.sin(), -theta.cos());
@@ -0,0 +1,64 @@
+use std::sync::Arc;
+
+use prism::canvas::{Align, Color, Font, Span, Text};
+
+/// Text shown by a `GameObject`, kept as a string plus style so the engine
+/// can re-lay it out whenever the string or the canvas scale changes: the
+/// font is `font_size` virtual pixels at any window size or zoom, like a
+/// sprite. For scores, dialog and labels; update it with `Action::SetLabel`.
+///
+/// Attach with `GameObject::with_label` or the builder's `label`.
+#[derive(Clone)]
+pub struct Label {
+    pub content:   String,
+    /// In virtual pixels; scaled with the canvas like `size`.
+    pub font_size: f32,
+    pub color:     Color,
+    pub align:     Align,
+    pub font:      Arc<Font>,
+    /// (content, pixel size) the drawable was last built from. Style
+    /// changes need `GameObject::set_label` to show.
+    pub(crate) built: Option<(String, f32)>,
+}
+
+impl Label {
+    pub fn new(content: impl Into<String>, font_size: f32, font: Arc<Font>) -> Self {
+        Self {
+            content: content.into(),
+            font_size,
+            color: Color(255, 255, 255, 255),
+            align: Align::Left,
+            font,
+            built: None,
+        }
+    }
+
+    pub fn color(mut self, color: Color) -> Self { self.color = color; self }
+    pub fn align(mut self, align: Align) -> Self { self.align = align; self }
+
+    /// A `Text` for the current content at `scale`, or None if the last
+    /// one built is still current.
+    pub(crate) fn rebuild(&mut self, scale: f32) -> Option<Text> {
+        let px = self.font_size * scale;
+        if self.built.as_ref().is_some_and(|(content, size)| *content == self.content && *size == px) {
+            return None;
+        }
+        self.built = Some((self.content.clone(), px));
+        Some(Text::new(
+            vec![Span::new(self.content.clone(), px, Some(px * 1.35), self.font.clone(), self.color, 0.0)],
+            None,
+            self.align.clone(),
+            None,
+        ))
+    }
+}
+
+impl std::fmt::Debug for Label {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("Label")
+            .field("content",   &self.content)
+            .field("font_size", &self.font_size)
+            .field("color",     &self.color)
+            .finish()
+    }
+}
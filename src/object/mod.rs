@@ -1,15 +1,27 @@
 mod builder;
 mod geometry;
+mod emitter;
+mod patrol;
+mod state_machine;
+mod nine_slice;
+mod label;
+mod squash_stretch;
 
 pub use builder::GameObjectBuilder;
+pub use emitter::ObjectEmitter;
+pub use patrol::Patrol;
+pub use state_machine::StateMachine;
+pub use label::Label;
+pub use squash_stretch::SquashStretch;
+pub(crate) use nine_slice::NineSlice;
 
 use prism::event::{OnEvent, Event};
 use prism::drawable::{Drawable, SizedTree, RequestTree, Offset, Rect, Size};
 use prism::layout::{SizeRequest, Area};
 use prism::Context;
 use prism::canvas::{Image, ShapeType, Color};
-use crate::sprite::AnimatedSprite;
-use crate::types::{CollisionMode, GlowConfig, GravityFalloff, HighlightEffect};
+use crate::sprite::{AnimatedSprite, AnimationSet};
+use crate::types::{CollisionMode, GameEvent, GlowConfig, GravityFalloff, HighlightEffect};
 use crate::crystalline::PhysicsMaterial;
 use wgpu_canvas::{Area as CanvasArea, Item as CanvasItem};
 use std::cell::Cell;
@@ -23,11 +35,18 @@ pub struct GameObject {
     pub animated_sprite: Option<AnimatedSprite>,
     pub size:            (f32, f32),
     pub position:        (f32, f32),
+    /// Position at the start of the current fixed step, used to interpolate rendering.
+    pub previous_position: (f32, f32),
+    /// Velocity in px per fixed step, added to `position` each step. The
+    /// old name for it: read and write it with `velocity()`/`set_velocity`.
     pub momentum:        (f32, f32),
     pub resistance:      (f32, f32),
+    /// Downward acceleration in px/step gained every 1/60 s, integrated with
+    /// `acceleration`. At 60 Hz that's `gravity` per step, as it always was.
     pub gravity:         f32,
     pub scaled_size:     Cell<(f32, f32)>,
-    pub render_scale:    Cell<f32>,
+    /// Canvas (zoom) scale the object was last laid out at.
+    pub canvas_scale:    Cell<f32>,
     pub is_platform:     bool,
     pub visible:         bool,
     pub layer:           i32,
@@ -42,6 +61,8 @@ pub struct GameObject {
     pub highlight:           Option<HighlightEffect>,
     pub(crate) glow_drawable:    Option<Box<dyn Drawable>>,
     pub(crate) tint_drawable:    Option<Box<dyn Drawable>>,
+    /// Set while resting on a platform's top face; cleared at the start of
+    /// every physics step and re-set by collision resolution.
     pub grounded:            bool,
     pub material:            PhysicsMaterial,
     pub collision_layer:     u32,
@@ -66,6 +87,84 @@ pub struct GameObject {
     pub ignore_zoom:         bool,
     pub screen_pin:          Option<crate::types::ScreenPin>,
     pub pivot:               (f32, f32),
+    /// Seconds left before the object removes itself. None = lives forever.
+    pub lifetime:            Option<f32>,
+    pub(crate) lifetime_total: f32,
+    /// Fade opacity to zero as `lifetime` runs out.
+    pub fade_out:            bool,
+    /// 0.0–1.0 multiplier applied to the drawable's alpha.
+    pub opacity:             f32,
+    /// The drawable's own colour, captured before tint/opacity are written into it.
+    pub(crate) color_base:   Option<Color>,
+    pub emitter:             Option<Box<ObjectEmitter>>,
+    /// Drawn mirrored horizontally. Private so the image can't fall out of
+    /// step with it: read with `flip_x()`, change with `set_flip_x`.
+    flip_x:                  bool,
+    /// Face the direction of horizontal movement each tick.
+    pub auto_flip:           bool,
+    /// |momentum.0| at or below this leaves the facing unchanged.
+    pub auto_flip_deadzone:  f32,
+    /// Platforms only: fraction (0–1) of a rider's horizontal momentum
+    /// removed each step it stands here. Applied before `resistance`.
+    pub platform_friction:   f32,
+    /// Waypoint movement, re-aimed every step. See `with_patrol`.
+    pub patrol:              Option<Patrol>,
+    /// Jumps allowed before landing again, counting the one off the ground.
+    pub max_jumps:           u32,
+    /// Refilled to `max_jumps` whenever grounded; spent by `Action::Jump`.
+    pub jumps_remaining:     u32,
+    /// Kept fully inside the canvas (or camera world) every step.
+    pub clamp_to_canvas:     bool,
+    /// Leaving one canvas edge re-enters from the opposite one.
+    pub wrap:                bool,
+    /// Named behaviour states; only the active one's events fire.
+    pub state_machine:       Option<StateMachine>,
+    /// Added to `momentum` every step along with `gravity`, scaled by the
+    /// step's `dt`: momentum units (px/step) per second. The speed gained
+    /// per second is the same at any tick rate.
+    pub acceleration:        (f32, f32),
+    /// Multiplies only the drawn rectangle, centred on the object: the
+    /// sprite is drawn at `size * render_scale * canvas_scale` while
+    /// collision, queries and anchors keep using `size`.
+    pub render_scale:        (f32, f32),
+    /// When false, neither `gravity` nor planet gravity pulls the object;
+    /// `gravity` keeps its value for when it's switched back on.
+    pub gravity_enabled:     bool,
+    /// Drawn instead of `drawable` when set. See `with_nine_slice`.
+    pub(crate) nine_slice:   Option<NineSlice>,
+    /// Text content and style, rebuilt into `drawable` when it or the
+    /// canvas scale changes. See `Label`.
+    pub label:               Option<Label>,
+    /// Never moved by physics: gravity, acceleration, momentum, resistance
+    /// and platform push-out all skip it, while it still collides and fires
+    /// events. Teleport, MoveTo and other explicit moves still work; its
+    /// `momentum` is kept for when it's switched off.
+    pub kinematic:           bool,
+    /// Continuous spin in degrees per second, added to `rotation` every
+    /// step (dt-scaled, no damping). Collision of non-platform objects
+    /// stays axis-aligned, so spin is visual unless the object is a platform.
+    pub angular_velocity:    f32,
+    /// Named animations `Action::PlayAnimation` switches between.
+    pub animations:          Option<AnimationSet>,
+    /// Momentum-driven squash and stretch of the visual. See `SquashStretch`.
+    pub squash_stretch:      Option<SquashStretch>,
+    /// Follows the canvas's world gravity (`Canvas::set_global_gravity`)
+    /// instead of its own: the canvas keeps `gravity` at the world value.
+    /// `Action::SetGravity` gives the object its own value again.
+    pub global_gravity:      bool,
+    /// Non-platform objects that are both `rigid` push each other apart
+    /// and exchange momentum when they overlap, instead of passing through.
+    /// How much bounce survives comes from `material.elasticity`.
+    pub rigid:               bool,
+    /// Weight in `rigid` collisions; heavier objects are pushed less.
+    pub mass:                f32,
+    /// Events the object brings with it: moved into the canvas's event
+    /// list when it's added, so every spawned copy of a template gets its
+    /// own. Empty once the object is on a canvas; use `Canvas::add_event`
+    /// from then on.
+    pub events:              Vec<GameEvent>,
+    /// Skipped by `draw` for the off phases of `Canvas::blink`.
+    pub(crate) blink_hidden: bool,
 }
 
 impl OnEvent for GameObject {}
@@ -74,6 +173,8 @@ impl GameObject {
     fn active_children(&self) -> Vec<&dyn Drawable> {
         if !self.visible { return vec![]; }
         let mut v: Vec<&dyn Drawable> = Vec::new();
+        // Pieces first, so `build` can place them by index.
+        if let Some(n) = &self.nine_slice    { v.extend(n.pieces().iter().map(|p| p as &dyn Drawable)); }
         if let Some(d) = &self.drawable      { v.push(d.as_ref()); }
         if let Some(g) = &self.glow_drawable  { v.push(g.as_ref()); }
         if let Some(t) = &self.tint_drawable  { v.push(t.as_ref()); }
@@ -83,6 +184,7 @@ impl GameObject {
     fn active_children_mut(&mut self) -> Vec<&mut dyn Drawable> {
         if !self.visible { return vec![]; }
         let mut v: Vec<&mut dyn Drawable> = Vec::new();
+        if let Some(n) = &mut self.nine_slice    { v.extend(n.pieces_mut().iter_mut().map(|p| p as &mut dyn Drawable)); }
         if let Some(d) = &mut self.drawable      { v.push(d.as_mut()); }
         if let Some(g) = &mut self.glow_drawable  { v.push(g.as_mut()); }
         if let Some(t) = &mut self.tint_drawable  { v.push(t.as_mut()); }
@@ -103,7 +205,7 @@ impl GameObject {
     }
 
     fn clip_rect(&self, poffset: Offset) -> Rect {
-        let s = self.render_scale.get();
+        let s = self.canvas_scale.get();
         let (cx, cy) = self._origin
             .map(|(x, y)| (x * s, y * s))
             .unwrap_or(poffset);
@@ -126,14 +228,29 @@ impl Drawable for GameObject {
     fn build(&self, size: Size, request: &RequestTree) -> SizedTree {
         let own_size   = request.0.get(size);
         let child_size = self.size;
+        // Keep a render-scaled visual centred on the unscaled box; with
+        // squash and stretch it sits on the box's bottom edge instead, so
+        // a landing squash stays on the ground.
+        let s = self.canvas_scale.get();
+        let drawn = self.scaled_size.get();
+        let slack = (self.size.0 * s - drawn.0, self.size.1 * s - drawn.1);
+        let inset = match self.squash_stretch {
+            Some(_) => (slack.0 * 0.5, slack.1),
+            None    => (slack.0 * 0.5, slack.1 * 0.5),
+        };
+        let slices = self.nine_slice.as_ref().map(|n| n.layout(drawn, s));
         SizedTree(
             own_size,
             self.active_children()
                 .into_iter()
                 .zip(request.1.clone())
-                .map(|(child, branch)| {
+                .enumerate()
+                .map(|(i, (child, branch))| {
                     let built = child.build(child_size, &branch);
-                    ((0.0_f32, 0.0_f32), built)
+                    match slices.as_ref().and_then(|l| l.get(i)) {
+                        Some(((x, y), _)) => ((inset.0 + x, inset.1 + y), built),
+                        None              => (inset, built),
+                    }
                 })
                 .collect(),
         )
@@ -145,7 +262,7 @@ impl Drawable for GameObject {
         poffset: Offset,
         bound:   Rect,
     ) -> Vec<(CanvasArea, CanvasItem)> {
-        if !self.visible { return vec![]; }
+        if !self.visible || self.blink_hidden { return vec![]; }
 
         let bound = if self.ped {
             let cr = self.clip_rect(poffset);
@@ -188,7 +305,7 @@ impl Drawable for GameObject {
 impl GameObject {
     pub fn build(id: impl Into<String>) -> GameObjectBuilder {
         GameObjectBuilder {
-            id: id.into(), image: None,
+            id: id.into(), image: None, animation: None,
             size: (100.0, 100.0), position: (0.0, 0.0), tags: vec![],
             momentum: (0.0, 0.0), resistance: (1.0, 1.0), gravity: 0.0,
             is_platform: false, layer: 0, rotation: 0.0, slope: None,
@@ -205,7 +322,30 @@ impl GameObject {
             align_to_slope: false, align_to_slope_speed: 8.0,
             ignore_zoom: false,
             screen_pin: None,
+            pin_to_window: false,
             pivot: (0.5, 0.5),
+            lifetime: None, fade_out: false, opacity: 1.0, emitter: None,
+            flip_x: false, auto_flip: false, auto_flip_deadzone: 0.1,
+            platform_friction: 0.0,
+            patrol: None,
+            max_jumps: 1,
+            clamp_to_canvas: false,
+            wrap: false,
+            state_machine: None,
+            acceleration: (0.0, 0.0),
+            render_scale: (1.0, 1.0),
+            gravity_enabled: true,
+            nine_slice: None,
+            label: None,
+            kinematic: false,
+            angular_velocity: 0.0,
+            animations: None,
+            squash_stretch: None,
+            global_gravity: false,
+            blink_hidden: false,
+            rigid: false,
+            mass: 1.0,
+            events: Vec::new(),
         }
     }
 
@@ -213,10 +353,10 @@ impl GameObject {
         Self {
             layout: prism::layout::Stack::default(),
             id: String::new(), tags: vec![], drawable: None, animated_sprite: None,
-            size, position: (0.0, 0.0), momentum: (0.0, 0.0),
+            size, position: (0.0, 0.0), previous_position: (0.0, 0.0), momentum: (0.0, 0.0),
             resistance: (1.0, 1.0), gravity: 0.0,
             scaled_size: Cell::new(size),
-            render_scale: Cell::new(1.0),
+            canvas_scale: Cell::new(1.0),
             is_platform: false, visible: true, layer: 0,
             rotation: 0.0, slope: None, one_way: false, surface_velocity: None,
             rotation_momentum: 0.0, rotation_resistance: 0.85,
@@ -234,28 +374,53 @@ impl GameObject {
             ignore_zoom: false,
             screen_pin: None,
             pivot: (0.5, 0.5),
+            lifetime: None, lifetime_total: 0.0, fade_out: false,
+            opacity: 1.0, color_base: None, emitter: None,
+            flip_x: false, auto_flip: false, auto_flip_deadzone: 0.1,
+            platform_friction: 0.0,
+            patrol: None,
+            max_jumps: 1, jumps_remaining: 0,
+            clamp_to_canvas: false,
+            wrap: false,
+            state_machine: None,
+            acceleration: (0.0, 0.0),
+            render_scale: (1.0, 1.0),
+            gravity_enabled: true,
+            nine_slice: None,
+            label: None,
+            kinematic: false,
+            angular_velocity: 0.0,
+            animations: None,
+            squash_stretch: None,
+            global_gravity: false,
+            blink_hidden: false,
+            rigid: false,
+            mass: 1.0,
+            events: Vec::new(),
         }
     }
 
+    #[deprecated(note = "use GameObject::build(id) ... .finish(); ctx is unused")]
     pub fn new(
         _ctx: &mut Context, id: String, drawable: Option<impl Drawable + 'static>,
         size: f32, position: (f32, f32), tags: Vec<String>,
         momentum: (f32, f32), resistance: (f32, f32), gravity: f32,
     ) -> Self {
         let mut s = Self::default_fields((size, size));
-        s.id = id; s.tags = tags; s.position = position;
+        s.id = id; s.tags = tags; s.position = position; s.previous_position = position;
         s.momentum = momentum; s.resistance = resistance; s.gravity = gravity;
         s.drawable = drawable.map(|d| Box::new(d) as Box<dyn Drawable>);
         s
     }
 
+    #[deprecated(note = "use GameObject::build(id) ... .finish(); ctx is unused")]
     pub fn new_rect(
         _ctx: &mut Context, id: String, drawable: Option<impl Drawable + 'static>,
         size: (f32, f32), position: (f32, f32), tags: Vec<String>,
         momentum: (f32, f32), resistance: (f32, f32), gravity: f32,
     ) -> Self {
         let mut s = Self::default_fields(size);
-        s.id = id; s.tags = tags; s.position = position;
+        s.id = id; s.tags = tags; s.position = position; s.previous_position = position;
         s.momentum = momentum; s.resistance = resistance; s.gravity = gravity;
         s.drawable = drawable.map(|d| Box::new(d) as Box<dyn Drawable>);
         s
@@ -266,8 +431,25 @@ impl GameObject {
         self
     }
 
+    /// Attaches named animations and starts the first one added.
+    pub fn with_animations(mut self, mut animations: AnimationSet) -> Self {
+        if let Some(sprite) = animations.start() { self.animated_sprite = Some(sprite); }
+        self.animations = Some(animations);
+        self
+    }
+
+    /// Shows the set's animation `name`; nothing if it's unknown or already
+    /// playing. See `AnimationSet`.
+    pub fn play_animation(&mut self, name: &str) {
+        let Some(set) = self.animations.as_mut() else { return; };
+        let Some(mut sprite) = set.switch(name, self.animated_sprite.as_ref()) else { return; };
+        sprite.set_mirrored(self.flip_x);
+        self.animated_sprite = Some(sprite);
+        self.color_base      = None;
+    }
+
     pub fn with_image(mut self, image: Image) -> Self {
-        self.drawable = Some(Box::new(image));
+        self.set_image(image);
         self
     }
 
@@ -275,9 +457,50 @@ impl GameObject {
     pub fn with_tag(mut self, tag: impl Into<String>)         -> Self { self.tags.push(tag.into()); self }
     pub fn with_tags(mut self, tags: Vec<String>)             -> Self { self.tags = tags; self }
     pub fn with_gravity(mut self, gravity: f32)               -> Self { self.gravity = gravity; self }
-    pub fn with_momentum(mut self, momentum: (f32, f32))      -> Self { self.momentum = momentum; self }
+    pub fn with_gravity_enabled(mut self, enabled: bool)      -> Self { self.gravity_enabled = enabled; self }
+    pub fn with_global_gravity(mut self)                      -> Self { self.global_gravity = true; self }
+    pub fn with_rigid(mut self)                               -> Self { self.rigid = true; self }
+    pub fn with_mass(mut self, mass: f32)                     -> Self { self.mass = mass.max(0.001); self }
+    pub fn with_kinematic(mut self, kinematic: bool)          -> Self { self.kinematic = kinematic; self }
+    pub fn with_spin(mut self, degrees_per_second: f32)       -> Self { self.angular_velocity = degrees_per_second; self }
+    pub fn with_squash_stretch(mut self, squash: SquashStretch) -> Self { self.squash_stretch = Some(squash); self }
+    #[deprecated(note = "use velocity")]
+    pub fn with_momentum(self, momentum: (f32, f32))          -> Self { self.with_velocity(momentum) }
+    pub fn with_velocity(mut self, velocity: (f32, f32))      -> Self { self.momentum = velocity; self }
     pub fn with_resistance(mut self, resistance: (f32, f32))  -> Self { self.resistance = resistance; self }
+    pub fn with_acceleration(mut self, acceleration: (f32, f32)) -> Self { self.acceleration = acceleration; self }
+    pub fn with_render_scale(mut self, render_scale: (f32, f32)) -> Self { self.render_scale = render_scale; self }
     pub fn clip(mut self)                                      -> Self { self.ped = true; self }
+    pub fn with_lifetime(mut self, seconds: f32)              -> Self { self.set_lifetime(seconds); self }
+    pub fn with_fade_out(mut self)                            -> Self { self.fade_out = true; self }
+    pub fn with_opacity(mut self, opacity: f32)               -> Self { self.set_opacity(opacity); self }
+    pub fn with_emitter(mut self, emitter: ObjectEmitter)     -> Self { self.emitter = Some(Box::new(emitter)); self }
+    pub fn with_tint(mut self, color: Color)                  -> Self { self.set_tint(color); self }
+    pub fn with_auto_flip(mut self)                           -> Self { self.auto_flip = true; self }
+    /// Jumps allowed before landing, e.g. 2 for a double jump. See `Action::Jump`.
+    pub fn with_max_jumps(mut self, jumps: u32)               -> Self { self.max_jumps = jumps; self }
+    /// Never leaves the canvas; see `Action::ClampToBounds`.
+    pub fn with_clamp_to_canvas(mut self)                     -> Self { self.clamp_to_canvas = true; self }
+    /// Asteroids-style edges; see `GameObject::wrap`.
+    pub fn with_wrap(mut self)                                -> Self { self.wrap = true; self }
+    /// Moves between `points` at `speed` px/step, in order. `looping` wraps
+    /// from the last point to the first; otherwise the path is walked back
+    /// and forth. Riders on a patrolling platform move with it.
+    pub fn with_patrol(mut self, points: Vec<(f32, f32)>, speed: f32, looping: bool) -> Self {
+        self.patrol = Some(Patrol::new(points, speed, looping));
+        self
+    }
+    /// Attaches named behaviour states; see `StateMachine`.
+    pub fn with_state_machine(mut self, machine: StateMachine) -> Self {
+        self.state_machine = Some(machine);
+        self
+    }
+
+    /// Carries `event` onto the canvas with the object; see `events`.
+    pub fn with_event(mut self, event: GameEvent) -> Self {
+        self.events.push(event);
+        self
+    }
 
     pub fn set_gravity(&mut self, gravity: f32) { self.gravity = gravity; }
 
@@ -285,21 +508,136 @@ impl GameObject {
         self.position = (cx - self.size.0 * 0.5, cy - self.size.1 * 0.5);
     }
 
+    /// Tags as registered. To change them on an added object use
+    /// `Canvas::add_tag`/`remove_tag`, which keep tag queries in sync.
+    pub fn tags(&self) -> &[String] { &self.tags }
+    pub fn has_tag(&self, tag: &str) -> bool { self.tags.iter().any(|t| t == tag) }
+
     pub fn center(&self) -> (f32, f32) {
         (self.position.0 + self.size.0 * 0.5, self.position.1 + self.size.1 * 0.5)
     }
 
-    pub fn set_animation(&mut self, animated_sprite: AnimatedSprite) {
+    /// Frame the animated sprite is showing; None without one.
+    pub fn animation_frame(&self) -> Option<usize> {
+        self.animated_sprite.as_ref().map(AnimatedSprite::current_frame)
+    }
+
+    pub fn set_animation(&mut self, mut animated_sprite: AnimatedSprite) {
+        if let Some(set) = self.animations.as_mut() { set.clear_current(); }
+        animated_sprite.set_mirrored(self.flip_x);
         self.animated_sprite = Some(animated_sprite);
+        self.color_base      = None;
+    }
+
+    /// Draws `image` stretched to the object's size with its border kept
+    /// intact: `insets` (left, top, right, bottom, in the image's pixels)
+    /// mark off the corners, which never stretch, and the edges, which
+    /// stretch along their length. For UI panels and dialog boxes. Replaces
+    /// the object's image; `set_image` switches back.
+    pub fn with_nine_slice(mut self, image: Image, insets: (f32, f32, f32, f32)) -> Self {
+        self.drawable   = None;
+        self.nine_slice = Some(NineSlice::new(&image, insets));
+        self.update_image_shape();
+        self
+    }
+
+    pub fn with_label(mut self, label: Label) -> Self {
+        self.set_label(label);
+        self
+    }
+
+    /// Shows `label` as the object's visual, replacing any image or text.
+    pub fn set_label(&mut self, mut label: Label) {
+        label.built     = None;
+        self.label      = Some(label);
+        self.nine_slice = None;
+        self.update_label();
+    }
+
+    /// Rebuilds the label's `Text` if its content or the canvas scale
+    /// changed since the last build.
+    pub(crate) fn update_label(&mut self) {
+        let scale = self.canvas_scale.get();
+        let Some(text) = self.label.as_mut().and_then(|l| l.rebuild(scale)) else { return; };
+        self.drawable = Some(Box::new(text));
     }
 
     pub fn set_image(&mut self, image: Image) {
-        self.drawable = Some(Box::new(image));
+        self.label      = None;
+        self.nine_slice = None;
+        self.drawable   = Some(Box::new(if self.flip_x { mirror_image(&image) } else { image }));
+        self.color_base = None;
+        self.apply_color();
+    }
+
+    /// Whether the object is drawn mirrored horizontally.
+    pub fn flip_x(&self) -> bool { self.flip_x }
+
+    /// Mirrors the object horizontally. Animated sprites flip every frame;
+    /// a static `Image` has its pixels flipped once.
+    pub fn set_flip_x(&mut self, flip: bool) {
+        if self.flip_x == flip { return; }
+        self.flip_x = flip;
+        if let Some(sprite) = self.animated_sprite.as_mut() {
+            sprite.set_mirrored(flip);
+        } else if let Some(img) = self.drawable.as_mut().and_then(|d| d.downcast_mut::<Image>()) {
+            *img = mirror_image(img);
+        }
+    }
+
+    /// Marks the object as standing on a floor and refills its jumps.
+    pub(crate) fn land(&mut self) {
+        self.grounded        = true;
+        self.jumps_remaining = self.max_jumps;
+    }
+
+    /// Spends a jump, setting upward momentum to `strength`. Returns false
+    /// (and does nothing) if none are left.
+    pub fn jump(&mut self, strength: f32) -> bool {
+        if self.jumps_remaining == 0 { return false; }
+        self.jumps_remaining -= 1;
+        self.momentum.1 = -strength;
+        self.grounded   = false;
+        true
+    }
+
+    /// Aims momentum at the current patrol waypoint, overriding gravity. A
+    /// kinematic object ignores momentum, so it's moved there directly.
+    pub(crate) fn update_patrol(&mut self) {
+        let Some(patrol) = &mut self.patrol else { return; };
+        let velocity = patrol.velocity(self.position);
+        if self.kinematic {
+            self.position.0 += velocity.0;
+            self.position.1 += velocity.1;
+        } else {
+            self.momentum = velocity;
+        }
+    }
+
+    /// Sets `flip_x` from the sign of `momentum.0` when `auto_flip` is on.
+    pub(crate) fn update_auto_flip(&mut self) {
+        if !self.auto_flip || self.momentum.0.abs() <= self.auto_flip_deadzone { return; }
+        self.set_flip_x(self.momentum.0 < 0.0);
+    }
+
+    /// A static `Image` is mirrored on the way in if the object is flipped.
+    pub fn set_drawable(&mut self, mut drawable: Box<dyn prism::drawable::Drawable>) {
+        if self.flip_x {
+            if let Some(img) = drawable.downcast_mut::<Image>() { *img = mirror_image(img); }
+        }
+        self.label      = None;
+        self.drawable   = Some(drawable);
+        self.color_base = None;
+        self.apply_color();
     }
 
-    pub fn set_drawable(&mut self, drawable: Box<dyn prism::drawable::Drawable>) {
-        self.drawable = Some(drawable);
+    pub fn set_lifetime(&mut self, seconds: f32) {
+        self.lifetime       = Some(seconds.max(0.0));
+        self.lifetime_total = seconds.max(0.0);
     }
+    pub fn set_opacity(&mut self, opacity: f32) { self.opacity = opacity.clamp(0.0, 1.0); }
+    pub fn set_emitter(&mut self, emitter: ObjectEmitter) { self.emitter = Some(Box::new(emitter)); }
+    pub fn clear_emitter(&mut self) { self.emitter = None; }
 
     pub fn set_clip(&mut self, clip: bool)                          { self.ped     = clip; }
     pub fn set_clip_origin(&mut self, origin: Option<(f32, f32)>)  { self._origin = origin; }
@@ -310,8 +648,31 @@ impl GameObject {
         self.position.1 += self.momentum.1;
     }
 
+    /// `momentum` under its physical name.
+    pub fn velocity(&self) -> (f32, f32) { self.momentum }
+    pub fn set_velocity(&mut self, x: f32, y: f32) { self.momentum = (x, y); }
+
+    /// `velocity += (acceleration + gravity) * delta_time`; once per fixed
+    /// step. Gravity counts per 1/60 s, like the crystalline solver's.
+    pub fn apply_acceleration(&mut self, delta_time: f32) {
+        self.accelerate(delta_time, true);
+    }
+
+    /// `apply_acceleration`, leaving gravity out when the crystalline solver
+    /// applies it instead.
+    pub(crate) fn accelerate(&mut self, delta_time: f32, with_gravity: bool) {
+        let gravity = if with_gravity && self.gravity_enabled && self.gravity_target.is_none() {
+            self.gravity
+        } else {
+            0.0
+        };
+        self.momentum.0 += self.acceleration.0 * delta_time;
+        self.momentum.1 += self.acceleration.1 * delta_time + gravity * (delta_time * GRAVITY_RATE);
+    }
+
+    #[deprecated(note = "gravity is part of apply_acceleration")]
     pub fn apply_gravity(&mut self) {
-        if self.gravity_target.is_none() { self.momentum.1 += self.gravity; }
+        if self.gravity_enabled && self.gravity_target.is_none() { self.momentum.1 += self.gravity; }
     }
 
     pub fn apply_resistance(&mut self) {
@@ -328,6 +689,8 @@ impl GameObject {
             let scaled = self.scaled_size.get();
             img.shape = ShapeType::Rectangle(0.0, scaled, self.rotation);
             self.drawable = Some(Box::new(img));
+            // Frames come back untinted, so re-apply tint/opacity every frame.
+            self.apply_color();
         }
     }
 
@@ -351,10 +714,38 @@ impl GameObject {
             };
         };
         if let Some(d) = self.drawable.as_mut()      { if let Some(i) = d.downcast_mut::<Image>() { rescale(i, rotation); } }
+        if let Some(n) = self.nine_slice.as_mut()    { n.resize(scaled, self.canvas_scale.get()); }
         if let Some(d) = self.glow_drawable.as_mut() { if let Some(i) = d.downcast_mut::<Image>() { rescale(i, rotation); } }
         if let Some(d) = self.tint_drawable.as_mut() { if let Some(i) = d.downcast_mut::<Image>() { rescale(i, rotation); } }
     }
 
+    /// Writes tint and `opacity` into the colour of an `Image` drawable
+    /// (including animation frames, which are rebuilt every tick). The tint
+    /// multiplies the image, with its alpha as strength. The first call
+    /// remembers the image's own colour so repeated calls don't compound.
+    pub(crate) fn apply_color(&mut self) {
+        let tint = self.highlight.as_ref().and_then(|h| h.tint);
+        if self.opacity >= 1.0 && tint.is_none() && self.color_base.is_none() { return; }
+        let Some(img) = self.drawable.as_mut().and_then(|d| d.downcast_mut::<Image>()) else { return; };
+        let base = *self.color_base.get_or_insert(img.color.unwrap_or(Color(255, 255, 255, 255)));
+        let (r, g, b) = match tint {
+            Some(t) => {
+                let k = t.3 as f32 / 255.0;
+                let mul = |c: u8, tc: u8| (c as f32 * (1.0 + (tc as f32 / 255.0 - 1.0) * k)).round() as u8;
+                (mul(base.0, t.0), mul(base.1, t.1), mul(base.2, t.2))
+            }
+            None => (base.0, base.1, base.2),
+        };
+        let alpha = (base.3 as f32 * self.opacity.clamp(0.0, 1.0)).round() as u8;
+        img.color = Some(Color(r, g, b, alpha));
+    }
+
+    /// True when the visual is an `Image` that `apply_color` can tint directly.
+    fn is_image_drawable(&self) -> bool {
+        self.animated_sprite.is_some()
+            || self.drawable.as_ref().map_or(false, |d| d.downcast_ref::<Image>().is_some())
+    }
+
     fn highlight_shape(&self, stroke: f32, size: (f32, f32)) -> ShapeType {
         if let Some(d) = &self.drawable {
             if let Some(img) = d.downcast_ref::<Image>() {
@@ -380,7 +771,10 @@ impl GameObject {
                         color: Some(glow.color),
                     }) as Box<dyn Drawable>
                 });
-                self.tint_drawable = effect.tint.map(|color| {
+                // Images are tinted by colour multiply in apply_color; other
+                // drawables (text, shapes) get a translucent overlay instead.
+                let overlay_tint = effect.tint.filter(|_| !self.is_image_drawable());
+                self.tint_drawable = overlay_tint.map(|color| {
                     let pixel: std::sync::Arc<image::RgbaImage> =
                         image::RgbaImage::from_pixel(1, 1, image::Rgba([255, 255, 255, 255])).into();
                     Box::new(Image {
@@ -410,18 +804,101 @@ impl GameObject {
         effect.tint = Some(color);
         self.highlight = Some(effect);
         self.rebuild_highlight_drawables();
+        self.apply_color();
     }
     pub fn clear_tint(&mut self) {
         if let Some(e) = &mut self.highlight { e.tint = None; if e.glow.is_none() { self.highlight = None; } }
         self.rebuild_highlight_drawables();
+        self.apply_color();
     }
     pub fn set_highlight(&mut self, effect: HighlightEffect) {
         if effect.tint.is_none() && effect.glow.is_none() { self.highlight = None; }
         else { self.highlight = Some(effect); }
         self.rebuild_highlight_drawables();
+        self.apply_color();
     }
     pub fn clear_highlight(&mut self) {
         self.highlight = None;
         self.rebuild_highlight_drawables();
+        self.apply_color();
+    }
+}
+
+/// `image` with its pixels mirrored horizontally; shape and colour kept.
+/// Steps per second `gravity` is measured against.
+const GRAVITY_RATE: f32 = 60.0;
+
+fn mirror_image(image: &Image) -> Image {
+    Image {
+        shape: image.shape.clone(),
+        image: image::imageops::flip_horizontal(&*image.image).into(),
+        color: image.color,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use image::{Rgba, RgbaImage};
+
+    const RED:  [u8; 4] = [255, 0, 0, 255];
+    const BLUE: [u8; 4] = [0, 0, 255, 255];
+
+    /// 2x1: red on the left, blue on the right.
+    fn red_blue() -> Image {
+        let mut pixels = RgbaImage::new(2, 1);
+        pixels.put_pixel(0, 0, Rgba(RED));
+        pixels.put_pixel(1, 0, Rgba(BLUE));
+        Image { shape: ShapeType::Rectangle(0.0, (2.0, 1.0), 0.0), image: pixels.into(), color: None }
+    }
+
+    fn left_pixel(obj: &GameObject) -> [u8; 4] {
+        let img = obj.drawable.as_ref().and_then(|d| d.downcast_ref::<Image>()).unwrap();
+        img.image.get_pixel(0, 0).0
+    }
+
+    #[test]
+    fn the_image_follows_the_flip() {
+        let mut obj = GameObject::build("hero").size(2.0, 1.0).flip_x().finish();
+        obj.set_image(red_blue());
+        assert!(obj.flip_x());
+        assert_eq!(left_pixel(&obj), BLUE);
+
+        obj.set_flip_x(false);
+        assert_eq!(left_pixel(&obj), RED);
+        // Setting the same facing again mustn't mirror twice.
+        obj.set_flip_x(true);
+        obj.set_flip_x(true);
+        assert_eq!(left_pixel(&obj), BLUE);
+
+        obj.set_drawable(Box::new(red_blue()));
+        assert_eq!(left_pixel(&obj), BLUE);
+    }
+
+    #[test]
+    fn auto_flip_mirrors_the_image_with_the_heading() {
+        let mut obj = GameObject::build("hero").size(2.0, 1.0).auto_flip().finish();
+        obj.set_image(red_blue());
+        obj.momentum = (-3.0, 0.0);
+        obj.update_auto_flip();
+        assert!(obj.flip_x());
+        assert_eq!(left_pixel(&obj), BLUE);
+
+        obj.momentum = (3.0, 0.0);
+        obj.update_auto_flip();
+        assert_eq!(left_pixel(&obj), RED);
+    }
+
+    #[test]
+    fn pin_to_window_holds_in_any_order() {
+        let before   = GameObject::build("a").pin_to_window().pin_top_left(20.0, 20.0).finish();
+        let after    = GameObject::build("b").pin_top_left(20.0, 20.0).pin_to_window().finish();
+        let repinned = GameObject::build("c").pin_center().pin_to_window().pin(1.0, 0.0).finish();
+        for obj in [&before, &after, &repinned] {
+            let pin = obj.screen_pin.unwrap();
+            assert!(pin.window, "{} lost pin_to_window", obj.id);
+        }
+        assert_eq!(before.screen_pin.unwrap().offset, (20.0, 20.0));
+        assert!(!GameObject::build("d").pin_center().finish().screen_pin.unwrap().window);
     }
-}
\ No newline at end of file
+}
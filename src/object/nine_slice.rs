@@ -0,0 +1,82 @@
+use image::{RgbaImage, imageops};
+use prism::canvas::{Image, ShapeType};
+
+/// An image drawn as a 3×3 grid so it can be stretched to any size without
+/// distorting its border: the corners keep their size, the edges stretch
+/// along one axis and the centre along both. Set with
+/// `GameObject::with_nine_slice`.
+#[derive(Clone, Debug)]
+pub(crate) struct NineSlice {
+    /// Border widths in source pixels: left, top, right, bottom. Drawn at
+    /// the same size in virtual pixels, times the canvas scale.
+    insets: (f32, f32, f32, f32),
+    /// Row-major: top-left, top, top-right, left, centre, right, ...
+    pieces: Vec<Image>,
+}
+
+impl NineSlice {
+    pub(crate) fn new(image: &Image, insets: (f32, f32, f32, f32)) -> Self {
+        let source = &image.image;
+        let (w, h) = (source.width(), source.height());
+        let left   = (insets.0.max(0.0) as u32).min(w);
+        let top    = (insets.1.max(0.0) as u32).min(h);
+        let right  = (insets.2.max(0.0) as u32).min(w - left);
+        let bottom = (insets.3.max(0.0) as u32).min(h - top);
+
+        let cols = [(0, left), (left, w - left - right), (w - right, right)];
+        let rows = [(0, top), (top, h - top - bottom), (h - bottom, bottom)];
+        let pieces = rows.iter()
+            .flat_map(|&(y, ph)| cols.iter().map(move |&(x, pw)| (x, y, pw, ph)))
+            .map(|(x, y, pw, ph)| {
+                // Empty slices (a zero inset) still need a pixel to upload;
+                // they're drawn at zero size.
+                let pixels: RgbaImage = if pw == 0 || ph == 0 {
+                    RgbaImage::new(1, 1)
+                } else {
+                    imageops::crop_imm(&**source, x, y, pw, ph).to_image()
+                };
+                Image {
+                    shape: ShapeType::Rectangle(0.0, (pw as f32, ph as f32), 0.0),
+                    image: pixels.into(),
+                    color: image.color,
+                }
+            })
+            .collect();
+
+        Self { insets: (left as f32, top as f32, right as f32, bottom as f32), pieces }
+    }
+
+    pub(crate) fn pieces(&self) -> &[Image] { &self.pieces }
+
+    pub(crate) fn pieces_mut(&mut self) -> &mut [Image] { &mut self.pieces }
+
+    /// Offset and size of each piece inside a `size` rectangle drawn at
+    /// `scale`. Borders shrink proportionally when `size` is smaller than
+    /// both borders together.
+    pub(crate) fn layout(&self, size: (f32, f32), scale: f32) -> [((f32, f32), (f32, f32)); 9] {
+        let fit = |a: f32, b: f32, total: f32| {
+            let (a, b) = (a * scale, b * scale);
+            let k = if a + b > total && a + b > 0.0 { total / (a + b) } else { 1.0 };
+            (a * k, b * k)
+        };
+        let (l, r) = fit(self.insets.0, self.insets.2, size.0);
+        let (t, b) = fit(self.insets.1, self.insets.3, size.1);
+        let cols = [(0.0, l), (l, size.0 - l - r), (size.0 - r, r)];
+        let rows = [(0.0, t), (t, size.1 - t - b), (size.1 - b, b)];
+
+        let mut out = [((0.0, 0.0), (0.0, 0.0)); 9];
+        for (i, &(y, h)) in rows.iter().enumerate() {
+            for (j, &(x, w)) in cols.iter().enumerate() {
+                out[i * 3 + j] = ((x, y), (w, h));
+            }
+        }
+        out
+    }
+
+    /// Stretches the pieces to fill `size` at `scale`.
+    pub(crate) fn resize(&mut self, size: (f32, f32), scale: f32) {
+        for (piece, (_, piece_size)) in self.pieces.iter_mut().zip(self.layout(size, scale)) {
+            piece.shape = ShapeType::Rectangle(0.0, piece_size, 0.0);
+        }
+    }
+}
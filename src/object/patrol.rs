@@ -0,0 +1,56 @@
+/// Moves an object back and forth along a list of waypoints.
+///
+/// Each step the object's momentum is set toward the current waypoint, so
+/// it moves through the normal physics path and objects standing on it (if
+/// it's a platform) ride along. Attach with `GameObject::with_patrol` or
+/// the builder's `patrol`.
+#[derive(Clone, Debug)]
+pub struct Patrol {
+    pub points:  Vec<(f32, f32)>,
+    /// Pixels per step.
+    pub speed:   f32,
+    /// true: after the last point go back to the first. false: ping-pong.
+    pub looping: bool,
+    pub(crate) next:    usize,
+    pub(crate) forward: bool,
+}
+
+impl Patrol {
+    pub fn new(points: Vec<(f32, f32)>, speed: f32, looping: bool) -> Self {
+        Self { points, speed: speed.max(0.0), looping, next: 0, forward: true }
+    }
+
+    /// Index of the waypoint currently being approached.
+    pub fn next_point(&self) -> usize { self.next }
+
+    /// Momentum that moves `position` toward the current waypoint without
+    /// overshooting it, advancing to the following waypoint on arrival.
+    pub(crate) fn velocity(&mut self, position: (f32, f32)) -> (f32, f32) {
+        if self.points.is_empty() { return (0.0, 0.0); }
+        self.next = self.next.min(self.points.len() - 1);
+
+        let mut goal = self.points[self.next];
+        if (goal.0 - position.0).abs() < 0.01 && (goal.1 - position.1).abs() < 0.01 {
+            self.advance();
+            goal = self.points[self.next];
+        }
+
+        let (dx, dy) = (goal.0 - position.0, goal.1 - position.1);
+        let dist = (dx * dx + dy * dy).sqrt();
+        if dist < f32::EPSILON { return (0.0, 0.0); }
+        let step = self.speed.min(dist);
+        (dx / dist * step, dy / dist * step)
+    }
+
+    fn advance(&mut self) {
+        let last = self.points.len() - 1;
+        if last == 0 { return; }
+        if self.looping {
+            self.next = (self.next + 1) % self.points.len();
+        } else {
+            if self.forward && self.next == last { self.forward = false; }
+            else if !self.forward && self.next == 0 { self.forward = true; }
+            self.next = if self.forward { self.next + 1 } else { self.next - 1 };
+        }
+    }
+}
@@ -0,0 +1,86 @@
+use super::GameObject;
+
+/// Momentum-driven squash and stretch: the drawn visual gets taller and
+/// thinner while the object moves fast vertically, and shorter and wider
+/// for a moment after it lands, easing back to normal while it stands still.
+/// Area is preserved (width × height stays constant) and it's applied on
+/// top of `render_scale`, so collision still uses `size`.
+///
+/// Attach with `GameObject::with_squash_stretch` or the builder's
+/// `squash_stretch`.
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct SquashStretch {
+    /// Stretch per pixel-per-step of vertical momentum; the landing squash
+    /// uses the speed the object hit the ground at.
+    pub strength: f32,
+    /// Largest deformation either way, as a fraction of the height.
+    pub max:      f32,
+    /// Fraction of the deformation kept each step while it recovers
+    /// (0 snaps back at once, closer to 1 wobbles longer).
+    pub recovery: f32,
+    /// > 0 stretched, < 0 squashed.
+    pub(crate) amount:       f32,
+    pub(crate) fall_speed:   f32,
+    pub(crate) was_grounded: bool,
+}
+
+impl SquashStretch {
+    pub fn new(strength: f32) -> Self {
+        Self {
+            strength,
+            max:          0.3,
+            recovery:     0.7,
+            amount:       0.0,
+            fall_speed:   0.0,
+            was_grounded: false,
+        }
+    }
+
+    pub fn max(mut self, max: f32) -> Self { self.max = max.max(0.0); self }
+    pub fn recovery(mut self, recovery: f32) -> Self { self.recovery = recovery.clamp(0.0, 1.0); self }
+
+    /// Current deformation: > 0 stretched, < 0 squashed, 0 at rest.
+    pub fn amount(&self) -> f32 { self.amount }
+
+    /// Width and height multipliers for the current deformation.
+    pub(crate) fn factor(&self) -> (f32, f32) {
+        let h = 1.0 + self.amount;
+        (1.0 / h, h)
+    }
+
+    /// Advances one fixed step from the object's post-physics state.
+    pub(crate) fn update(&mut self, vertical_momentum: f32, grounded: bool) {
+        if grounded && !self.was_grounded {
+            self.amount = -(self.fall_speed.abs() * self.strength).min(self.max);
+        } else if !grounded {
+            let target = (vertical_momentum.abs() * self.strength).min(self.max);
+            // Stretch follows speed immediately; anything above it eases off.
+            self.amount = if target > self.amount {
+                target
+            } else {
+                target + (self.amount - target) * self.recovery
+            };
+            self.fall_speed = vertical_momentum;
+        } else {
+            self.amount *= self.recovery;
+        }
+        if self.amount.abs() < 0.001 { self.amount = 0.0; }
+        self.was_grounded = grounded;
+    }
+}
+
+impl GameObject {
+    /// `render_scale` with any squash and stretch applied: what the visual
+    /// is drawn at relative to `size`.
+    pub fn visual_scale(&self) -> (f32, f32) {
+        let (w, h) = self.squash_stretch.as_ref().map_or((1.0, 1.0), SquashStretch::factor);
+        (self.render_scale.0 * w, self.render_scale.1 * h)
+    }
+
+    pub(crate) fn update_squash_stretch(&mut self) {
+        let (momentum, grounded) = (self.momentum.1, self.grounded);
+        if let Some(squash) = self.squash_stretch.as_mut() {
+            squash.update(momentum, grounded);
+        }
+    }
+}
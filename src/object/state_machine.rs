@@ -0,0 +1,88 @@
+use std::collections::HashMap;
+
+use crate::types::{Action, Condition, GameEvent, Target};
+
+/// Named behaviour states for one object, e.g. patrol → chase → attack.
+///
+/// Only the active state's events are live: they dispatch exactly like the
+/// object's own events (tick, key, collision, mouse...). Once per step the
+/// active state's transitions are checked in the order they were added and
+/// the first whose condition holds switches state, running the new state's
+/// `on_enter` action. Conditions and actions, including those of the
+/// state's events, run with `Target::Current` and `Target::SelfObject` set
+/// to the owning object.
+///
+/// Attach with `GameObject::with_state_machine` or the builder's
+/// `state_machine`.
+#[derive(Clone, Debug)]
+pub struct StateMachine {
+    pub(crate) states:  HashMap<String, MachineState>,
+    pub(crate) current: String,
+    /// Seconds spent in the current state.
+    pub(crate) elapsed: f32,
+    /// `on_enter` of the initial state still has to run.
+    pub(crate) entered: bool,
+}
+
+#[derive(Clone, Debug, Default)]
+pub(crate) struct MachineState {
+    pub(crate) events:      Vec<GameEvent>,
+    pub(crate) transitions: Vec<(Condition, String)>,
+    pub(crate) on_enter:    Option<Action>,
+}
+
+impl StateMachine {
+    pub fn new(initial: impl Into<String>) -> Self {
+        let initial = initial.into();
+        let mut states = HashMap::new();
+        states.insert(initial.clone(), MachineState::default());
+        Self { states, current: initial, elapsed: 0.0, entered: false }
+    }
+
+    /// Adds an event that's only live while in `state`. Its action runs
+    /// with `Target::Current` set to the owner, whatever kind of event it is.
+    pub fn event(mut self, state: impl Into<String>, event: GameEvent) -> Self {
+        let event = event.map_action(|action| Action::for_each(Target::SelfObject, action));
+        self.states.entry(state.into()).or_default().events.push(event);
+        self
+    }
+
+    /// Adds a `Tick` event that's only live while in `state`.
+    pub fn on_tick(self, state: impl Into<String>, action: Action) -> Self {
+        self.event(state, GameEvent::Tick { action, target: Target::Current })
+    }
+
+    /// Runs once each time `state` becomes active (including the initial state).
+    pub fn on_enter(mut self, state: impl Into<String>, action: Action) -> Self {
+        self.states.entry(state.into()).or_default().on_enter = Some(action);
+        self
+    }
+
+    /// While in `from`, switch to `to` as soon as `condition` holds.
+    pub fn transition(mut self, from: impl Into<String>, condition: Condition, to: impl Into<String>) -> Self {
+        let to = to.into();
+        self.states.entry(to.clone()).or_default();
+        self.states.entry(from.into()).or_default().transitions.push((condition, to));
+        self
+    }
+
+    pub fn state(&self) -> &str { &self.current }
+
+    /// Seconds since the current state was entered.
+    pub fn time_in_state(&self) -> f32 { self.elapsed }
+
+    pub(crate) fn active_events(&self) -> &[GameEvent] {
+        self.states.get(&self.current).map_or(&[], |s| s.events.as_slice())
+    }
+
+    /// Switches to `state`, returning its `on_enter` action. Unknown states
+    /// are ignored.
+    pub(crate) fn enter(&mut self, state: &str) -> Option<Action> {
+        let entered = self.states.get(state)?;
+        let on_enter = entered.on_enter.clone();
+        self.current = state.to_string();
+        self.elapsed = 0.0;
+        self.entered = true;
+        on_enter
+    }
+}
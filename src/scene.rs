@@ -152,9 +152,7 @@ impl Canvas {
             self.add_game_object(obj_name, obj);
         }
 
-        for (event, target) in events {
-            self.add_event(event, target);
-        }
+        self.add_events(events);
 
         if let Some(mut cb) = on_enter_cb.take() {
             cb.call(self);
@@ -3,6 +3,9 @@ use image::{RgbaImage, Rgba, AnimationDecoder, imageops};
 use std::io::Cursor;
 use prism::drawable::{Drawable, SizedTree, Rect};
 use std::cell::RefCell;
+use std::collections::HashMap;
+use std::sync::Arc;
+use crate::entropy::Entropy;
 
 
 pub fn solid_circle(size: f32, color: Color) -> Image {
@@ -138,25 +141,38 @@ pub(crate) fn generate_planet_rgba(radius: u32, r: u8, g: u8, b: u8, brightness_
     img
 }
 
-pub fn load_image(bytes: &[u8]) -> Image {
-    let rgba = image::io::Reader::new(Cursor::new(bytes))
-        .with_guessed_format()
-        .expect("quartz: failed to guess image format")
-        .decode()
-        .expect("quartz: cannot decode image from bytes")
-        .into_rgba8();
+/// Decodes PNG/JPEG (or any still format the `image` crate recognises) into
+/// an `Image` at its native pixel size. Unlike `load_image`, returns an error
+/// instead of panicking on unknown or corrupt data.
+pub fn try_load_image(bytes: &[u8]) -> Result<Image, String> {
+    let rgba = decode_rgba(bytes)?;
     let (w, h) = (rgba.width() as f32, rgba.height() as f32);
-    make_image(rgba, w, h)
+    Ok(make_image(rgba, w, h))
+}
+
+/// Like `try_load_image`, but sized to `w` × `h`.
+pub fn try_load_image_sized(bytes: &[u8], w: f32, h: f32) -> Result<Image, String> {
+    Ok(make_image(decode_rgba(bytes)?, w, h))
+}
+
+pub fn load_image(bytes: &[u8]) -> Image {
+    try_load_image(bytes).unwrap_or_else(|e| panic!("quartz: {}", e))
 }
 
 pub fn load_image_sized(bytes: &[u8], w: f32, h: f32) -> Image {
-    let rgba = image::io::Reader::new(Cursor::new(bytes))
+    try_load_image_sized(bytes, w, h).unwrap_or_else(|e| panic!("quartz: {}", e))
+}
+
+fn decode_rgba(bytes: &[u8]) -> Result<RgbaImage, String> {
+    let reader = image::ImageReader::new(Cursor::new(bytes))
         .with_guessed_format()
-        .expect("quartz: failed to guess image format")
-        .decode()
-        .expect("quartz: cannot decode image from bytes")
-        .into_rgba8();
-    make_image(rgba, w, h)
+        .map_err(|e| format!("Failed to read image: {}", e))?;
+    let Some(format) = reader.format() else {
+        return Err("Unsupported image format (expected PNG or JPEG)".to_string());
+    };
+    reader.decode()
+        .map(|img| img.into_rgba8())
+        .map_err(|e| format!("Failed to decode {:?} image: {}", format, e))
 }
 
 pub fn load_animation(bytes: &[u8], size: (f32, f32), fps: f32) -> AnimatedSprite {
@@ -248,9 +264,31 @@ impl Default for RotationOptions {
     }
 }
 
+/// How an `AnimatedSprite` behaves after its last frame.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum PlaybackMode {
+    /// Wraps back to the first frame.
+    #[default]
+    Loop,
+    /// Holds the last frame; see `AnimatedSprite::is_finished`.
+    Once,
+}
+
+/// Which way an `AnimatedSprite` steps through its frames.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum PlaybackDirection {
+    #[default]
+    Forward,
+    /// Last frame to first; `Loop` wraps from frame 0 to the last frame and
+    /// `Once` holds frame 0.
+    Backward,
+}
+
 #[derive(Clone)]
 pub struct AnimatedSprite {
-    frames:                Vec<RgbaImage>,
+    /// Shared, so handing the current frame to the renderer (and cloning the
+    /// sprite) never copies pixels.
+    frames:                Vec<Arc<RgbaImage>>,
     current_frame:         usize,
     frame_duration:        f32,
     time_since_last_frame: f32,
@@ -258,6 +296,14 @@ pub struct AnimatedSprite {
     mirrored_h:            bool,
     mirrored_v:            bool,
     rotation:              RotationOptions,
+    paused:                bool,
+    mode:                  PlaybackMode,
+    direction:             PlaybackDirection,
+    /// Start at a random point of the cycle when added to a canvas.
+    random_start:          bool,
+    /// Last mirrored frame as (frame, h, v, pixels), so a flipped sprite
+    /// only re-flips when its frame changes.
+    mirrored_frame:        RefCell<Option<(usize, bool, bool, Arc<RgbaImage>)>>,
 }
 
 impl AnimatedSprite {
@@ -269,7 +315,15 @@ impl AnimatedSprite {
         Self::decode_slice(&bytes, size, fps)
     }
 
-    fn decode_slice(bytes: &[u8], size: (f32, f32), fps: f32) -> Result<Self, String> {
+    /// Decodes at the GIF's own resolution; `size` is the first frame's
+    /// pixel size. The frames are stretched to whatever object shows them.
+    pub(crate) fn decode_native(bytes: &[u8], fps: f32) -> Result<Self, String> {
+        let frames = Self::decode_frames(bytes)?;
+        let size   = (frames[0].width() as f32, frames[0].height() as f32);
+        Ok(Self::from_frames(frames, size, fps))
+    }
+
+    fn decode_frames(bytes: &[u8]) -> Result<Vec<RgbaImage>, String> {
         let cursor  = Cursor::new(bytes);
         let decoder = image::codecs::gif::GifDecoder::new(cursor)
             .map_err(|e| format!("Failed to decode GIF: {}", e))?;
@@ -282,6 +336,11 @@ impl AnimatedSprite {
         if frames.is_empty() {
             return Err("GIF has no frames".to_string());
         }
+        Ok(frames)
+    }
+
+    fn decode_slice(bytes: &[u8], size: (f32, f32), fps: f32) -> Result<Self, String> {
+        let mut frames = Self::decode_frames(bytes)?;
 
         let tw = size.0.round().max(1.0) as u32;
         let th = size.1.round().max(1.0) as u32;
@@ -308,7 +367,7 @@ impl AnimatedSprite {
     pub fn from_frames(frames: Vec<RgbaImage>, size: (f32, f32), fps: f32) -> Self {
         assert!(!frames.is_empty(), "AnimatedSprite::from_frames requires at least one frame");
         Self {
-            frames,
+            frames:                frames.into_iter().map(Arc::new).collect(),
             current_frame:         0,
             frame_duration:        1.0 / fps,
             time_since_last_frame: 0.0,
@@ -316,39 +375,159 @@ impl AnimatedSprite {
             mirrored_h:            false,
             mirrored_v:            false,
             rotation:              RotationOptions::default(),
+            paused:                false,
+            mode:                  PlaybackMode::Loop,
+            direction:             PlaybackDirection::Forward,
+            random_start:          false,
+            mirrored_frame:        RefCell::new(None),
         }
     }
 
     pub fn fps(&self) -> f32 { 1.0 / self.frame_duration }
 
     pub fn update(&mut self, delta_time: f32) {
+        if self.paused { return; }
         self.time_since_last_frame += delta_time;
         while self.time_since_last_frame >= self.frame_duration {
             self.time_since_last_frame -= self.frame_duration;
-            self.current_frame = (self.current_frame + 1) % self.frames.len();
+            if self.mode == PlaybackMode::Once && self.current_frame == self.end_frame() {
+                self.time_since_last_frame = 0.0;
+                break;
+            }
+            let len = self.frames.len();
+            self.current_frame = match self.direction {
+                PlaybackDirection::Forward  => (self.current_frame + 1) % len,
+                PlaybackDirection::Backward => (self.current_frame + len - 1) % len,
+            };
         }
     }
 
+    /// Jumps `seconds` ahead in playback, even while paused. A looping
+    /// sprite wraps around; a `Once` sprite stops on its last frame.
+    pub fn skip(&mut self, seconds: f32) {
+        let seconds = match self.mode {
+            PlaybackMode::Loop => seconds.max(0.0) % self.cycle_duration(),
+            PlaybackMode::Once => seconds.max(0.0),
+        };
+        let paused = std::mem::replace(&mut self.paused, false);
+        self.update(seconds);
+        self.paused = paused;
+    }
+
+    /// Starts `seconds` into the animation, so copies placed side by side
+    /// can be set apart by hand. See `skip`.
+    pub fn with_start_offset(mut self, seconds: f32) -> Self { self.skip(seconds); self }
+
+    /// Starts every copy added to a canvas (`add_game_object`, spawns) at a
+    /// random point of its cycle, drawn from the canvas `entropy`, so a
+    /// field of flowers spawned from one template doesn't sway in lockstep.
+    /// Seeding the entropy makes the offsets repeat.
+    pub fn with_random_start(mut self) -> Self { self.random_start = true; self }
+
+    /// Seconds to play every frame once.
+    pub fn cycle_duration(&self) -> f32 { self.frame_duration * self.frames.len() as f32 }
+
+    pub(crate) fn apply_random_start(&mut self, entropy: &mut Entropy) {
+        if !self.random_start { return; }
+        let offset = entropy.range(0.0, self.cycle_duration());
+        self.skip(offset);
+    }
+
     pub fn get_current_image(&self) -> Image {
-        let mut pixels = self.frames[self.current_frame].clone();
-        if self.mirrored_h { pixels = imageops::flip_horizontal(&pixels); }
-        if self.mirrored_v { pixels = imageops::flip_vertical(&pixels); }
         Image {
             shape: ShapeType::Rectangle(0.0, self.size, self.rotation.to_radians()),
-            image: pixels.into(),
+            image: self.current_pixels(),
             color: None,
         }
     }
 
-    pub fn set_fps(&mut self, fps: f32) { self.frame_duration = 1.0 / fps; }
+    fn current_pixels(&self) -> Arc<RgbaImage> {
+        let frame = &self.frames[self.current_frame];
+        if !self.mirrored_h && !self.mirrored_v { return Arc::clone(frame); }
+
+        let key = (self.current_frame, self.mirrored_h, self.mirrored_v);
+        let mut cached = self.mirrored_frame.borrow_mut();
+        if let Some((f, h, v, pixels)) = cached.as_ref() {
+            if (*f, *h, *v) == key { return Arc::clone(pixels); }
+        }
+        let mut pixels = (**frame).clone();
+        if self.mirrored_h { pixels = imageops::flip_horizontal(&pixels); }
+        if self.mirrored_v { pixels = imageops::flip_vertical(&pixels); }
+        let pixels = Arc::new(pixels);
+        *cached = Some((key.0, key.1, key.2, Arc::clone(&pixels)));
+        pixels
+    }
+
+    pub fn set_fps(&mut self, fps: f32) { self.frame_duration = 1.0 / fps.max(0.001); }
+
+    /// Freezes on the current frame until `resume`.
+    pub fn pause(&mut self)            { self.paused = true; }
+    pub fn resume(&mut self)           { self.paused = false; }
+    pub fn is_paused(&self) -> bool    { self.paused }
+
+    pub fn with_mode(mut self, mode: PlaybackMode) -> Self { self.mode = mode; self }
+    pub fn set_mode(&mut self, mode: PlaybackMode)         { self.mode = mode; }
+    pub fn mode(&self) -> PlaybackMode                     { self.mode }
+
+    /// Changes which way playback steps from the current frame on, so one
+    /// asset can play an effect and its inverse (a door opening, then
+    /// closing). Unlike reaching the end of a `Once` cycle, switching never
+    /// happens on its own.
+    pub fn with_direction(mut self, direction: PlaybackDirection) -> Self { self.direction = direction; self }
+    pub fn set_direction(&mut self, direction: PlaybackDirection)         { self.direction = direction; }
+    pub fn direction(&self) -> PlaybackDirection                          { self.direction }
+
+    /// Plays backward from the current frame, resuming if paused.
+    pub fn play_reverse(&mut self) {
+        self.direction = PlaybackDirection::Backward;
+        self.paused    = false;
+    }
+
+    /// True once a `Once` sprite has reached its last frame (frame 0 when
+    /// playing backward). Never true while looping. `reset` or `set_frame`
+    /// plays it again.
+    pub fn is_finished(&self) -> bool {
+        self.mode == PlaybackMode::Once && self.current_frame == self.end_frame()
+    }
 
+    /// Back to the first frame in the playback direction: frame 0, or the
+    /// last frame when playing backward.
     pub fn reset(&mut self) {
-        self.current_frame         = 0;
+        self.current_frame = match self.direction {
+            PlaybackDirection::Forward  => 0,
+            PlaybackDirection::Backward => self.frames.len() - 1,
+        };
         self.time_since_last_frame = 0.0;
     }
 
+    /// Frame a `Once` sprite stops on.
+    fn end_frame(&self) -> usize {
+        match self.direction {
+            PlaybackDirection::Forward  => self.frames.len() - 1,
+            PlaybackDirection::Backward => 0,
+        }
+    }
+
     pub fn frame_count(&self) -> usize { self.frames.len() }
 
+    /// Index of the frame showing, 0-based in file order whatever the
+    /// playback direction.
+    pub fn current_frame(&self) -> usize { self.current_frame }
+
+    /// How far playback is through the cycle, 0 to 1, counting time spent
+    /// on the current frame and following the playback direction. A
+    /// finished `Once` sprite reads 1.
+    pub fn progress(&self) -> f32 {
+        if self.is_finished() { return 1.0; }
+        let len = self.frames.len();
+        let played = match self.direction {
+            PlaybackDirection::Forward  => self.current_frame,
+            PlaybackDirection::Backward => len - 1 - self.current_frame,
+        };
+        let within = (self.time_since_last_frame / self.frame_duration).min(1.0);
+        ((played as f32 + within) / len as f32).clamp(0.0, 1.0)
+    }
+
     pub fn set_frame(&mut self, frame: usize) {
         if frame < self.frames.len() {
             self.current_frame         = frame;
@@ -377,18 +556,85 @@ impl AnimatedSprite {
     pub fn rotation_degrees(&self) -> f32 { self.rotation.to_radians().to_degrees() }
 
     pub fn rotate_90_cw(&mut self) {
-        self.frames = self.frames.iter().map(|f| imageops::rotate270(f)).collect();
+        self.frames = self.frames.iter().map(|f| Arc::new(imageops::rotate270(&**f))).collect();
+        self.mirrored_frame.replace(None);
         self.size = (self.size.1, self.size.0);
     }
 
     pub fn rotate_90_ccw(&mut self) {
-        self.frames = self.frames.iter().map(|f| imageops::rotate90(f)).collect();
+        self.frames = self.frames.iter().map(|f| Arc::new(imageops::rotate90(&**f))).collect();
+        self.mirrored_frame.replace(None);
         self.size = (self.size.1, self.size.0);
     }
 
     pub fn rotate_180(&mut self) {
-        self.frames = self.frames.iter().map(|f| imageops::rotate180(f)).collect();
+        self.frames = self.frames.iter().map(|f| Arc::new(imageops::rotate180(&**f))).collect();
+        self.mirrored_frame.replace(None);
+    }
+}
+
+/// Preloaded animations for one object, by name ("idle", "walk_left", ...).
+/// `Action::PlayAnimation` switches between them instantly: sprites share
+/// their decoded frames, so nothing is decoded again. The first one added
+/// plays when the set is attached (`GameObject::with_animations`).
+#[derive(Clone, Debug, Default)]
+pub struct AnimationSet {
+    animations: HashMap<String, AnimatedSprite>,
+    initial:    Option<String>,
+    /// Name of the animation showing, if it came from this set.
+    current:    Option<String>,
+    keep_frame: bool,
+}
+
+impl AnimationSet {
+    pub fn new() -> Self { Self::default() }
+
+    pub fn with(mut self, name: impl Into<String>, sprite: AnimatedSprite) -> Self {
+        self.insert(name, sprite);
+        self
     }
+
+    /// Switching carries the frame index (wrapped) over to the new
+    /// animation instead of starting it from frame 0, so e.g. a walk cycle
+    /// keeps its step when the character turns.
+    pub fn keep_frame_on_switch(mut self) -> Self { self.keep_frame = true; self }
+
+    pub fn insert(&mut self, name: impl Into<String>, sprite: AnimatedSprite) {
+        let name = name.into();
+        if self.initial.is_none() { self.initial = Some(name.clone()); }
+        self.animations.insert(name, sprite);
+    }
+
+    pub fn get(&self, name: &str) -> Option<&AnimatedSprite> { self.animations.get(name) }
+
+    /// Name of the animation playing, or None if another was set directly.
+    pub fn current(&self) -> Option<&str> { self.current.as_deref() }
+
+    pub fn names(&self) -> impl Iterator<Item = &str> { self.animations.keys().map(String::as_str) }
+
+    /// The first animation added, marked as current.
+    pub(crate) fn start(&mut self) -> Option<AnimatedSprite> {
+        let name = self.initial.clone()?;
+        let sprite = self.animations.get(&name)?.clone();
+        self.current = Some(name);
+        Some(sprite)
+    }
+
+    /// The sprite to show for `name`, switching from `playing`. None if
+    /// `name` is unknown or already playing, so it can run every step.
+    pub(crate) fn switch(&mut self, name: &str, playing: Option<&AnimatedSprite>) -> Option<AnimatedSprite> {
+        if self.current.as_deref() == Some(name) { return None; }
+        let mut sprite = self.animations.get(name)?.clone();
+        sprite.reset();
+        if let Some(playing) = playing.filter(|_| self.keep_frame) {
+            sprite.current_frame         = playing.current_frame % sprite.frames.len();
+            sprite.time_since_last_frame = playing.time_since_last_frame;
+        }
+        self.current = Some(name.to_string());
+        Some(sprite)
+    }
+
+    pub(crate) fn clear_current(&mut self) { self.current = None; }
 }
 
 impl std::fmt::Debug for AnimatedSprite {
@@ -401,6 +647,9 @@ impl std::fmt::Debug for AnimatedSprite {
             .field("mirrored_h",     &self.mirrored_h)
             .field("mirrored_v",     &self.mirrored_v)
             .field("rotation",       &self.rotation)
+            .field("paused",         &self.paused)
+            .field("mode",           &self.mode)
+            .field("random_start",   &self.random_start)
             .finish()
     }
 }
@@ -1,4 +1,4 @@
-use std::collections::HashMap;
+use std::collections::{HashMap, HashSet};
 use crate::{GameObject, GameEvent, Target};
 
 #[derive(Debug, Default)]
@@ -9,6 +9,14 @@ pub struct ObjectStore {
     pub id_to_index:    HashMap<String, usize>,
     pub events:         Vec<Vec<GameEvent>>,
     pub tag_to_indices: HashMap<String, Vec<usize>>,
+    /// Name of the object `Target::Current` resolves to (set by `Action::ForEach`).
+    pub(crate) current: Option<String>,
+    /// Name of the object `Target::SelfObject` resolves to (the owner of the
+    /// event being dispatched).
+    pub(crate) owner:   Option<String>,
+    /// Name of the other object in the collision being dispatched, for
+    /// `Condition::Partner`.
+    pub(crate) partner: Option<String>,
 }
 
 impl Clone for ObjectStore {
@@ -20,6 +28,9 @@ impl Clone for ObjectStore {
             id_to_index:    self.id_to_index.clone(),
             events:         self.events.iter().map(|v| v.clone()).collect(),
             tag_to_indices: self.tag_to_indices.clone(),
+            current:        self.current.clone(),
+            owner:          self.owner.clone(),
+            partner:        self.partner.clone(),
         }
     }
 }
@@ -55,7 +66,7 @@ impl ObjectStore {
         self.events.remove(idx);
 
         self.name_to_index.remove(&removed_name);
-        self.id_to_index.remove(&removed_obj.id);
+        let id_was_here = self.id_to_index.get(&removed_obj.id) == Some(&idx);
 
         for tag in &removed_obj.tags {
             if let Some(indices) = self.tag_to_indices.get_mut(tag) {
@@ -69,14 +80,134 @@ impl ObjectStore {
             indices.iter_mut().for_each(|i| if *i > idx { *i -= 1 });
         });
 
+        // Spawned copies share their template's id; hand it to the newest
+        // one left rather than dropping it.
+        if id_was_here {
+            match self.objects.iter().rposition(|o| o.id == removed_obj.id) {
+                Some(i) => { self.id_to_index.insert(removed_obj.id, i); }
+                None    => { self.id_to_index.remove(&removed_obj.id); }
+            }
+        }
+
+        true
+    }
+
+    /// Rearranges the objects so the one at `order[k]` ends up at `k`,
+    /// remapping every index map. Tag lists come out in the new object
+    /// order. `order` must be a permutation of `0..len`.
+    pub fn reorder(&mut self, order: &[usize]) {
+        debug_assert_eq!(order.len(), self.objects.len());
+        let mut new_index = vec![0; order.len()];
+        for (new, &old) in order.iter().enumerate() { new_index[old] = new; }
+
+        let mut objects: Vec<_> = std::mem::take(&mut self.objects).into_iter().map(Some).collect();
+        let mut names:   Vec<_> = std::mem::take(&mut self.names).into_iter().map(Some).collect();
+        let mut events:  Vec<_> = std::mem::take(&mut self.events).into_iter().map(Some).collect();
+        for &old in order {
+            self.objects.push(objects[old].take().expect("reorder: repeated index"));
+            self.names.push(names[old].take().expect("reorder: repeated index"));
+            self.events.push(events[old].take().expect("reorder: repeated index"));
+        }
+
+        self.name_to_index.values_mut().for_each(|i| *i = new_index[*i]);
+        self.id_to_index.values_mut().for_each(|i| *i = new_index[*i]);
+        self.tag_to_indices.values_mut().for_each(|indices| {
+            indices.iter_mut().for_each(|i| *i = new_index[*i]);
+            indices.sort_unstable();
+        });
+    }
+
+    /// Re-registers the object called `old` under `new`. Returns false if
+    /// `old` doesn't exist or `new` is already taken.
+    pub fn rename(&mut self, old: &str, new: String) -> bool {
+        if self.name_to_index.contains_key(&new) { return false; }
+        let Some(idx) = self.name_to_index.remove(old) else { return false; };
+        self.names[idx] = new.clone();
+        self.name_to_index.insert(new.clone(), idx);
+        if self.current.as_deref() == Some(old) { self.current = Some(new.clone()); }
+        if self.owner.as_deref() == Some(old) { self.owner = Some(new.clone()); }
+        if self.partner.as_deref() == Some(old) { self.partner = Some(new); }
+        true
+    }
+
+    /// Adds `tag` to the object at `idx`, keeping `tag_to_indices` in sync
+    /// and in object order.
+    /// Returns false if the object is missing or already has the tag.
+    pub fn add_tag(&mut self, idx: usize, tag: &str) -> bool {
+        let Some(obj) = self.objects.get_mut(idx) else { return false; };
+        if obj.tags.iter().any(|t| t == tag) { return false; }
+        obj.tags.push(tag.to_string());
+        let indices = self.tag_to_indices.entry(tag.to_string()).or_default();
+        let at = indices.partition_point(|&i| i < idx);
+        indices.insert(at, idx);
         true
     }
 
+    /// Removes `tag` from the object at `idx`, keeping `tag_to_indices` in sync.
+    /// Returns false if the object is missing or doesn't have the tag.
+    pub fn remove_tag(&mut self, idx: usize, tag: &str) -> bool {
+        let Some(obj) = self.objects.get_mut(idx) else { return false; };
+        if !obj.tags.iter().any(|t| t == tag) { return false; }
+        obj.tags.retain(|t| t != tag);
+        if let Some(indices) = self.tag_to_indices.get_mut(tag) {
+            indices.retain(|&i| i != idx);
+        }
+        true
+    }
+
+    /// Events of the object at `idx`: its own, then those of its state
+    /// machine's active state.
+    pub fn events_of(&self, idx: usize) -> impl Iterator<Item = &GameEvent> {
+        let state = self.objects.get(idx)
+            .and_then(|o| o.state_machine.as_ref())
+            .map_or(&[][..], |m| m.active_events());
+        self.events.get(idx).into_iter().flatten().chain(state)
+    }
+
+    /// Every live event, in object order (see `events_of`).
+    pub fn all_events(&self) -> impl Iterator<Item = &GameEvent> {
+        self.owned_events().map(|(_, event)| event)
+    }
+
+    /// Like `all_events`, paired with the index of the owning object.
+    pub fn owned_events(&self) -> impl Iterator<Item = (usize, &GameEvent)> {
+        (0..self.objects.len()).flat_map(move |idx| self.events_of(idx).map(move |e| (idx, e)))
+    }
+
     pub fn get_indices(&self, target: &Target) -> Vec<usize> {
         match target {
             Target::ByName(name) => self.name_to_index.get(name).map(|&i| vec![i]).unwrap_or_default(),
             Target::ById(id)     => self.id_to_index.get(id).map(|&i| vec![i]).unwrap_or_default(),
             Target::ByTag(tag)   => self.tag_to_indices.get(tag).cloned().unwrap_or_default(),
+            Target::Current      => self.current.as_ref()
+                .and_then(|name| self.name_to_index.get(name))
+                .map(|&i| vec![i]).unwrap_or_default(),
+            Target::SelfObject   => self.owner.as_ref()
+                .and_then(|name| self.name_to_index.get(name))
+                .map(|&i| vec![i]).unwrap_or_default(),
+            Target::All          => (0..self.objects.len()).collect(),
+            Target::AllExcept(t) => {
+                let excluded: HashSet<usize> = self.get_indices(t).into_iter().collect();
+                (0..self.objects.len()).filter(|i| !excluded.contains(i)).collect()
+            }
+            Target::Union(targets) => {
+                let mut seen = HashSet::new();
+                targets.iter()
+                    .flat_map(|t| self.get_indices(t))
+                    .filter(|&i| seen.insert(i))
+                    .collect()
+            }
+            Target::Intersect(targets) => {
+                let Some((first, rest)) = targets.split_first() else { return Vec::new(); };
+                let others: Vec<HashSet<usize>> = rest.iter()
+                    .map(|t| self.get_indices(t).into_iter().collect())
+                    .collect();
+                let mut seen = HashSet::new();
+                self.get_indices(first).into_iter()
+                    .filter(|i| others.iter().all(|set| set.contains(i)))
+                    .filter(|&i| seen.insert(i))
+                    .collect()
+            }
         }
     }
 
@@ -0,0 +1,33 @@
+//! Shared fixtures for the unit tests.
+
+use crate::canvas::{Canvas, CanvasMode};
+use crate::object::GameObject;
+use crate::types::Action;
+use crate::value::MathOp;
+
+/// A 3840x2160 canvas with a fixed seed and no window.
+pub(crate) fn canvas() -> Canvas {
+    let mut canvas = Canvas::headless(CanvasMode::Landscape);
+    canvas.entropy.seed(1);
+    canvas
+}
+
+/// A plain box with no image.
+pub(crate) fn object(id: &str, position: (f32, f32), size: (f32, f32)) -> GameObject {
+    GameObject::build(id).position(position.0, position.1).size(size.0, size.1).finish()
+}
+
+/// A solid, immovable platform.
+pub(crate) fn platform(id: &str, position: (f32, f32), size: (f32, f32)) -> GameObject {
+    GameObject::build(id).position(position.0, position.1).size(size.0, size.1).platform().kinematic(true).finish()
+}
+
+/// Adds one to the i32 game var `name`, which must already be set.
+pub(crate) fn count(name: &str) -> Action {
+    Action::mod_var(name, MathOp::Add, 1)
+}
+
+/// Steps at 60 Hz.
+pub(crate) fn run(canvas: &mut Canvas, steps: usize) {
+    for _ in 0..steps { canvas.step(1.0 / 60.0); }
+}
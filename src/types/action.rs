@@ -1,11 +1,14 @@
 use prism::canvas::{Color, Text};
-use crate::object::GameObject;
+use crate::object::{GameObject, ObjectEmitter};
 use crate::value::{Expr, MathOp};
 use crate::sound::SoundOptions;
 use crate::crystalline::{PhysicsMaterial, PhysicsQuality, Emitter, CollisionResponse};
 use crate::constraints::{GrappleConstraint, SwingBias};
 use crate::camera::{FlashMode, FlashEase};
-use super::targeting::{Target, Location};
+use crate::input::EventCallback;
+use crate::assets::AnimationHandle;
+use crate::sprite::{PlaybackMode, PlaybackDirection};
+use super::targeting::{Target, Location, Anchor, Axis};
 use super::collision::CollisionMode;
 use super::condition::Condition;
 
@@ -13,31 +16,130 @@ use super::condition::Condition;
 pub enum Action {
     ApplyMomentum { target: Target, value: (f32, f32) },
     SetMomentum   { target: Target, value: (f32, f32) },
+    /// Adds `force` px/step of momentum along the line from `from` to each
+    /// target's centre. A target centred exactly on `from` is pushed
+    /// straight up.
+    Knockback     { target: Target, from: Location, force: f32 },
+    /// Sets upward momentum to `strength` if the object has a jump left
+    /// (see `GameObject::max_jumps`); jumps refill on landing.
+    Jump          { target: Target, strength: f32 },
+    /// Moves targets back inside the canvas (the camera world when a camera
+    /// is active), zeroing momentum on the clamped axis.
+    ClampToBounds { target: Target },
+    /// Steer toward `destination` at `speed` px/step every step until stopped,
+    /// halting within `arrive_radius`. Replaces any earlier steering. Measured
+    /// from the collision-box centre, to a target's centre for `AtTarget`.
+    MoveToward    { target: Target, destination: Location, speed: f32, arrive_radius: f32 },
+    /// Steer away from `from` every step while closer than `radius` (0 = always).
+    Flee          { target: Target, from: Location, speed: f32, radius: f32 },
+    StopSteering  { target: Target },
+    /// See `Canvas::attach`.
+    Attach        { child: Target, parent: Target, offset: (f32, f32), anchor: Anchor },
+    Detach        { child: Target },
+    /// Switches targets' `StateMachine`s to `state`, running its `on_enter`.
+    SetState      { target: Target, state: String },
     Spawn         { object: Box<GameObject>, location: Location },
+    /// `Spawn`, adding the momentum of the first `inherit_from` object (if
+    /// any) and `extra_momentum` to the template's own, so projectiles
+    /// leave a moving shooter at the shooter's speed plus their own.
+    SpawnWithMomentum { object: Box<GameObject>, location: Location, inherit_from: Option<Target>, extra_momentum: (f32, f32) },
+    /// Spawn a screen-space clone of `object` (`ignore_zoom` set) whose
+    /// top-left is `offset` virtual pixels from the view's top-left, so it
+    /// stays put while the camera moves. For HUD elements.
+    SpawnRelativeToCamera { object: Box<GameObject>, offset: (f32, f32) },
+    /// Spawn one clone of `object` at each location.
+    SpawnMany     { object: Box<GameObject>, locations: Vec<Location> },
+    /// Spawn a `rows` x `cols` grid of clones. `origin` is the top-left cell,
+    /// `spacing` the distance between cell origins.
+    SpawnGrid     { object: Box<GameObject>, rows: usize, cols: usize, spacing: (f32, f32), origin: Location },
+    /// One-shot burst of `count` object particles at `location`.
+    EmitBurst     { emitter: Box<ObjectEmitter>, location: Location, count: usize },
     SetResistance { target: Target, value: (f32, f32) },
+    /// Sets `GameObject::acceleration` (px/step per second).
+    SetAcceleration { target: Target, value: (f32, f32) },
     Remove        { target: Target },
+    /// Runs `effect` for each target, with it as `Target::Current`, then
+    /// removes it: the effect can still read its position, e.g. spawn an
+    /// explosion at `Location::at_target(Target::current())`.
+    Destroy       { target: Target, effect: Option<Box<Action>> },
     TransferMomentum { from: Target, to: Target, scale: f32 },
     SetAnimation  { target: Target, animation_bytes: &'static [u8], fps: f32 },
+    /// Shows an animation decoded earlier by `Canvas::preload_animation`.
+    SetAnimationHandle { target: Target, handle: AnimationHandle },
+    /// Switches to the named animation of targets' `AnimationSet`s.
+    PlayAnimation { target: Target, name: String },
+    /// Animation controls; no-ops on objects without an animated sprite.
+    PauseAnimation    { target: Target },
+    ResumeAnimation   { target: Target },
+    SetAnimationFps   { target: Target, fps: f32 },
+    SetAnimationFrame { target: Target, frame: usize },
+    SetAnimationMode  { target: Target, mode: PlaybackMode },
+    SetAnimationDirection { target: Target, direction: PlaybackDirection },
     Teleport      { target: Target, location: Location },
+    /// Sets rotation so the object's centre faces `at` (0° = right,
+    /// clockwise). `at` resolves with `Target::Current` set to each object.
+    LookAt        { target: Target, at: Location },
+    /// Like `LookAt`, but turns at most `max_turn_rate` degrees per run
+    /// along the shorter way round. Run it from a tick event.
+    LookAtSmooth  { target: Target, at: Location, max_turn_rate: f32 },
     Show          { target: Target },
     Hide          { target: Target },
     Toggle        { target: Target },
     Conditional   { condition: Condition, if_true: Box<Action>, if_false: Option<Box<Action>> },
     Custom        { name: String },
+    /// Run a closure directly. Actions are cloned whenever an event fires,
+    /// so the closure runs on a fresh clone each time: captured plain values
+    /// reset between runs. Keep state that must persist in game vars or an
+    /// `Rc`/`Shared` captured by the closure.
+    Callback(Box<dyn EventCallback>),
     SetVar        { name: String, value: Expr },
     ModVar        { name: String, op: MathOp, operand: Expr },
     Multi(Vec<Action>),
+    /// Runs `action` `times` times in a row, at most `Action::MAX_REPEAT`.
+    Repeat        { times: u32, action: Box<Action> },
+    /// Run `action` once per object matched by `target`, with `Target::Current`
+    /// (and `Location::AwayFrom`) resolving to that object.
+    ForEach       { target: Target, action: Box<Action> },
     PlaySound     { path: String, options: SoundOptions },
+    /// Sets `gravity`, taking the targets off the world gravity.
     SetGravity    { target: Target, value: f32 },
+    /// Puts the targets back on the world gravity. See `Canvas::set_global_gravity`.
+    UseGlobalGravity { target: Target },
+    /// Same as `Canvas::set_global_gravity`.
+    SetGlobalGravity { value: f32 },
+    /// Switches gravity off (float) or back on without touching `gravity`.
+    SetGravityEnabled { target: Target, enabled: bool },
+    /// Sets `GameObject::kinematic`.
+    SetKinematic  { target: Target, kinematic: bool },
+    /// Resizes the collision box and visual at once, keeping the box's
+    /// bottom-centre where it was. Grounded objects keep their bottom edge,
+    /// so growing never sinks them into the floor. `ScaleTo` tweens instead.
     SetSize       { target: Target, value: (f32, f32) },
+    /// `SetSize` keeping the `anchor` point of the box in place instead.
+    /// Grounded objects still keep their bottom edge.
+    SetSizeAnchored { target: Target, value: (f32, f32), anchor: Anchor },
+    /// Sets `GameObject::render_scale`; collision keeps using `size`.
+    SetRenderScale { target: Target, value: (f32, f32) },
     AddTag        { target: Target, tag: String },
     RemoveTag     { target: Target, tag: String },
+    /// Moves targets tagged `from` to `to`: `from` is removed and `to`
+    /// added in one step. Targets without `from` are left alone.
+    ChangeTag     { target: Target, from: String, to: String },
     SetText       { target: Target, text: Text },
+    /// Sets a `Label`'s content to `text` resolved against the game
+    /// variables (a `Format` expression for "SCORE {0}"). No-op on objects
+    /// without a label.
+    SetLabel      { target: Target, text: Expr },
     Expr(String),
+    /// Prints the message with the current step number. See also
+    /// `Canvas::set_event_trace`.
+    Log(String),
     SetRotation   { target: Target, value: f32 },
     SetPivot      { target: Target, x: f32, y: f32 },
     SetSlope      { target: Target, left_offset: f32, right_offset: f32, auto_rotate: bool },
     AddRotation   { target: Target, value: f32 },
+    /// Sets `GameObject::angular_velocity`, in degrees per second.
+    SetSpin       { target: Target, rate: f32 },
     ApplyRotation { target: Target, value: f32 },
     SetSurfaceNormal { target: Target, nx: f32, ny: f32 },
     SetCollisionMode { target: Target, mode: CollisionMode },
@@ -51,6 +153,7 @@ pub enum Action {
     SetElasticity    { target: Target, value: f32 },
     SetFriction      { target: Target, value: f32 },
     SetDensity       { target: Target, value: f32 },
+    SetMass          { target: Target, value: f32 },
 
     // -- Forces / impulses ---
     ApplyForce       { target: Target, fx: f32, fy: f32 },
@@ -98,12 +201,27 @@ pub enum Action {
     AddZoom { value: f32 },
     /// Smooth zoom transition (lerped). Preferred over SetZoom for animated zoom.
     SmoothZoom { value: f32 },
+    /// Timed, eased zoom. See `Canvas::zoom_to`.
+    ZoomTo { value: f32, duration: f32 },
     /// Zoom toward a screen-space point with a multiplicative delta.
     SmoothZoomAt { delta: f32 },
 
     // -- Camera effects ---
     /// Trigger a camera shake. intensity = world-space pixels, duration = seconds.
     CameraShake { intensity: f32, duration: f32 },
+    /// Shake the whole rendered canvas; works with or without a camera.
+    /// See `Canvas::shake`.
+    Shake { intensity: f32, duration: f32 },
+    /// Flash targets on and off every `interval` seconds for `duration`
+    /// seconds, e.g. invincibility frames. See `Canvas::blink`.
+    Blink { target: Target, duration: f32, interval: f32 },
+    /// Bob targets along `axis` by `amplitude` pixels, `frequency` times a
+    /// second, e.g. a hovering coin. See `Canvas::oscillate`.
+    Oscillate { target: Target, axis: Axis, amplitude: f32, frequency: f32 },
+    /// Stop targets' oscillation on `axis`, or on both with None.
+    StopOscillation { target: Target, axis: Option<Axis> },
+    /// Freeze the simulation for `duration` real seconds. See `Canvas::hitstop`.
+    Hitstop { duration: f32 },
     /// Trigger a screen flash. Color fades out over duration seconds.
     CameraFlash { color: Color, duration: f32 },
     /// Trigger a screen flash with full control over mode, easing, intensity, and freeze.
@@ -150,6 +268,7 @@ pub enum Action {
 
 impl Action {
     pub fn expr(s: impl Into<String>) -> Self { Action::Expr(s.into()) }
+    pub fn log(message: impl Into<String>) -> Self { Action::Log(message.into()) }
 
     pub fn expr_checked(s: impl Into<String>) -> Result<Self, String> {
         let src = s.into();
@@ -157,6 +276,100 @@ impl Action {
         Ok(Action::Expr(src))
     }
 
+    /// The `target` field, for variants that have one.
+    pub fn target(&self) -> Option<&Target> {
+        match self {
+            Action::ApplyMomentum             { target, .. }
+            | Action::SetMomentum             { target, .. }
+            | Action::Knockback               { target, .. }
+            | Action::Jump                    { target, .. }
+            | Action::ClampToBounds           { target, .. }
+            | Action::MoveToward              { target, .. }
+            | Action::Flee                    { target, .. }
+            | Action::StopSteering            { target, .. }
+            | Action::SetState                { target, .. }
+            | Action::SetResistance           { target, .. }
+            | Action::SetAcceleration         { target, .. }
+            | Action::Remove                  { target, .. }
+            | Action::Destroy                 { target, .. }
+            | Action::SetAnimation            { target, .. }
+            | Action::SetAnimationHandle      { target, .. }
+            | Action::PlayAnimation           { target, .. }
+            | Action::PauseAnimation          { target, .. }
+            | Action::ResumeAnimation         { target, .. }
+            | Action::SetAnimationFps         { target, .. }
+            | Action::SetAnimationFrame       { target, .. }
+            | Action::SetAnimationMode        { target, .. }
+            | Action::SetAnimationDirection   { target, .. }
+            | Action::Teleport                { target, .. }
+            | Action::LookAt                  { target, .. }
+            | Action::LookAtSmooth            { target, .. }
+            | Action::Show                    { target, .. }
+            | Action::Hide                    { target, .. }
+            | Action::Toggle                  { target, .. }
+            | Action::ForEach                 { target, .. }
+            | Action::SetGravity              { target, .. }
+            | Action::UseGlobalGravity        { target, .. }
+            | Action::SetGravityEnabled       { target, .. }
+            | Action::SetKinematic            { target, .. }
+            | Action::SetSize                 { target, .. }
+            | Action::SetSizeAnchored         { target, .. }
+            | Action::SetRenderScale          { target, .. }
+            | Action::AddTag                  { target, .. }
+            | Action::RemoveTag               { target, .. }
+            | Action::ChangeTag               { target, .. }
+            | Action::Blink                   { target, .. }
+            | Action::Oscillate               { target, .. }
+            | Action::StopOscillation         { target, .. }
+            | Action::SetText                 { target, .. }
+            | Action::SetLabel                { target, .. }
+            | Action::SetRotation             { target, .. }
+            | Action::SetPivot                { target, .. }
+            | Action::SetSlope                { target, .. }
+            | Action::AddRotation             { target, .. }
+            | Action::SetSpin                 { target, .. }
+            | Action::ApplyRotation           { target, .. }
+            | Action::SetSurfaceNormal        { target, .. }
+            | Action::SetCollisionMode        { target, .. }
+            | Action::SetGlow                 { target, .. }
+            | Action::ClearGlow               { target, .. }
+            | Action::SetTint                 { target, .. }
+            | Action::ClearTint               { target, .. }
+            | Action::SetMaterial             { target, .. }
+            | Action::SetElasticity           { target, .. }
+            | Action::SetFriction             { target, .. }
+            | Action::SetDensity              { target, .. }
+            | Action::SetMass                 { target, .. }
+            | Action::ApplyForce              { target, .. }
+            | Action::ApplyImpulse            { target, .. }
+            | Action::SetPosition             { target, .. }
+            | Action::SetCameraRelative       { target, .. }
+            | Action::WakeBody                { target, .. }
+            | Action::FreezeBody              { target, .. }
+            | Action::UnfreezeBody            { target, .. }
+            | Action::SetCollisionLayer       { target, .. }
+            | Action::AttachEmitter           { target, .. }
+            | Action::SetRenderLayer          { target, .. }
+            | Action::SetGravityStrength      { target, .. }
+            | Action::SetPlanetRadius         { target, .. }
+            | Action::SetGravityTarget        { target, .. }
+            | Action::SetGravityInfluenceMult { target, .. }
+            | Action::SetGravityFalloff       { target, .. }
+            | Action::SetGravityAllSources    { target, .. }
+            | Action::SetAlignToSlope         { target, .. }
+            | Action::SetAlignToSlopeSpeed    { target, .. }
+            | Action::AttachGrapple           { target, .. }
+            | Action::ReleaseGrapple          { target, .. }
+            | Action::SetGrappleLength        { target, .. }
+            | Action::SetGrappleStiffness     { target, .. }
+            | Action::SetGrappleDamping       { target, .. }
+            | Action::SetGrappleAnchor        { target, .. }
+            | Action::SetGrappleAnchorObject  { target, .. }
+            | Action::SetGrappleSwingBias     { target, .. } => Some(target),
+            _ => None,
+        }
+    }
+
     pub fn when(cond: Condition, if_true: Action, if_false: Option<Action>) -> Self {
         Action::Conditional { condition: cond, if_true: Box::new(if_true), if_false: if_false.map(Box::new) }
     }
@@ -167,46 +380,143 @@ impl Action {
         Action::Conditional { condition: cond, if_true: Box::new(if_true), if_false: Some(Box::new(if_false)) }
     }
     pub fn multi(actions: Vec<Action>) -> Self { Action::Multi(actions) }
+    /// Upper bound on `Action::Repeat` counts, so a typo can't stall a step.
+    pub const MAX_REPEAT: u32 = 1000;
+
+    pub fn repeat(times: u32, action: Action) -> Self {
+        Action::Repeat { times, action: Box::new(action) }
+    }
+    pub fn for_each(target: Target, action: Action) -> Self {
+        Action::ForEach { target, action: Box::new(action) }
+    }
     pub fn set_var(name: impl Into<String>, value: impl Into<Expr>) -> Self {
         Action::SetVar { name: name.into(), value: value.into() }
     }
-    pub fn apply_momentum(target: Target, x: f32, y: f32) -> Self {
+    #[deprecated(note = "use velocity")]
+    pub fn apply_momentum(target: Target, x: f32, y: f32) -> Self { Action::add_velocity(target, x, y) }
+    /// Adds (x, y) px/step to each target's velocity at once.
+    pub fn add_velocity(target: Target, x: f32, y: f32) -> Self {
         Action::ApplyMomentum { target, value: (x, y) }
     }
+    pub fn knockback(target: Target, from: Location, force: f32) -> Self {
+        Action::Knockback { target, from, force }
+    }
     pub fn apply_rotation(target: Target, value: f32) -> Self { Action::ApplyRotation { target, value } }
     pub fn set_rotation(target: Target, value: f32) -> Self   { Action::SetRotation { target, value } }
     pub fn set_pivot(target: Target, x: f32, y: f32) -> Self  { Action::SetPivot { target, x, y } }
     pub fn add_rotation(target: Target, value: f32) -> Self   { Action::AddRotation { target, value } }
+    pub fn set_spin(target: Target, rate: f32) -> Self        { Action::SetSpin { target, rate } }
     pub fn show(target: Target)   -> Self { Action::Show { target } }
     pub fn hide(target: Target)   -> Self { Action::Hide { target } }
     pub fn toggle(target: Target) -> Self { Action::Toggle { target } }
     pub fn remove(target: Target) -> Self { Action::Remove { target } }
+    pub fn destroy(target: Target, effect: Option<Action>) -> Self {
+        Action::Destroy { target, effect: effect.map(Box::new) }
+    }
     pub fn spawn(object: GameObject, location: Location) -> Self {
         Action::Spawn { object: Box::new(object), location }
     }
+    #[deprecated(note = "use velocity")]
+    pub fn spawn_with_momentum(
+        object:       GameObject,
+        location:     Location,
+        inherit_from: Option<Target>,
+        extra_x:      f32,
+        extra_y:      f32,
+    ) -> Self {
+        Action::spawn_with_velocity(object, location, inherit_from, extra_x, extra_y)
+    }
+    pub fn spawn_with_velocity(
+        object:       GameObject,
+        location:     Location,
+        inherit_from: Option<Target>,
+        extra_x:      f32,
+        extra_y:      f32,
+    ) -> Self {
+        Action::SpawnWithMomentum { object: Box::new(object), location, inherit_from, extra_momentum: (extra_x, extra_y) }
+    }
+    pub fn spawn_relative_to_camera(object: GameObject, x: f32, y: f32) -> Self {
+        Action::SpawnRelativeToCamera { object: Box::new(object), offset: (x, y) }
+    }
+    pub fn spawn_many(object: GameObject, locations: Vec<Location>) -> Self {
+        Action::SpawnMany { object: Box::new(object), locations }
+    }
+    pub fn spawn_grid(object: GameObject, rows: usize, cols: usize, spacing: (f32, f32), origin: Location) -> Self {
+        Action::SpawnGrid { object: Box::new(object), rows, cols, spacing, origin }
+    }
+    pub fn emit_burst(emitter: ObjectEmitter, location: Location, count: usize) -> Self {
+        Action::EmitBurst { emitter: Box::new(emitter), location, count }
+    }
     pub fn teleport(target: Target, location: Location) -> Self {
         Action::Teleport { target, location }
     }
-    pub fn set_momentum(target: Target, x: f32, y: f32) -> Self {
+    pub fn look_at(target: Target, at: Location) -> Self { Action::LookAt { target, at } }
+    pub fn look_at_smooth(target: Target, at: Location, max_turn_rate: f32) -> Self {
+        Action::LookAtSmooth { target, at, max_turn_rate }
+    }
+    #[deprecated(note = "use velocity")]
+    pub fn set_momentum(target: Target, x: f32, y: f32) -> Self { Action::set_velocity(target, x, y) }
+    pub fn set_velocity(target: Target, x: f32, y: f32) -> Self {
         Action::SetMomentum { target, value: (x, y) }
     }
+    pub fn jump(target: Target, strength: f32) -> Self { Action::Jump { target, strength } }
+    pub fn clamp_to_bounds(target: Target) -> Self { Action::ClampToBounds { target } }
+    pub fn move_toward(target: Target, destination: Location, speed: f32, arrive_radius: f32) -> Self {
+        Action::MoveToward { target, destination, speed, arrive_radius }
+    }
+    pub fn flee(target: Target, from: Location, speed: f32, radius: f32) -> Self {
+        Action::Flee { target, from, speed, radius }
+    }
+    pub fn stop_steering(target: Target) -> Self { Action::StopSteering { target } }
+    pub fn attach(child: Target, parent: Target, offset: (f32, f32), anchor: Anchor) -> Self {
+        Action::Attach { child, parent, offset, anchor }
+    }
+    pub fn detach(child: Target) -> Self { Action::Detach { child } }
+    pub fn set_state(target: Target, state: impl Into<String>) -> Self {
+        Action::SetState { target, state: state.into() }
+    }
     pub fn set_resistance(target: Target, x: f32, y: f32) -> Self {
         Action::SetResistance { target, value: (x, y) }
     }
+    pub fn set_acceleration(target: Target, x: f32, y: f32) -> Self {
+        Action::SetAcceleration { target, value: (x, y) }
+    }
     pub fn set_gravity(target: Target, value: f32) -> Self { Action::SetGravity { target, value } }
-    pub fn transfer_momentum(from: Target, to: Target, scale: f32) -> Self {
+    pub fn use_global_gravity(target: Target) -> Self { Action::UseGlobalGravity { target } }
+    pub fn set_global_gravity(value: f32) -> Self { Action::SetGlobalGravity { value } }
+    pub fn set_gravity_enabled(target: Target, enabled: bool) -> Self {
+        Action::SetGravityEnabled { target, enabled }
+    }
+    pub fn set_kinematic(target: Target, kinematic: bool) -> Self {
+        Action::SetKinematic { target, kinematic }
+    }
+    #[deprecated(note = "use velocity")]
+    pub fn transfer_momentum(from: Target, to: Target, scale: f32) -> Self { Action::transfer_velocity(from, to, scale) }
+    pub fn transfer_velocity(from: Target, to: Target, scale: f32) -> Self {
         Action::TransferMomentum { from, to, scale }
     }
     pub fn set_size(target: Target, width: f32, height: f32) -> Self {
         Action::SetSize { target, value: (width, height) }
     }
+    pub fn set_size_anchored(target: Target, width: f32, height: f32, anchor: Anchor) -> Self {
+        Action::SetSizeAnchored { target, value: (width, height), anchor }
+    }
+    pub fn set_render_scale(target: Target, x: f32, y: f32) -> Self {
+        Action::SetRenderScale { target, value: (x, y) }
+    }
     pub fn add_tag(target: Target, tag: impl Into<String>) -> Self {
         Action::AddTag { target, tag: tag.into() }
     }
     pub fn remove_tag(target: Target, tag: impl Into<String>) -> Self {
         Action::RemoveTag { target, tag: tag.into() }
     }
+    pub fn change_tag(target: Target, from: impl Into<String>, to: impl Into<String>) -> Self {
+        Action::ChangeTag { target, from: from.into(), to: to.into() }
+    }
     pub fn set_text(target: Target, text: Text) -> Self { Action::SetText { target, text } }
+    pub fn set_label(target: Target, text: impl Into<Expr>) -> Self {
+        Action::SetLabel { target, text: text.into() }
+    }
     pub fn play_sound(path: impl Into<String>) -> Self {
         Action::PlaySound { path: path.into(), options: SoundOptions::default() }
     }
@@ -216,6 +526,24 @@ impl Action {
     pub fn set_animation(target: Target, animation_bytes: &'static [u8], fps: f32) -> Self {
         Action::SetAnimation { target, animation_bytes, fps }
     }
+    pub fn set_animation_handle(target: Target, handle: AnimationHandle) -> Self {
+        Action::SetAnimationHandle { target, handle }
+    }
+    pub fn play_animation(target: Target, name: impl Into<String>) -> Self {
+        Action::PlayAnimation { target, name: name.into() }
+    }
+    pub fn pause_animation(target: Target)  -> Self { Action::PauseAnimation { target } }
+    pub fn resume_animation(target: Target) -> Self { Action::ResumeAnimation { target } }
+    pub fn set_animation_fps(target: Target, fps: f32) -> Self { Action::SetAnimationFps { target, fps } }
+    pub fn set_animation_frame(target: Target, frame: usize) -> Self {
+        Action::SetAnimationFrame { target, frame }
+    }
+    pub fn set_animation_mode(target: Target, mode: PlaybackMode) -> Self {
+        Action::SetAnimationMode { target, mode }
+    }
+    pub fn set_animation_direction(target: Target, direction: PlaybackDirection) -> Self {
+        Action::SetAnimationDirection { target, direction }
+    }
     pub fn set_slope(target: Target, left: f32, right: f32, auto_rotate: bool) -> Self {
         Action::SetSlope { target, left_offset: left, right_offset: right, auto_rotate }
     }
@@ -226,6 +554,12 @@ impl Action {
         Action::ModVar { name: name.into(), op, operand: operand.into() }
     }
     pub fn custom(name: impl Into<String>) -> Self { Action::Custom { name: name.into() } }
+    pub fn callback<F>(f: F) -> Self
+    where
+        F: FnMut(&mut crate::Canvas) + Clone + 'static,
+    {
+        Action::Callback(Box::new(f))
+    }
     pub fn set_collision_mode(target: Target, mode: CollisionMode) -> Self {
         Action::SetCollisionMode { target, mode }
     }
@@ -243,6 +577,7 @@ impl Action {
     pub fn set_elasticity(target: Target, value: f32) -> Self { Action::SetElasticity { target, value } }
     pub fn set_friction(target: Target, value: f32) -> Self { Action::SetFriction { target, value } }
     pub fn set_density(target: Target, value: f32) -> Self { Action::SetDensity { target, value } }
+    pub fn set_mass(target: Target, value: f32) -> Self { Action::SetMass { target, value } }
     pub fn apply_force(target: Target, fx: f32, fy: f32) -> Self { Action::ApplyForce { target, fx, fy } }
     pub fn apply_impulse(target: Target, ix: f32, iy: f32) -> Self { Action::ApplyImpulse { target, ix, iy } }
     pub fn set_position(target: Target, x: f32, y: f32) -> Self { Action::SetPosition { target, x, y } }
@@ -307,12 +642,26 @@ impl Action {
     pub fn smooth_zoom(value: f32) -> Self {
         Action::SmoothZoom { value }
     }
+    pub fn zoom_to(value: f32, duration: f32) -> Self {
+        Action::ZoomTo { value, duration }
+    }
     pub fn smooth_zoom_at(delta: f32) -> Self {
         Action::SmoothZoomAt { delta }
     }
     pub fn camera_shake(intensity: f32, duration: f32) -> Self {
         Action::CameraShake { intensity, duration }
     }
+    pub fn shake(intensity: f32, duration: f32) -> Self { Action::Shake { intensity, duration } }
+    pub fn hitstop(duration: f32) -> Self { Action::Hitstop { duration } }
+    pub fn blink(target: Target, duration: f32, interval: f32) -> Self {
+        Action::Blink { target, duration, interval }
+    }
+    pub fn oscillate(target: Target, axis: Axis, amplitude: f32, frequency: f32) -> Self {
+        Action::Oscillate { target, axis, amplitude, frequency }
+    }
+    pub fn stop_oscillation(target: Target, axis: Option<Axis>) -> Self {
+        Action::StopOscillation { target, axis }
+    }
     pub fn camera_flash(color: Color, duration: f32) -> Self {
         Action::CameraFlash { color, duration }
     }
@@ -6,8 +6,17 @@ pub enum Condition {
     Always,
     KeyHeld(prism::event::Key),
     KeyNotHeld(prism::event::Key),
+    /// True once `key` has been held for at least `seconds` (see
+    /// `Canvas::key_held_duration`). Releasing the key resets it, after
+    /// that key's `KeyRelease` events have seen the finished hold.
+    KeyHeldFor { key: prism::event::Key, seconds: f32 },
     Collision(Target),
     NoCollision(Target),
+    /// Inside a collision event's action: true if the other object of the
+    /// pair is one the target resolves to. A partner removed before its
+    /// `CollisionExit` only matches `Target::ByName` of its name. False
+    /// outside collision events. See `GameEvent::with_partner`.
+    Partner(Target),
     And(Box<Condition>, Box<Condition>),
     Or(Box<Condition>, Box<Condition>),
     Not(Box<Condition>),
@@ -15,9 +24,44 @@ pub enum Condition {
     IsHidden(Target),
     Compare(Expr, CompOp, Expr),
     VarExists(String),
+    /// True if any target was standing on a platform top (or, with
+    /// crystalline, on a floor contact) during the last physics step.
     Grounded(Target),
     Expr(String),
     HasTag(Target, String),
+    /// True with probability p (clamped to 0..1), drawn from the canvas entropy.
+    Chance(f32),
+    /// `Chance` expressed as a probability per second of simulation time,
+    /// so a check made every step fires at the same rate whatever the step
+    /// length. See `Condition::rate_per_second`.
+    RatePerSecond(f32),
+    /// True once `seconds` have passed since this key's cooldown last
+    /// fired (or if it never has). It only restarts when the whole
+    /// condition it's part of passes, so an `And` whose other side fails
+    /// leaves it ready. Timers live on the canvas and advance with
+    /// simulation time.
+    Cooldown { key: String, seconds: f32 },
+    /// Number of visible objects `Target` resolves to equals `value`.
+    /// Hidden ones (pooled, or hidden by `Action::Hide`) don't count, and a
+    /// tag with no objects counts as 0, so `count_equals(tag, 0)` is "all
+    /// gone".
+    CountEquals { target: Target, value: usize },
+    /// Like `CountEquals`, true if the visible count is greater than `value`.
+    CountGreater { target: Target, value: usize },
+    /// True if the centres of any `a` object and any other `b` object are
+    /// within `distance` px. With tags, one close pair is enough; wrap in
+    /// `Not` for "none within".
+    WithinDistance { a: Target, b: Target, distance: f32 },
+    /// True if any target's `StateMachine` is in the named state.
+    InState(Target, String),
+    /// True if any target has spent at least `seconds` in its current state.
+    TimeInState(Target, f32),
+    /// True if any target's animated sprite is in `PlaybackMode::Once` and
+    /// has reached its last frame.
+    AnimationFinished(Target),
+    /// True if any target's animated sprite is showing the given frame
+    /// (0-based), e.g. the swing frame of an attack.
+    AnimationFrame(Target, usize),
 
     // -- Crystalline physics conditions ---
     IsSleeping(Target),
@@ -43,6 +87,26 @@ pub enum Condition {
 
 impl Condition {
     pub fn expr(s: impl Into<String>) -> Self { Condition::Expr(s.into()) }
+    pub fn chance(p: f32) -> Self { Condition::Chance(p) }
+    /// True with the per-step probability `1 - (1 - p)^dt`, `dt` being the
+    /// current step's length in seconds: the chance that never firing over
+    /// a whole second's worth of steps multiplies out to `1 - p`. So `0.3`
+    /// means "at least once in a given second, 30% of the time" at any
+    /// tick rate. Meant for checks that run every step (`Tick`, `KeyHold`).
+    pub fn rate_per_second(p: f32) -> Self { Condition::RatePerSecond(p) }
+    pub fn grounded(target: Target) -> Self { Condition::Grounded(target) }
+    pub fn partner(target: Target) -> Self { Condition::Partner(target) }
+    pub fn key_held_for(key: prism::event::Key, seconds: f32) -> Self { Condition::KeyHeldFor { key, seconds } }
+    pub fn count_equals(target: Target, value: usize) -> Self { Condition::CountEquals { target, value } }
+    pub fn count_greater(target: Target, value: usize) -> Self { Condition::CountGreater { target, value } }
+    pub fn within_distance(a: Target, b: Target, distance: f32) -> Self {
+        Condition::WithinDistance { a, b, distance }
+    }
+    pub fn in_state(target: Target, state: impl Into<String>) -> Self { Condition::InState(target, state.into()) }
+    pub fn time_in_state(target: Target, seconds: f32) -> Self { Condition::TimeInState(target, seconds) }
+    pub fn animation_finished(target: Target) -> Self { Condition::AnimationFinished(target) }
+    pub fn animation_frame(target: Target, frame: usize) -> Self { Condition::AnimationFrame(target, frame) }
+    pub fn cooldown(key: impl Into<String>, seconds: f32) -> Self { Condition::Cooldown { key: key.into(), seconds } }
 
     pub fn expr_checked(s: impl Into<String>) -> Result<Self, String> {
         let src = s.into();
@@ -1,15 +1,39 @@
 use super::action::Action;
+use super::condition::Condition;
 use super::targeting::Target;
 use super::input_types::{MouseButton, ScrollAxis};
 use prism::event::Modifiers;
 
+/// Something an object reacts to, attached with `Canvas::add_event` (whose
+/// target picks the owning objects).
+///
+/// `target` names what the event is about and isn't checked when it fires;
+/// gate an event with `GameEvent::when`, or a collision event on the other
+/// object with `GameEvent::with_partner`.
 pub enum GameEvent {
+    /// Runs every step the owner overlaps another (non-platform) object,
+    /// once per overlapping pair.
     Collision         { action: Action, target: Target },
+    /// Runs once on the step a pair starts overlapping.
+    CollisionEnter    { action: Action, target: Target },
+    /// Runs once on the step a pair stops overlapping, including when the
+    /// other object is hidden or removed.
+    CollisionExit     { action: Action, target: Target },
     BoundaryCollision { action: Action, target: Target },
     KeyPress          { key: prism::event::Key, action: Action, target: Target, modifiers: Option<Modifiers> },
     KeyRelease        { key: prism::event::Key, action: Action, target: Target, modifiers: Option<Modifiers> },
+    /// Runs every step while `key` is down.
     KeyHold           { key: prism::event::Key, action: Action, target: Target, modifiers: Option<Modifiers> },
+    /// A `KeyHold` that runs at most once per `min_interval` seconds, e.g. a
+    /// fire rate. Each event keeps its own timer. Usually built with
+    /// `GameEvent::key_hold(..).with_min_interval(s)`.
+    ThrottledKeyHold  { key: prism::event::Key, action: Action, target: Target, modifiers: Option<Modifiers>, min_interval: f32 },
+    /// Runs every fixed step.
     Tick              { action: Action, target: Target },
+    /// A `Tick` that runs at `priority` among the step's ticks: lower runs
+    /// earlier, a plain `Tick` counts as 0 and equal priorities keep object
+    /// order. Usually built with `GameEvent::tick(..).with_priority(n)`.
+    OrderedTick       { action: Action, target: Target, priority: i32 },
     Custom            { name: String, target: Target },
     MousePress        { action: Action, target: Target, button: Option<MouseButton> },
     MouseRelease      { action: Action, target: Target, button: Option<MouseButton> },
@@ -18,13 +42,19 @@ pub enum GameEvent {
     MouseOver         { action: Action, target: Target },
     MouseScroll       { action: Action, target: Target, axis: Option<ScrollAxis> },
     MouseMove         { action: Action, target: Target },
+    /// Runs once after `Canvas::set_mode` switches to a different mode,
+    /// once the new canvas size is in effect, e.g. to re-place UI.
+    ModeChanged       { action: Action, target: Target },
 }
 
 impl GameEvent {
+    pub fn is_collision(&self)       -> bool { matches!(self, GameEvent::Collision      { .. }) }
+    pub fn is_collision_enter(&self) -> bool { matches!(self, GameEvent::CollisionEnter { .. }) }
+    pub fn is_collision_exit(&self)  -> bool { matches!(self, GameEvent::CollisionExit  { .. }) }
     pub fn is_key_press(&self)    -> bool { matches!(self, GameEvent::KeyPress    { .. }) }
     pub fn is_key_release(&self)  -> bool { matches!(self, GameEvent::KeyRelease  { .. }) }
-    pub fn is_key_hold(&self)     -> bool { matches!(self, GameEvent::KeyHold     { .. }) }
-    pub fn is_tick(&self)         -> bool { matches!(self, GameEvent::Tick        { .. }) }
+    pub fn is_key_hold(&self)     -> bool { matches!(self, GameEvent::KeyHold { .. } | GameEvent::ThrottledKeyHold { .. }) }
+    pub fn is_tick(&self)         -> bool { matches!(self, GameEvent::Tick { .. } | GameEvent::OrderedTick { .. }) }
     pub fn is_custom(&self)       -> bool { matches!(self, GameEvent::Custom      { .. }) }
     pub fn is_mouse_press(&self)  -> bool { matches!(self, GameEvent::MousePress  { .. }) }
     pub fn is_mouse_release(&self)-> bool { matches!(self, GameEvent::MouseRelease{ .. }) }
@@ -33,12 +63,14 @@ impl GameEvent {
     pub fn is_mouse_over(&self)   -> bool { matches!(self, GameEvent::MouseOver   { .. }) }
     pub fn is_mouse_scroll(&self) -> bool { matches!(self, GameEvent::MouseScroll { .. }) }
     pub fn is_mouse_move(&self)   -> bool { matches!(self, GameEvent::MouseMove   { .. }) }
+    pub fn is_mode_changed(&self) -> bool { matches!(self, GameEvent::ModeChanged { .. }) }
 
     pub fn key(&self) -> Option<&prism::event::Key> {
         match self {
             GameEvent::KeyPress   { key, .. }
             | GameEvent::KeyRelease { key, .. }
-            | GameEvent::KeyHold    { key, .. } => Some(key),
+            | GameEvent::KeyHold    { key, .. }
+            | GameEvent::ThrottledKeyHold { key, .. } => Some(key),
             _ => None,
         }
     }
@@ -47,7 +79,8 @@ impl GameEvent {
         match self {
             GameEvent::KeyPress   { modifiers, .. }
             | GameEvent::KeyRelease { modifiers, .. }
-            | GameEvent::KeyHold    { modifiers, .. } => modifiers.as_ref(),
+            | GameEvent::KeyHold    { modifiers, .. }
+            | GameEvent::ThrottledKeyHold { modifiers, .. } => modifiers.as_ref(),
             _ => None,
         }
     }
@@ -55,22 +88,132 @@ impl GameEvent {
     pub fn action(&self) -> &Action {
         match self {
             GameEvent::Collision          { action, .. }
+            | GameEvent::CollisionEnter   { action, .. }
+            | GameEvent::CollisionExit    { action, .. }
             | GameEvent::BoundaryCollision{ action, .. }
             | GameEvent::KeyPress         { action, .. }
             | GameEvent::KeyRelease       { action, .. }
             | GameEvent::KeyHold          { action, .. }
+            | GameEvent::ThrottledKeyHold { action, .. }
             | GameEvent::Tick             { action, .. }
+            | GameEvent::OrderedTick      { action, .. }
             | GameEvent::MousePress       { action, .. }
             | GameEvent::MouseRelease     { action, .. }
             | GameEvent::MouseEnter       { action, .. }
             | GameEvent::MouseLeave       { action, .. }
             | GameEvent::MouseOver        { action, .. }
             | GameEvent::MouseScroll      { action, .. }
-            | GameEvent::MouseMove        { action, .. } => action,
+            | GameEvent::MouseMove        { action, .. }
+            | GameEvent::ModeChanged      { action, .. } => action,
             GameEvent::Custom { .. } => panic!("Custom events don't have actions"),
         }
     }
 
+    pub fn target(&self) -> &Target {
+        match self {
+            GameEvent::Collision          { target, .. }
+            | GameEvent::CollisionEnter   { target, .. }
+            | GameEvent::CollisionExit    { target, .. }
+            | GameEvent::BoundaryCollision{ target, .. }
+            | GameEvent::KeyPress         { target, .. }
+            | GameEvent::KeyRelease       { target, .. }
+            | GameEvent::KeyHold          { target, .. }
+            | GameEvent::ThrottledKeyHold { target, .. }
+            | GameEvent::Tick             { target, .. }
+            | GameEvent::OrderedTick      { target, .. }
+            | GameEvent::Custom           { target, .. }
+            | GameEvent::MousePress       { target, .. }
+            | GameEvent::MouseRelease     { target, .. }
+            | GameEvent::MouseEnter       { target, .. }
+            | GameEvent::MouseLeave       { target, .. }
+            | GameEvent::MouseOver        { target, .. }
+            | GameEvent::MouseScroll      { target, .. }
+            | GameEvent::MouseMove        { target, .. }
+            | GameEvent::ModeChanged      { target, .. } => target,
+        }
+    }
+
+    /// Only runs the action when `condition` holds at dispatch time, e.g. a
+    /// tick that only acts while grounded. Wraps the action in
+    /// `Action::when_if`; `Custom` events are returned unchanged.
+    pub fn when(self, condition: Condition) -> Self {
+        self.map_action(|action| Action::when_if(condition, action))
+    }
+
+    /// Replaces the event's action with `f(action)`; `Custom` events are
+    /// returned unchanged.
+    pub(crate) fn map_action(mut self, f: impl FnOnce(Action) -> Action) -> Self {
+        match &mut self {
+            GameEvent::Custom { .. } => {}
+            GameEvent::Collision          { action, .. }
+            | GameEvent::CollisionEnter   { action, .. }
+            | GameEvent::CollisionExit    { action, .. }
+            | GameEvent::BoundaryCollision{ action, .. }
+            | GameEvent::KeyPress         { action, .. }
+            | GameEvent::KeyRelease       { action, .. }
+            | GameEvent::KeyHold          { action, .. }
+            | GameEvent::ThrottledKeyHold { action, .. }
+            | GameEvent::Tick             { action, .. }
+            | GameEvent::OrderedTick      { action, .. }
+            | GameEvent::MousePress       { action, .. }
+            | GameEvent::MouseRelease     { action, .. }
+            | GameEvent::MouseEnter       { action, .. }
+            | GameEvent::MouseLeave       { action, .. }
+            | GameEvent::MouseOver        { action, .. }
+            | GameEvent::MouseScroll      { action, .. }
+            | GameEvent::MouseMove        { action, .. }
+            | GameEvent::ModeChanged      { action, .. } => {
+                let inner = std::mem::replace(action, Action::Multi(Vec::new()));
+                *action = f(inner);
+            }
+        }
+        self
+    }
+
+    /// Only runs a collision event's action when the other object of the
+    /// pair is one `partner` resolves to: `when(Condition::Partner(partner))`.
+    pub fn with_partner(self, partner: Target) -> Self {
+        self.when(Condition::Partner(partner))
+    }
+
+    /// A `KeyHold` running `action` every step `key` is down, whatever the
+    /// modifiers.
+    pub fn key_hold(key: prism::event::Key, action: Action, target: Target) -> Self {
+        GameEvent::KeyHold { key, action, target, modifiers: None }
+    }
+
+    /// Turns a `KeyHold` into a `ThrottledKeyHold` firing at most once per
+    /// `seconds` (or changes a `ThrottledKeyHold`'s interval); other events
+    /// are returned unchanged.
+    pub fn with_min_interval(self, seconds: f32) -> Self {
+        match self {
+            GameEvent::KeyHold { key, action, target, modifiers }
+            | GameEvent::ThrottledKeyHold { key, action, target, modifiers, .. } =>
+                GameEvent::ThrottledKeyHold { key, action, target, modifiers, min_interval: seconds },
+            other => other,
+        }
+    }
+
+    /// A `Tick` running `action` every step, at priority 0.
+    pub fn tick(action: Action, target: Target) -> Self {
+        GameEvent::Tick { action, target }
+    }
+
+    /// Turns a `Tick` into an `OrderedTick` at `priority` (or re-prioritises
+    /// an `OrderedTick`); other events are returned unchanged.
+    pub fn with_priority(self, priority: i32) -> Self {
+        match self {
+            GameEvent::Tick { action, target } | GameEvent::OrderedTick { action, target, .. } =>
+                GameEvent::OrderedTick { action, target, priority },
+            other => other,
+        }
+    }
+
+    /// An `OrderedTick`'s priority; 0 for other events, plain `Tick`s included.
+    pub fn priority(&self) -> i32 {
+        if let GameEvent::OrderedTick { priority, .. } = self { *priority } else { 0 }
+    }
+
     pub fn custom_name(&self) -> Option<&str> {
         if let GameEvent::Custom { name, .. } = self { Some(name) } else { None }
     }
@@ -81,6 +224,10 @@ impl Clone for GameEvent {
         match self {
             GameEvent::Collision { action, target } =>
                 GameEvent::Collision { action: action.clone(), target: target.clone() },
+            GameEvent::CollisionEnter { action, target } =>
+                GameEvent::CollisionEnter { action: action.clone(), target: target.clone() },
+            GameEvent::CollisionExit { action, target } =>
+                GameEvent::CollisionExit { action: action.clone(), target: target.clone() },
             GameEvent::BoundaryCollision { action, target } =>
                 GameEvent::BoundaryCollision { action: action.clone(), target: target.clone() },
             GameEvent::KeyPress { key, action, target, modifiers } =>
@@ -89,8 +236,12 @@ impl Clone for GameEvent {
                 GameEvent::KeyRelease { key: key.clone(), action: action.clone(), target: target.clone(), modifiers: *modifiers },
             GameEvent::KeyHold { key, action, target, modifiers } =>
                 GameEvent::KeyHold { key: key.clone(), action: action.clone(), target: target.clone(), modifiers: *modifiers },
+            GameEvent::ThrottledKeyHold { key, action, target, modifiers, min_interval } =>
+                GameEvent::ThrottledKeyHold { key: key.clone(), action: action.clone(), target: target.clone(), modifiers: *modifiers, min_interval: *min_interval },
             GameEvent::Tick { action, target } =>
                 GameEvent::Tick { action: action.clone(), target: target.clone() },
+            GameEvent::OrderedTick { action, target, priority } =>
+                GameEvent::OrderedTick { action: action.clone(), target: target.clone(), priority: *priority },
             GameEvent::Custom { name, target } =>
                 GameEvent::Custom { name: name.clone(), target: target.clone() },
             GameEvent::MousePress { action, target, button } =>
@@ -107,6 +258,8 @@ impl Clone for GameEvent {
                 GameEvent::MouseScroll { action: action.clone(), target: target.clone(), axis: *axis },
             GameEvent::MouseMove { action, target } =>
                 GameEvent::MouseMove { action: action.clone(), target: target.clone() },
+            GameEvent::ModeChanged { action, target } =>
+                GameEvent::ModeChanged { action: action.clone(), target: target.clone() },
         }
     }
 }
@@ -116,6 +269,10 @@ impl std::fmt::Debug for GameEvent {
         match self {
             GameEvent::Collision { action, target } =>
                 f.debug_struct("Collision").field("action", action).field("target", target).finish(),
+            GameEvent::CollisionEnter { action, target } =>
+                f.debug_struct("CollisionEnter").field("action", action).field("target", target).finish(),
+            GameEvent::CollisionExit { action, target } =>
+                f.debug_struct("CollisionExit").field("action", action).field("target", target).finish(),
             GameEvent::BoundaryCollision { action, target } =>
                 f.debug_struct("BoundaryCollision").field("action", action).field("target", target).finish(),
             GameEvent::KeyPress { key, action, target, modifiers } =>
@@ -124,8 +281,12 @@ impl std::fmt::Debug for GameEvent {
                 f.debug_struct("KeyRelease").field("key", key).field("action", action).field("target", target).field("modifiers", modifiers).finish(),
             GameEvent::KeyHold { key, action, target, modifiers } =>
                 f.debug_struct("KeyHold").field("key", key).field("action", action).field("target", target).field("modifiers", modifiers).finish(),
+            GameEvent::ThrottledKeyHold { key, action, target, modifiers, min_interval } =>
+                f.debug_struct("ThrottledKeyHold").field("key", key).field("action", action).field("target", target).field("modifiers", modifiers).field("min_interval", min_interval).finish(),
             GameEvent::Tick { action, target } =>
                 f.debug_struct("Tick").field("action", action).field("target", target).finish(),
+            GameEvent::OrderedTick { action, target, priority } =>
+                f.debug_struct("OrderedTick").field("action", action).field("target", target).field("priority", priority).finish(),
             GameEvent::Custom { name, target } =>
                 f.debug_struct("Custom").field("name", name).field("target", target).finish(),
             GameEvent::MousePress { action, target, button } =>
@@ -142,6 +303,8 @@ impl std::fmt::Debug for GameEvent {
                 f.debug_struct("MouseScroll").field("action", action).field("target", target).field("axis", axis).finish(),
             GameEvent::MouseMove { action, target } =>
                 f.debug_struct("MouseMove").field("action", action).field("target", target).finish(),
+            GameEvent::ModeChanged { action, target } =>
+                f.debug_struct("ModeChanged").field("action", action).field("target", target).finish(),
         }
     }
 }
\ No newline at end of file
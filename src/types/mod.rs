@@ -7,7 +7,7 @@ pub mod action;
 pub mod event;
 pub mod gravity;
 
-pub use targeting::{Target, Location, Anchor};
+pub use targeting::{Target, Location, Anchor, Axis};
 pub use collision::{CollisionMode, CollisionShape, collision_layers};
 pub use effects::{GlowConfig, HighlightEffect};
 pub use input_types::{MouseButton, ScrollAxis};
@@ -27,9 +27,13 @@ pub use gravity::GravityFalloff;
 /// * `offset`  — pixel nudge applied after anchoring in virtual screen
 ///   coordinates. Positive X is right, positive Y is down.
 ///
+/// * `window`  — anchor to the actual window (letterbox bars included)
+///   instead of the virtual canvas, e.g. a score in the true top-left corner.
+///
 /// Setting a `ScreenPin` automatically implies `ignore_zoom = true`.
 #[derive(Debug, Clone, Copy, PartialEq, Default)]
 pub struct ScreenPin {
     pub anchor: (f32, f32),
     pub offset: (f32, f32),
+    pub window: bool,
 }
\ No newline at end of file
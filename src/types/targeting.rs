@@ -3,20 +3,69 @@ pub enum Target {
     ByName(String),
     ById(String),
     ByTag(String),
+    /// The object currently being visited by `Action::ForEach`.
+    /// Matches nothing outside a ForEach.
+    Current,
+    /// The object whose event is running: the one passed to `add_event`
+    /// (or owning the `StateMachine`). Matches nothing outside event dispatch,
+    /// e.g. in `Canvas::run` called directly.
+    SelfObject,
+    /// Every object, in insertion order.
+    All,
+    /// Every object the inner target doesn't match.
+    AllExcept(Box<Target>),
+    /// Objects matched by any sub-target, in first-match order, without
+    /// duplicates.
+    Union(Vec<Target>),
+    /// Objects matched by every sub-target, in the first sub-target's order.
+    /// Empty if there are no sub-targets.
+    Intersect(Vec<Target>),
 }
 
 impl Target {
     pub fn name(s: impl Into<String>) -> Self { Target::ByName(s.into()) }
     pub fn id(s: impl Into<String>)   -> Self { Target::ById(s.into()) }
     pub fn tag(s: impl Into<String>)  -> Self { Target::ByTag(s.into()) }
+    pub fn current()                  -> Self { Target::Current }
+    pub fn self_object()              -> Self { Target::SelfObject }
+    pub fn all()                      -> Self { Target::All }
+    pub fn all_except(t: Target)      -> Self { Target::AllExcept(Box::new(t)) }
+    pub fn union(ts: Vec<Target>)     -> Self { Target::Union(ts) }
+    pub fn intersect(ts: Vec<Target>) -> Self { Target::Intersect(ts) }
 }
 
-#[derive(Debug, Clone, Copy)]
+/// Normalised point on an object's bounding box. (0,0) = top-left, (1,1) = bottom-right.
+#[derive(Debug, Clone, Copy, PartialEq)]
 pub struct Anchor {
     pub x: f32,
     pub y: f32,
 }
 
+impl Anchor {
+    pub const TOP_LEFT:      Anchor = Anchor { x: 0.0, y: 0.0 };
+    pub const TOP_CENTER:    Anchor = Anchor { x: 0.5, y: 0.0 };
+    pub const TOP_RIGHT:     Anchor = Anchor { x: 1.0, y: 0.0 };
+    pub const CENTER_LEFT:   Anchor = Anchor { x: 0.0, y: 0.5 };
+    pub const CENTER:        Anchor = Anchor { x: 0.5, y: 0.5 };
+    pub const CENTER_RIGHT:  Anchor = Anchor { x: 1.0, y: 0.5 };
+    pub const BOTTOM_LEFT:   Anchor = Anchor { x: 0.0, y: 1.0 };
+    pub const BOTTOM_CENTER: Anchor = Anchor { x: 0.5, y: 1.0 };
+    pub const BOTTOM_RIGHT:  Anchor = Anchor { x: 1.0, y: 1.0 };
+
+    pub const fn new(x: f32, y: f32) -> Self { Anchor { x, y } }
+}
+
+impl Default for Anchor {
+    fn default() -> Self { Anchor::TOP_LEFT }
+}
+
+/// A world axis. See `Action::Oscillate`.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum Axis {
+    X,
+    Y,
+}
+
 #[derive(Debug, Clone)]
 pub enum Location {
     Position((f32, f32)),
@@ -31,6 +80,28 @@ pub enum Location {
         anchor: Anchor,
         offset: (f32, f32),
     },
+    /// `distance` pixels from the current ForEach object, along the line
+    /// pointing away from `from`'s centre. Outside a ForEach it moves away
+    /// from the owner of the running event instead.
+    AwayFrom {
+        from: Box<Target>,
+        distance: f32,
+    },
+    /// Top-left of cell (`col`, `row`) on a grid of `tile_size` cells whose
+    /// cell (0, 0) starts at `origin`. Matches `Canvas::load_tilemap`.
+    GridCell {
+        col: i32,
+        row: i32,
+        tile_size: (f32, f32),
+        origin: (f32, f32),
+    },
+    /// `anchor` point of the virtual canvas plus `offset`, e.g.
+    /// `Anchor::BOTTOM_RIGHT` with (-50, -50) is 50px in from that corner.
+    /// Like any location it names the spawned object's top-left.
+    CanvasAnchor {
+        anchor: Anchor,
+        offset: (f32, f32),
+    },
 }
 
 impl Location {
@@ -60,4 +131,19 @@ impl Location {
             offset,
         }
     }
+
+    pub fn away_from(from: Target, distance: f32) -> Self {
+        Location::AwayFrom {
+            from: Box::new(from),
+            distance,
+        }
+    }
+
+    pub fn grid_cell(col: i32, row: i32, tile_size: (f32, f32), origin: (f32, f32)) -> Self {
+        Location::GridCell { col, row, tile_size, origin }
+    }
+
+    pub fn canvas_anchor(anchor: Anchor, offset: (f32, f32)) -> Self {
+        Location::CanvasAnchor { anchor, offset }
+    }
 }
\ No newline at end of file